@@ -0,0 +1,329 @@
+//! JSON-RPC interface of the social pallet.
+//!
+//! RPC methods read data through a `SocialProvider`. A node uses `RuntimeApiProvider`,
+//! which calls the `SocialApi` runtime API of a client, while tests may pass a mock provider.
+//! Tests cover the block selection and error conversion of `RuntimeApiProvider`,
+//! but not its calls to a client.
+//! Results are returned as DTOs with byte strings decoded as UTF-8 and block numbers and times as `u64`.
+
+use std::marker::PhantomData;
+use std::sync::Arc;
+
+use jsonrpc_core::{Error as RpcError, ErrorCode, Result};
+use jsonrpc_derive::rpc;
+use serde::{Deserialize, Serialize};
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_runtime::generic::BlockId;
+use sp_runtime::traits::{Block as BlockT, SaturatedConversion};
+
+use pallet_social::{BlogId, PostId, CommentId, Blog, Post, PostExtension, Profile, Change};
+pub use pallet_social_runtime_api::SocialApi as SocialRuntimeApi;
+
+mod tests;
+
+/// Error code of a failed call to the runtime.
+const RUNTIME_ERROR: i64 = 1;
+
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ChangeDto<AccountId> {
+    pub account: AccountId,
+    pub block: u64,
+    pub time: u64,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BlogDto<AccountId> {
+    pub id: BlogId,
+    pub created: ChangeDto<AccountId>,
+    pub updated: Option<ChangeDto<AccountId>>,
+    pub owner: AccountId,
+    pub writers: Vec<AccountId>,
+    pub slug: String,
+    pub ipfs_hash: String,
+    pub comments_restricted: bool,
+    pub posts_count: u32,
+    pub followers_count: u32,
+    pub score: i32,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum PostExtensionDto {
+    RegularPost,
+    SharedPost(PostId),
+    SharedComment(CommentId),
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PostDto<AccountId> {
+    pub id: PostId,
+    pub blog_id: BlogId,
+    pub created: ChangeDto<AccountId>,
+    pub updated: Option<ChangeDto<AccountId>>,
+    pub extension: PostExtensionDto,
+    pub ipfs_hash: String,
+    pub comments_count: u32,
+    pub upvotes_count: u32,
+    pub downvotes_count: u32,
+    pub shares_count: u32,
+    pub score: i32,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProfileDto<AccountId> {
+    pub account: AccountId,
+    pub created: ChangeDto<AccountId>,
+    pub updated: Option<ChangeDto<AccountId>>,
+    pub username: String,
+    pub ipfs_hash: String,
+}
+
+fn string_from_bytes(bytes: Vec<u8>) -> String {
+    String::from_utf8_lossy(&bytes).into_owned()
+}
+
+impl<T: pallet_social::Trait> From<Change<T>> for ChangeDto<T::AccountId> {
+    fn from(change: Change<T>) -> Self {
+        ChangeDto {
+            account: change.account,
+            block: change.block.saturated_into(),
+            time: change.time.saturated_into(),
+        }
+    }
+}
+
+impl<T: pallet_social::Trait> From<Blog<T>> for BlogDto<T::AccountId> {
+    fn from(blog: Blog<T>) -> Self {
+        BlogDto {
+            id: blog.id,
+            created: blog.created.into(),
+            updated: blog.updated.map(Into::into),
+            owner: blog.owner,
+            writers: blog.writers,
+            slug: string_from_bytes(blog.slug),
+            ipfs_hash: string_from_bytes(blog.ipfs_hash),
+            comments_restricted: blog.comments_restricted,
+            posts_count: blog.posts_count,
+            followers_count: blog.followers_count,
+            score: blog.score,
+        }
+    }
+}
+
+impl From<PostExtension> for PostExtensionDto {
+    fn from(extension: PostExtension) -> Self {
+        match extension {
+            PostExtension::RegularPost => PostExtensionDto::RegularPost,
+            PostExtension::SharedPost(post_id) => PostExtensionDto::SharedPost(post_id),
+            PostExtension::SharedComment(comment_id) => PostExtensionDto::SharedComment(comment_id),
+        }
+    }
+}
+
+impl<T: pallet_social::Trait> From<Post<T>> for PostDto<T::AccountId> {
+    fn from(post: Post<T>) -> Self {
+        PostDto {
+            id: post.id,
+            blog_id: post.blog_id,
+            created: post.created.into(),
+            updated: post.updated.map(Into::into),
+            extension: post.extension.into(),
+            ipfs_hash: string_from_bytes(post.ipfs_hash),
+            comments_count: post.comments_count,
+            upvotes_count: post.upvotes_count,
+            downvotes_count: post.downvotes_count,
+            shares_count: post.shares_count,
+            score: post.score,
+        }
+    }
+}
+
+impl<T: pallet_social::Trait> From<(T::AccountId, Profile<T>)> for ProfileDto<T::AccountId> {
+    fn from((account, profile): (T::AccountId, Profile<T>)) -> Self {
+        ProfileDto {
+            account,
+            created: profile.created.into(),
+            updated: profile.updated.map(Into::into),
+            username: string_from_bytes(profile.username),
+            ipfs_hash: string_from_bytes(profile.ipfs_hash),
+        }
+    }
+}
+
+#[rpc]
+pub trait SocialApi<BlockHash, AccountId> {
+    #[rpc(name = "social_getBlogBySlug")]
+    fn blog_by_slug(&self, slug: String, at: Option<BlockHash>) -> Result<Option<BlogDto<AccountId>>>;
+
+    #[rpc(name = "social_getProfileByUsername")]
+    fn profile_by_username(&self, username: String, at: Option<BlockHash>) -> Result<Option<ProfileDto<AccountId>>>;
+
+    #[rpc(name = "social_getPostsByBlog")]
+    fn posts_by_blog(
+        &self,
+        blog_id: BlogId,
+        offset: u32,
+        limit: u32,
+        at: Option<BlockHash>,
+    ) -> Result<Vec<PostDto<AccountId>>>;
+
+    /// Posts of the blogs followed by an account, newest first.
+    #[rpc(name = "social_getFeed")]
+    fn feed(
+        &self,
+        account: AccountId,
+        offset: u32,
+        limit: u32,
+        at: Option<BlockHash>,
+    ) -> Result<Vec<PostDto<AccountId>>>;
+}
+
+/// Source of the data served by `Social`. If `at` is `None`, the best block is read.
+/// Errors are passed to RPC clients as the data of a runtime error.
+pub trait SocialProvider<BlockHash, AccountId> {
+    fn blog_by_slug(&self, at: Option<BlockHash>, slug: Vec<u8>) -> std::result::Result<Option<BlogDto<AccountId>>, String>;
+
+    fn profile_by_username(&self, at: Option<BlockHash>, username: Vec<u8>)
+        -> std::result::Result<Option<ProfileDto<AccountId>>, String>;
+
+    fn posts_by_blog(&self, at: Option<BlockHash>, blog_id: BlogId, offset: u32, limit: u32)
+        -> std::result::Result<Vec<PostDto<AccountId>>, String>;
+
+    fn feed(&self, at: Option<BlockHash>, account: AccountId, offset: u32, limit: u32)
+        -> std::result::Result<Vec<PostDto<AccountId>>, String>;
+}
+
+/// Reads the social data via the `SocialApi` runtime API of a client.
+pub struct RuntimeApiProvider<C, Block, Runtime> {
+    client: Arc<C>,
+    _marker: PhantomData<fn() -> (Block, Runtime)>,
+}
+
+impl<C, Block, Runtime> RuntimeApiProvider<C, Block, Runtime> {
+    pub fn new(client: Arc<C>) -> Self {
+        RuntimeApiProvider { client, _marker: Default::default() }
+    }
+}
+
+impl<C, Block, Runtime> RuntimeApiProvider<C, Block, Runtime>
+where
+    Block: BlockT,
+    C: HeaderBackend<Block>,
+{
+    fn block_id(&self, at: Option<<Block as BlockT>::Hash>) -> BlockId<Block> {
+        block_id_or_best(at, || self.client.info().best_hash)
+    }
+}
+
+/// Block `at`, or the best one if `at` is `None`.
+fn block_id_or_best<Block: BlockT, F>(at: Option<<Block as BlockT>::Hash>, best_hash: F) -> BlockId<Block>
+where
+    F: FnOnce() -> <Block as BlockT>::Hash,
+{
+    BlockId::hash(at.unwrap_or_else(best_hash))
+}
+
+/// Convert the result of a runtime API call, passing its error to RPC clients as text.
+fn api_result<R, E: std::fmt::Debug>(result: std::result::Result<R, E>) -> std::result::Result<R, String> {
+    result.map_err(|e| format!("{:?}", e))
+}
+
+impl<C, Block, Runtime> SocialProvider<<Block as BlockT>::Hash, Runtime::AccountId> for RuntimeApiProvider<C, Block, Runtime>
+where
+    Block: BlockT,
+    Runtime: pallet_social::Trait,
+    C: ProvideRuntimeApi + HeaderBackend<Block>,
+    C::Api: SocialRuntimeApi<Block, Runtime>,
+{
+    fn blog_by_slug(&self, at: Option<<Block as BlockT>::Hash>, slug: Vec<u8>)
+        -> std::result::Result<Option<BlogDto<Runtime::AccountId>>, String>
+    {
+        api_result(self.client.runtime_api().blog_by_slug(&self.block_id(at), slug))
+            .map(|blog| blog.map(Into::into))
+    }
+
+    fn profile_by_username(&self, at: Option<<Block as BlockT>::Hash>, username: Vec<u8>)
+        -> std::result::Result<Option<ProfileDto<Runtime::AccountId>>, String>
+    {
+        api_result(self.client.runtime_api().profile_by_username(&self.block_id(at), username))
+            .map(|profile| profile.map(Into::into))
+    }
+
+    fn posts_by_blog(&self, at: Option<<Block as BlockT>::Hash>, blog_id: BlogId, offset: u32, limit: u32)
+        -> std::result::Result<Vec<PostDto<Runtime::AccountId>>, String>
+    {
+        api_result(self.client.runtime_api().posts_by_blog(&self.block_id(at), blog_id, offset, limit))
+            .map(|posts| posts.into_iter().map(Into::into).collect())
+    }
+
+    fn feed(&self, at: Option<<Block as BlockT>::Hash>, account: Runtime::AccountId, offset: u32, limit: u32)
+        -> std::result::Result<Vec<PostDto<Runtime::AccountId>>, String>
+    {
+        api_result(self.client.runtime_api().feed(&self.block_id(at), account, offset, limit))
+            .map(|posts| posts.into_iter().map(Into::into).collect())
+    }
+}
+
+/// Implementation of `SocialApi` on top of a `SocialProvider`.
+pub struct Social<P> {
+    provider: P,
+}
+
+impl<P> Social<P> {
+    pub fn new(provider: P) -> Self {
+        Social { provider }
+    }
+}
+
+impl<C, Block, Runtime> Social<RuntimeApiProvider<C, Block, Runtime>> {
+    /// Serve the data of a client's runtime.
+    pub fn with_client(client: Arc<C>) -> Self {
+        Self::new(RuntimeApiProvider::new(client))
+    }
+}
+
+fn runtime_error(data: String) -> RpcError {
+    RpcError {
+        code: ErrorCode::ServerError(RUNTIME_ERROR),
+        message: "Unable to query the social runtime API".into(),
+        data: Some(data.into()),
+    }
+}
+
+impl<P, BlockHash, AccountId> SocialApi<BlockHash, AccountId> for Social<P>
+where
+    P: SocialProvider<BlockHash, AccountId> + Send + Sync + 'static,
+{
+    fn blog_by_slug(&self, slug: String, at: Option<BlockHash>) -> Result<Option<BlogDto<AccountId>>> {
+        self.provider.blog_by_slug(at, slug.into_bytes()).map_err(runtime_error)
+    }
+
+    fn profile_by_username(&self, username: String, at: Option<BlockHash>) -> Result<Option<ProfileDto<AccountId>>> {
+        self.provider.profile_by_username(at, username.into_bytes()).map_err(runtime_error)
+    }
+
+    fn posts_by_blog(
+        &self,
+        blog_id: BlogId,
+        offset: u32,
+        limit: u32,
+        at: Option<BlockHash>,
+    ) -> Result<Vec<PostDto<AccountId>>> {
+        self.provider.posts_by_blog(at, blog_id, offset, limit).map_err(runtime_error)
+    }
+
+    fn feed(
+        &self,
+        account: AccountId,
+        offset: u32,
+        limit: u32,
+        at: Option<BlockHash>,
+    ) -> Result<Vec<PostDto<AccountId>>> {
+        self.provider.feed(at, account, offset, limit).map_err(runtime_error)
+    }
+}
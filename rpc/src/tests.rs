@@ -0,0 +1,308 @@
+#![cfg(test)]
+
+use super::*;
+
+use frame_support::{impl_outer_origin, parameter_types, weights::Weight};
+use jsonrpc_core::IoHandler;
+use serde_json::{json, Value};
+use sp_core::H256;
+use sp_runtime::{traits::{BlakeTwo256, IdentityLookup}, testing::{Block, ExtrinsicWrapper, Header}, Perbill};
+
+type AccountId = u64;
+type BlockHash = u64;
+
+const ACCOUNT1: AccountId = 1;
+const ACCOUNT2: AccountId = 2;
+
+fn change(account: AccountId) -> ChangeDto<AccountId> {
+    ChangeDto { account, block: 1, time: 6000 }
+}
+
+fn blog() -> BlogDto<AccountId> {
+    BlogDto {
+        id: 1,
+        created: change(ACCOUNT1),
+        updated: None,
+        owner: ACCOUNT1,
+        writers: vec![],
+        slug: "blog_slug".into(),
+        ipfs_hash: "QmRAQB6YaCyidP37UdDnjFY5vQuiBrcqdyoW1CuDgwxkD4".into(),
+        comments_restricted: false,
+        posts_count: 1,
+        followers_count: 2,
+        score: 0,
+    }
+}
+
+fn post(id: PostId) -> PostDto<AccountId> {
+    PostDto {
+        id,
+        blog_id: 1,
+        created: change(ACCOUNT1),
+        updated: None,
+        extension: PostExtensionDto::RegularPost,
+        ipfs_hash: "QmRAQB6YaCyidP37UdDnjFY5vQuiBrcqdyoW1CuDgwxkD4".into(),
+        comments_count: 0,
+        upvotes_count: 0,
+        downvotes_count: 0,
+        shares_count: 0,
+        score: 0,
+    }
+}
+
+/// Serves a single blog with posts 1 and 2, followed by ACCOUNT2, and a profile of ACCOUNT1.
+/// Reading at any block other than the best one (`None`) fails.
+struct MockProvider;
+
+impl MockProvider {
+    fn ensure_best_block(at: Option<BlockHash>) -> std::result::Result<(), String> {
+        match at {
+            None => Ok(()),
+            Some(hash) => Err(format!("Unknown block {}", hash)),
+        }
+    }
+}
+
+impl SocialProvider<BlockHash, AccountId> for MockProvider {
+    fn blog_by_slug(&self, at: Option<BlockHash>, slug: Vec<u8>) -> std::result::Result<Option<BlogDto<AccountId>>, String> {
+        Self::ensure_best_block(at)?;
+        Ok(Some(blog()).filter(|blog| blog.slug.as_bytes() == &slug[..]))
+    }
+
+    fn profile_by_username(&self, at: Option<BlockHash>, username: Vec<u8>)
+        -> std::result::Result<Option<ProfileDto<AccountId>>, String>
+    {
+        Self::ensure_best_block(at)?;
+        Ok(Some(ProfileDto {
+            account: ACCOUNT1,
+            created: change(ACCOUNT1),
+            updated: None,
+            username: "Alice".into(),
+            ipfs_hash: "QmRAQB6YaCyidP37UdDnjFY5vQuiBrcqdyoW1CuDgwxkD4".into(),
+        }).filter(|profile| profile.username.as_bytes() == &username[..]))
+    }
+
+    fn posts_by_blog(&self, at: Option<BlockHash>, blog_id: BlogId, offset: u32, limit: u32)
+        -> std::result::Result<Vec<PostDto<AccountId>>, String>
+    {
+        Self::ensure_best_block(at)?;
+        let posts = if blog_id == 1 { vec![post(1), post(2)] } else { vec![] };
+        Ok(posts.into_iter().skip(offset as usize).take(limit as usize).collect())
+    }
+
+    fn feed(&self, at: Option<BlockHash>, account: AccountId, offset: u32, limit: u32)
+        -> std::result::Result<Vec<PostDto<AccountId>>, String>
+    {
+        Self::ensure_best_block(at)?;
+        let posts = if account == ACCOUNT2 { vec![post(2), post(1)] } else { vec![] };
+        Ok(posts.into_iter().skip(offset as usize).take(limit as usize).collect())
+    }
+}
+
+fn io_handler() -> IoHandler {
+    let mut io = IoHandler::new();
+    io.extend_with(SocialApi::to_delegate(Social::new(MockProvider)));
+    io
+}
+
+fn call(method: &str, params: Value) -> Value {
+    let request = json!({ "jsonrpc": "2.0", "method": method, "params": params, "id": 1 });
+    let response = io_handler().handle_request_sync(&request.to_string()).expect("a response to a call");
+    serde_json::from_str(&response).unwrap()
+}
+
+#[test]
+fn get_blog_by_slug_should_work() {
+    let response = call("social_getBlogBySlug", json!(["blog_slug"]));
+    assert_eq!(response["result"], serde_json::to_value(blog()).unwrap());
+    assert_eq!(response["result"]["postsCount"], json!(1));
+
+    let response = call("social_getBlogBySlug", json!(["unknown"]));
+    assert_eq!(response["result"], Value::Null);
+}
+
+#[test]
+fn get_profile_by_username_should_work() {
+    let response = call("social_getProfileByUsername", json!(["Alice"]));
+    assert_eq!(response["result"]["account"], json!(ACCOUNT1));
+    assert_eq!(response["result"]["username"], json!("Alice"));
+
+    let response = call("social_getProfileByUsername", json!(["Bob"]));
+    assert_eq!(response["result"], Value::Null);
+}
+
+#[test]
+fn get_posts_by_blog_should_work() {
+    let response = call("social_getPostsByBlog", json!([1, 1, 10]));
+    assert_eq!(response["result"], serde_json::to_value(vec![post(2)]).unwrap());
+    assert_eq!(response["result"][0]["extension"], json!("regularPost"));
+
+    let response = call("social_getPostsByBlog", json!([2, 0, 10]));
+    assert_eq!(response["result"], json!([]));
+}
+
+#[test]
+fn get_feed_should_work() {
+    let response = call("social_getFeed", json!([ACCOUNT2, 0, 10]));
+    assert_eq!(response["result"], serde_json::to_value(vec![post(2), post(1)]).unwrap());
+
+    let response = call("social_getFeed", json!([ACCOUNT1, 0, 10]));
+    assert_eq!(response["result"], json!([]));
+}
+
+#[test]
+fn provider_error_should_be_returned_as_runtime_error() {
+    let response = call("social_getFeed", json!([ACCOUNT2, 0, 10, 42]));
+    assert_eq!(response["error"]["code"], json!(RUNTIME_ERROR));
+    assert_eq!(response["error"]["data"], json!("Unknown block 42"));
+}
+
+impl_outer_origin! {
+    pub enum Origin for Runtime {}
+}
+
+/// Provides the types of pallet values converted to DTOs.
+#[derive(Clone, Eq, PartialEq)]
+pub struct Runtime;
+parameter_types! {
+    pub const BlockHashCount: u64 = 250;
+    pub const MaximumBlockWeight: Weight = 1024;
+    pub const MaximumBlockLength: u32 = 2 * 1024;
+    pub const AvailableBlockRatio: Perbill = Perbill::from_percent(75);
+    pub const MinimumPeriod: u64 = 5;
+}
+impl system::Trait for Runtime {
+    type Origin = Origin;
+    type Call = ();
+    type Index = u64;
+    type BlockNumber = u64;
+    type Hash = H256;
+    type Hashing = BlakeTwo256;
+    type AccountId = u64;
+    type Lookup = IdentityLookup<Self::AccountId>;
+    type Header = Header;
+    type Event = ();
+    type BlockHashCount = BlockHashCount;
+    type MaximumBlockWeight = MaximumBlockWeight;
+    type MaximumBlockLength = MaximumBlockLength;
+    type AvailableBlockRatio = AvailableBlockRatio;
+    type Version = ();
+    type ModuleToIndex = ();
+}
+impl pallet_timestamp::Trait for Runtime {
+    type Moment = u64;
+    type OnTimestampSet = ();
+    type MinimumPeriod = MinimumPeriod;
+}
+impl pallet_social::Trait for Runtime {
+    type Event = ();
+    type ScoringStrategy = pallet_social::scoring::DefaultScoringStrategy;
+}
+
+fn pallet_change(account: AccountId) -> Change<Runtime> {
+    Change { account, block: 1, time: 6000 }
+}
+
+#[test]
+fn blog_dto_should_be_converted_from_blog() {
+    let pallet_blog: Blog<Runtime> = Blog {
+        id: 1,
+        created: pallet_change(ACCOUNT1),
+        updated: Some(pallet_change(ACCOUNT2)),
+        owner: ACCOUNT1,
+        writers: vec![ACCOUNT2],
+        slug: b"blog_slug".to_vec(),
+        ipfs_hash: b"QmRAQB6YaCyidP37UdDnjFY5vQuiBrcqdyoW1CuDgwxkD4".to_vec(),
+        comments_restricted: true,
+        posts_count: 1,
+        followers_count: 2,
+        score: -5,
+    };
+
+    let dto: BlogDto<AccountId> = pallet_blog.into();
+    assert_eq!(dto, BlogDto {
+        updated: Some(change(ACCOUNT2)),
+        writers: vec![ACCOUNT2],
+        comments_restricted: true,
+        score: -5,
+        ..blog()
+    });
+}
+
+#[test]
+fn post_dto_should_be_converted_from_post() {
+    let pallet_post: Post<Runtime> = Post {
+        id: 2,
+        blog_id: 1,
+        created: pallet_change(ACCOUNT1),
+        updated: None,
+        extension: PostExtension::SharedComment(3),
+        ipfs_hash: b"QmRAQB6YaCyidP37UdDnjFY5vQuiBrcqdyoW1CuDgwxkD4".to_vec(),
+        comments_count: 4,
+        upvotes_count: 5,
+        downvotes_count: 6,
+        shares_count: 7,
+        score: 8,
+    };
+
+    let dto: PostDto<AccountId> = pallet_post.into();
+    assert_eq!(dto, PostDto {
+        extension: PostExtensionDto::SharedComment(3),
+        comments_count: 4,
+        upvotes_count: 5,
+        downvotes_count: 6,
+        shares_count: 7,
+        score: 8,
+        ..post(2)
+    });
+}
+
+#[test]
+fn profile_dto_should_be_converted_from_profile() {
+    let pallet_profile: Profile<Runtime> = Profile {
+        created: pallet_change(ACCOUNT1),
+        updated: None,
+        username: b"Alice".to_vec(),
+        ipfs_hash: b"QmRAQB6YaCyidP37UdDnjFY5vQuiBrcqdyoW1CuDgwxkD4".to_vec(),
+    };
+
+    let dto: ProfileDto<AccountId> = (ACCOUNT1, pallet_profile).into();
+    assert_eq!(dto, ProfileDto {
+        account: ACCOUNT1,
+        created: change(ACCOUNT1),
+        updated: None,
+        username: "Alice".into(),
+        ipfs_hash: "QmRAQB6YaCyidP37UdDnjFY5vQuiBrcqdyoW1CuDgwxkD4".into(),
+    });
+}
+
+#[test]
+fn invalid_utf8_should_be_replaced_in_dto() {
+    let pallet_profile: Profile<Runtime> = Profile {
+        created: pallet_change(ACCOUNT1),
+        updated: None,
+        username: vec![b'A', 0xff],
+        ipfs_hash: vec![],
+    };
+
+    let dto: ProfileDto<AccountId> = (ACCOUNT1, pallet_profile).into();
+    assert_eq!(dto.username, "A\u{fffd}");
+    assert_eq!(dto.ipfs_hash, "");
+}
+
+type TestBlock = Block<ExtrinsicWrapper<u64>>;
+
+#[test]
+fn block_id_should_fall_back_to_best_block() {
+    let best_hash = H256::repeat_byte(1);
+    let hash = H256::repeat_byte(2);
+
+    assert_eq!(block_id_or_best::<TestBlock, _>(None, || best_hash), BlockId::Hash(best_hash));
+    assert_eq!(block_id_or_best::<TestBlock, _>(Some(hash), || best_hash), BlockId::Hash(hash));
+}
+
+#[test]
+fn api_error_should_be_converted_to_text() {
+    assert_eq!(api_result::<u32, &str>(Ok(1)), Ok(1));
+    assert_eq!(api_result::<u32, &str>(Err("Unknown block")), Err("\"Unknown block\"".to_string()));
+}
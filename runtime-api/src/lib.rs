@@ -0,0 +1,29 @@
+//! Runtime API of the social pallet, to let clients read paginated feeds and listings
+//! instead of whole storage indexes.
+//!
+//! A runtime implements it by delegating to the paginated getters of `pallet_social::Module`,
+//! such as `Module::posts_by_blog`. Limits greater than `pallet_social::MAX_PAGE_LIMIT` are lowered to it.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use sp_std::prelude::*;
+use pallet_social::{BlogId, PostId, Blog, Post, Comment, Profile};
+
+sp_api::decl_runtime_apis! {
+    pub trait SocialApi<Runtime> where Runtime: pallet_social::Trait {
+        fn blog_by_slug(slug: Vec<u8>) -> Option<Blog<Runtime>>;
+
+        fn profile_by_username(username: Vec<u8>) -> Option<(Runtime::AccountId, Profile<Runtime>)>;
+
+        /// Posts of the blogs followed by an account, newest first.
+        fn feed(account: Runtime::AccountId, offset: u32, limit: u32) -> Vec<Post<Runtime>>;
+
+        fn posts_by_blog(blog_id: BlogId, offset: u32, limit: u32) -> Vec<Post<Runtime>>;
+
+        fn comments_by_post(post_id: PostId, offset: u32, limit: u32) -> Vec<Comment<Runtime>>;
+
+        fn followers(account: Runtime::AccountId, offset: u32, limit: u32) -> Vec<Runtime::AccountId>;
+
+        fn blog_followers(blog_id: BlogId, offset: u32, limit: u32) -> Vec<Runtime::AccountId>;
+    }
+}
@@ -9,6 +9,8 @@ pub const DEFAULT_USERNAME_MAX_LEN: u32 = 50;
 pub const DEFAULT_BLOG_MAX_LEN: u32 = 1_000;
 pub const DEFAULT_POST_MAX_LEN: u32 = 10_000;
 pub const DEFAULT_COMMENT_MAX_LEN: u32 = 1_000;
+pub const DEFAULT_MAX_COMMENT_DEPTH: u32 = 10;
+pub const DEFAULT_MAX_EDIT_HISTORY_RECORDS: u32 = 100;
 
 pub const DEFAULT_FOLLOW_BLOG_ACTION_WEIGHT: i16 = 7;
 pub const DEFAULT_FOLLOW_ACCOUNT_ACTION_WEIGHT: i16 = 3;
@@ -21,3 +23,11 @@ pub const DEFAULT_CREATE_COMMENT_ACTION_WEIGHT: i16 = 5;
 pub const DEFAULT_UPVOTE_COMMENT_ACTION_WEIGHT: i16 = 4;
 pub const DEFAULT_DOWNVOTE_COMMENT_ACTION_WEIGHT: i16 = -2;
 pub const DEFAULT_SHARE_COMMENT_ACTION_WEIGHT: i16 = 3;
+
+// Every account starts with reputation 1, so these defaults do not restrict anything.
+pub const DEFAULT_MIN_REPUTATION_TO_DOWNVOTE: u32 = 1;
+pub const DEFAULT_MIN_REPUTATION_TO_CREATE_BLOG: u32 = 1;
+pub const DEFAULT_MIN_REPUTATION_TO_MODERATE: u32 = 1;
+
+/// About one day with 6 seconds per block.
+pub const DEFAULT_SCORE_HALF_LIFE: u32 = 14_400;
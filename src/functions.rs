@@ -2,6 +2,8 @@ use super::*;
 
 // use sp_std::prelude::*;
 use frame_support::{dispatch::DispatchResult};
+use sp_runtime::{traits::SaturatedConversion, DispatchError};
+use sp_std::collections::btree_set::BTreeSet;
 // use system::{self};
 
 impl<T: Trait> Module<T> {
@@ -35,6 +37,262 @@ impl<T: Trait> Module<T> {
         reaction_id
     }
 
+    pub fn reaction_ids_by_post_id(post_id: PostId) -> Vec<ReactionId> {
+        Self::reaction_ids_by_target(ReactionTarget::Post(post_id))
+    }
+
+    pub fn reaction_ids_by_comment_id(comment_id: CommentId) -> Vec<ReactionId> {
+        Self::reaction_ids_by_target(ReactionTarget::Comment(comment_id))
+    }
+
+    /// Upvote or downvote a reaction of `kind` is scored as, if any.
+    pub fn scoring_kind_of_reaction(kind: ReactionKind) -> Option<ReactionKind> {
+        match kind {
+            ReactionKind::Emoji(emoji) => Self::emoji_scoring_kind(emoji),
+            _ => Some(kind),
+        }
+    }
+
+    pub fn post_scoring_action(kind: ReactionKind) -> Option<ScoringAction> {
+        match Self::scoring_kind_of_reaction(kind) {
+            Some(ReactionKind::Upvote) => Some(ScoringAction::UpvotePost),
+            Some(ReactionKind::Downvote) => Some(ScoringAction::DownvotePost),
+            _ => None,
+        }
+    }
+
+    pub fn comment_scoring_action(kind: ReactionKind) -> Option<ScoringAction> {
+        match Self::scoring_kind_of_reaction(kind) {
+            Some(ReactionKind::Upvote) => Some(ScoringAction::UpvoteComment),
+            Some(ReactionKind::Downvote) => Some(ScoringAction::DownvoteComment),
+            _ => None,
+        }
+    }
+
+    /// Revert the score given to a post by a reaction of `account`.
+    /// Both votes are checked as the scoring kind of an emoji could have changed since it was given.
+    fn revert_post_reaction_score(account: T::AccountId, post: &mut Post<T>) -> DispatchResult {
+        Self::revert_post_score(account.clone(), post, ScoringAction::UpvotePost)?;
+        Self::revert_post_score(account, post, ScoringAction::DownvotePost)
+    }
+
+    /// Revert the score given to a comment by a reaction of `account`.
+    fn revert_comment_reaction_score(account: T::AccountId, comment: &mut Comment<T>) -> DispatchResult {
+        Self::revert_comment_score(account.clone(), comment, ScoringAction::UpvoteComment)?;
+        Self::revert_comment_score(account, comment, ScoringAction::DownvoteComment)
+    }
+
+    fn ensure_can_react_with_kind(owner: &T::AccountId, kind: ReactionKind) -> DispatchResult {
+        if Self::scoring_kind_of_reaction(kind) == Some(ReactionKind::Downvote) {
+            Self::ensure_reputation_at_least(owner, Self::min_reputation_to_downvote(), Error::<T>::InsufficientReputationToDownvote)?;
+        }
+        Ok(())
+    }
+
+    pub fn ensure_reaction_target_exists(target: &ReactionTarget<T::AccountId>) -> DispatchResult {
+        match *target {
+            ReactionTarget::Post(post_id) => {
+                ensure!(<PostById<T>>::exists(post_id), Error::<T>::PostNotFound);
+            },
+            ReactionTarget::Comment(comment_id) => {
+                ensure!(<CommentById<T>>::exists(comment_id), Error::<T>::CommentNotFound);
+            },
+            ReactionTarget::Blog(blog_id) => {
+                ensure!(<BlogById<T>>::exists(blog_id), Error::<T>::BlogNotFound);
+            },
+            ReactionTarget::Profile(ref account) => {
+                let has_profile = Self::social_account_by_id(account.clone())
+                    .map_or(false, |social_account| social_account.profile.is_some());
+                ensure!(has_profile, Error::<T>::ProfileDoesNotExist);
+            },
+        }
+        Ok(())
+    }
+
+    fn already_reacted_error(target: &ReactionTarget<T::AccountId>) -> Error<T> {
+        match target {
+            ReactionTarget::Post(_) => Error::<T>::AccountAlreadyReactedToPost,
+            ReactionTarget::Comment(_) => Error::<T>::AccountAlreadyReactedToComment,
+            _ => Error::<T>::AccountAlreadyReacted,
+        }
+    }
+
+    fn not_yet_reacted_error(target: &ReactionTarget<T::AccountId>) -> Error<T> {
+        match target {
+            ReactionTarget::Post(_) => Error::<T>::AccountNotYetReactedToPost,
+            ReactionTarget::Comment(_) => Error::<T>::AccountNotYetReactedToComment,
+            _ => Error::<T>::ReactionByAccountNotFound,
+        }
+    }
+
+    fn reaction_to_delete_not_found_error(target: &ReactionTarget<T::AccountId>) -> Error<T> {
+        match target {
+            ReactionTarget::Post(_) => Error::<T>::PostReactionByAccountNotFound,
+            ReactionTarget::Comment(_) => Error::<T>::CommentReactionByAccountNotFound,
+            _ => Error::<T>::ReactionByAccountNotFound,
+        }
+    }
+
+    /// Upvote and downvote counters after replacing a reaction of `old_kind` with one of `new_kind`.
+    fn changed_vote_counters(
+        (upvotes, downvotes): (u32, u32),
+        old_kind: Option<ReactionKind>,
+        new_kind: Option<ReactionKind>,
+        overflow_upvoting: Error<T>,
+        overflow_downvoting: Error<T>,
+    ) -> Result<(u32, u32), Error<T>> {
+        let (mut upvotes, mut downvotes) = (upvotes, downvotes);
+        match old_kind {
+            Some(ReactionKind::Upvote) => {
+                upvotes = upvotes.checked_sub(1).ok_or(Error::<T>::UnderflowRemovingUpvote)?;
+            },
+            Some(ReactionKind::Downvote) => {
+                downvotes = downvotes.checked_sub(1).ok_or(Error::<T>::UnderflowRemovingDownvote)?;
+            },
+            _ => (),
+        }
+        match new_kind {
+            Some(ReactionKind::Upvote) => {
+                upvotes = upvotes.checked_add(1).ok_or(overflow_upvoting)?;
+            },
+            Some(ReactionKind::Downvote) => {
+                downvotes = downvotes.checked_add(1).ok_or(overflow_downvoting)?;
+            },
+            _ => (),
+        }
+        Ok((upvotes, downvotes))
+    }
+
+    /// Replace a reaction of `old_kind` on the target with one of `new_kind`, updating the counters
+    /// and the score given to the target. `None` means there is no reaction before or after the change.
+    /// Counters are checked before anything is written. Blogs and profiles have no reaction counters and score.
+    fn change_target_reaction(
+        owner: T::AccountId,
+        target: &ReactionTarget<T::AccountId>,
+        old_kind: Option<ReactionKind>,
+        new_kind: Option<ReactionKind>
+    ) -> DispatchResult {
+        match *target {
+            ReactionTarget::Post(post_id) => {
+                let ref mut post = Self::post_by_id(post_id).ok_or(Error::<T>::PostNotFound)?;
+                let (upvotes, downvotes) = Self::changed_vote_counters(
+                    (post.upvotes_count, post.downvotes_count), old_kind, new_kind,
+                    Error::<T>::OverflowUpvotingPost, Error::<T>::OverflowDownvotingPost
+                )?;
+                post.upvotes_count = upvotes;
+                post.downvotes_count = downvotes;
+
+                if old_kind.is_some() {
+                    Self::revert_post_reaction_score(owner.clone(), post)?;
+                }
+                if let Some(action) = new_kind.and_then(Self::post_scoring_action) {
+                    if post.created.account != owner {
+                        Self::change_post_score(owner, post, action)?;
+                    }
+                }
+                <PostById<T>>::insert(post_id, post);
+            },
+            ReactionTarget::Comment(comment_id) => {
+                let ref mut comment = Self::comment_by_id(comment_id).ok_or(Error::<T>::CommentNotFound)?;
+                let (upvotes, downvotes) = Self::changed_vote_counters(
+                    (comment.upvotes_count, comment.downvotes_count), old_kind, new_kind,
+                    Error::<T>::OverflowUpvotingComment, Error::<T>::OverflowDownvotingComment
+                )?;
+                comment.upvotes_count = upvotes;
+                comment.downvotes_count = downvotes;
+
+                if old_kind.is_some() {
+                    Self::revert_comment_reaction_score(owner.clone(), comment)?;
+                }
+                if let Some(action) = new_kind.and_then(Self::comment_scoring_action) {
+                    if comment.created.account != owner {
+                        Self::change_comment_score(owner, comment, action)?;
+                    }
+                }
+                <CommentById<T>>::insert(comment_id, comment);
+            },
+            ReactionTarget::Blog(_) | ReactionTarget::Profile(_) => (),
+        }
+        Ok(())
+    }
+
+    /// Deleted comments keep their reactions, which can still be deleted, but cannot get new ones.
+    pub fn ensure_reaction_target_not_deleted(target: &ReactionTarget<T::AccountId>) -> DispatchResult {
+        if let ReactionTarget::Comment(comment_id) = *target {
+            let is_deleted = Self::comment_by_id(comment_id).map_or(false, |comment| comment.is_deleted());
+            ensure!(!is_deleted, Error::<T>::CommentIsDeleted);
+        }
+        Ok(())
+    }
+
+    /// Create a reaction of `owner` on `target` and return its id.
+    /// `do_update_reaction` and `do_delete_reaction` return the id of the affected reaction too.
+    pub fn do_react(owner: T::AccountId, target: ReactionTarget<T::AccountId>, kind: ReactionKind) -> Result<ReactionId, DispatchError> {
+        ensure!(
+            !<ReactionIdByAccount<T>>::exists((owner.clone(), target.clone())),
+            Self::already_reacted_error(&target)
+        );
+        Self::ensure_can_react_with_kind(&owner, kind)?;
+        Self::ensure_reaction_target_exists(&target)?;
+        Self::ensure_reaction_target_not_deleted(&target)?;
+        let new_count = Self::reaction_count_by_target((target.clone(), kind)).checked_add(1).ok_or(Error::<T>::OverflowCountingReactions)?;
+
+        Self::change_target_reaction(owner.clone(), &target, None, Some(kind))?;
+        <ReactionCountByTarget<T>>::insert((target.clone(), kind), new_count);
+
+        let reaction_id = Self::new_reaction(owner.clone(), kind);
+        <ReactionIdsByTarget<T>>::mutate(target.clone(), |ids| ids.push(reaction_id));
+        <ReactionIdByAccount<T>>::insert((owner.clone(), target.clone()), reaction_id);
+
+        Self::deposit_event(RawEvent::ReactionCreated(owner, target, reaction_id));
+        Ok(reaction_id)
+    }
+
+    pub fn do_update_reaction(owner: T::AccountId, target: ReactionTarget<T::AccountId>, new_kind: ReactionKind) -> Result<ReactionId, DispatchError> {
+        let reaction_id = Self::reaction_id_by_account((owner.clone(), target.clone()))
+            .ok_or_else(|| Self::not_yet_reacted_error(&target))?;
+        let mut reaction = Self::reaction_by_id(reaction_id).ok_or(Error::<T>::ReactionNotFound)?;
+        Self::ensure_reaction_target_exists(&target)?;
+        Self::ensure_reaction_target_not_deleted(&target)?;
+
+        ensure!(owner == reaction.created.account, Error::<T>::NotAReactionOwner);
+        ensure!(reaction.kind != new_kind, Error::<T>::NewReactionKindNotDiffer);
+        Self::ensure_can_react_with_kind(&owner, new_kind)?;
+        let old_count = Self::reaction_count_by_target((target.clone(), reaction.kind)).checked_sub(1).ok_or(Error::<T>::UnderflowCountingReactions)?;
+        let new_count = Self::reaction_count_by_target((target.clone(), new_kind)).checked_add(1).ok_or(Error::<T>::OverflowCountingReactions)?;
+
+        Self::change_target_reaction(owner.clone(), &target, Some(reaction.kind), Some(new_kind))?;
+        <ReactionCountByTarget<T>>::insert((target.clone(), reaction.kind), old_count);
+        <ReactionCountByTarget<T>>::insert((target.clone(), new_kind), new_count);
+
+        reaction.kind = new_kind;
+        reaction.updated = Some(Self::new_change(owner.clone()));
+        <ReactionById<T>>::insert(reaction_id, reaction);
+
+        Self::deposit_event(RawEvent::ReactionUpdated(owner, target, reaction_id));
+        Ok(reaction_id)
+    }
+
+    pub fn do_delete_reaction(owner: T::AccountId, target: ReactionTarget<T::AccountId>) -> Result<ReactionId, DispatchError> {
+        let reaction_id = Self::reaction_id_by_account((owner.clone(), target.clone()))
+            .ok_or_else(|| Self::reaction_to_delete_not_found_error(&target))?;
+        let reaction = Self::reaction_by_id(reaction_id).ok_or(Error::<T>::ReactionNotFound)?;
+        Self::ensure_reaction_target_exists(&target)?;
+
+        ensure!(owner == reaction.created.account, Error::<T>::NotAReactionOwner);
+        let old_count = Self::reaction_count_by_target((target.clone(), reaction.kind)).checked_sub(1).ok_or(Error::<T>::UnderflowCountingReactions)?;
+
+        Self::change_target_reaction(owner.clone(), &target, Some(reaction.kind), None)?;
+        <ReactionCountByTarget<T>>::insert((target.clone(), reaction.kind), old_count);
+
+        <ReactionById<T>>::remove(reaction_id);
+        <ReactionIdsByTarget<T>>::mutate(target.clone(), |ids| Self::vec_remove_on(ids, reaction_id));
+        <ReactionIdByAccount<T>>::remove((owner.clone(), target.clone()));
+
+        Self::deposit_event(RawEvent::ReactionDeleted(owner, target, reaction_id));
+        Ok(reaction_id)
+    }
+
     pub fn add_blog_follower_and_insert_blog(
         follower: T::AccountId,
         blog: &mut Blog<T>,
@@ -48,11 +306,11 @@ impl<T: Trait> Module<T> {
             .ok_or(Error::<T>::OverflowFollowingBlog)?;
 
         blog.followers_count = blog.followers_count.checked_add(1).ok_or(Error::<T>::OverflowFollowingBlog)?;
-        if blog.created.account != follower {
-            let author = blog.created.account.clone();
-            let score_diff = Self::get_score_diff(social_account.reputation, ScoringAction::FollowBlog);
+        if blog.owner != follower {
+            let author = blog.owner.clone();
+            let score_diff = T::ScoringStrategy::score_diff(&social_account, ScoringAction::FollowBlog);
             blog.score = blog.score.checked_add(score_diff as i32).ok_or(Error::<T>::OutOfBoundsUpdatingBlogScore)?;
-            Self::change_social_account_reputation(author, follower.clone(), score_diff, ScoringAction::FollowBlog)?;
+            Self::change_social_account_reputation(author, follower.clone(), score_diff, ScoringAction::FollowBlog, ScoredEntity::Blog(blog_id))?;
         }
 
         <BlogById<T>>::insert(blog_id, blog);
@@ -70,6 +328,56 @@ impl<T: Trait> Module<T> {
         Ok(())
     }
 
+    /// Unfollow a blog on behalf of `follower` reverting the score and reputation
+    /// given for following it. The blog itself is not saved to storage.
+    pub fn remove_blog_follower(follower: T::AccountId, blog: &mut Blog<T>) -> DispatchResult {
+        let blog_id = blog.id;
+        let mut social_account = Self::social_account_by_id(follower.clone()).ok_or(Error::<T>::SocialAccountNotFound)?;
+        social_account.following_blogs_count = social_account.following_blogs_count
+            .checked_sub(1)
+            .ok_or(Error::<T>::UnderflowUnfollowingBlog)?;
+        blog.followers_count = blog.followers_count.checked_sub(1).ok_or(Error::<T>::UnderflowUnfollowingBlog)?;
+
+        // The follow credited the owner of the blog at the time of following,
+        // who is not the current one if the ownership has been transferred since then:
+        let follow_diff = Self::reputation_diff_by_key(&(follower.clone(), ScoringAction::FollowBlog, ScoredEntity::Blog(blog_id)));
+        if let Some((_, score_diff)) = follow_diff {
+            blog.score = blog.score.checked_sub(score_diff as i32).ok_or(Error::<T>::OutOfBoundsUpdatingBlogScore)?;
+        }
+
+        <SocialAccountById<T>>::insert(follower.clone(), social_account);
+        if let Some((author, score_diff)) = follow_diff {
+            Self::change_social_account_reputation(author, follower.clone(), score_diff * -1, ScoringAction::FollowBlog, ScoredEntity::Blog(blog_id))?;
+        }
+
+        <BlogsFollowedByAccount<T>>::mutate(follower.clone(), |blog_ids| Self::vec_remove_on(blog_ids, blog_id));
+        <BlogFollowers<T>>::mutate(blog_id, |account_ids| Self::vec_remove_on(account_ids, follower.clone()));
+        <BlogFollowedByAccount<T>>::remove((follower, blog_id));
+
+        Ok(())
+    }
+
+    /// Check that `remove_blog_follower` does not fail for any follower of a blog,
+    /// so the followers can be removed one by one without leaving a blog half deleted.
+    /// Reverting the reputation of a follow cannot fail, as reputation is not reduced below 1.
+    pub fn ensure_blog_followers_removable(blog: &Blog<T>) -> DispatchResult {
+        let mut followers_count = blog.followers_count;
+        let mut blog_score = blog.score;
+
+        for follower in Self::blog_followers(blog.id) {
+            let social_account = Self::social_account_by_id(follower.clone()).ok_or(Error::<T>::SocialAccountNotFound)?;
+            social_account.following_blogs_count.checked_sub(1).ok_or(Error::<T>::UnderflowUnfollowingBlog)?;
+            followers_count = followers_count.checked_sub(1).ok_or(Error::<T>::UnderflowUnfollowingBlog)?;
+
+            let follow_key = (follower, ScoringAction::FollowBlog, ScoredEntity::Blog(blog.id));
+            if let Some((_, score_diff)) = Self::reputation_diff_by_key(&follow_key) {
+                blog_score = blog_score.checked_sub(score_diff as i32).ok_or(Error::<T>::OutOfBoundsUpdatingBlogScore)?;
+            }
+        }
+
+        Ok(())
+    }
+
     pub fn get_or_new_social_account(account: T::AccountId) -> SocialAccount<T> {
         if let Some(social_account) = Self::social_account_by_id(account) {
             social_account
@@ -84,6 +392,72 @@ impl<T: Trait> Module<T> {
         }
     }
 
+    pub fn ensure_reputation_at_least(account: &T::AccountId, min_reputation: u32, error: Error<T>) -> DispatchResult {
+        let reputation = Self::social_account_by_id(account.clone())
+            .map(|social_account| social_account.reputation)
+            .unwrap_or(1);
+        ensure!(reputation >= min_reputation, error);
+
+        Ok(())
+    }
+
+    pub fn reputation_tiers() -> ReputationTiers {
+        ReputationTiers {
+            downvote: Self::min_reputation_to_downvote(),
+            create_blog: Self::min_reputation_to_create_blog(),
+            moderate: Self::min_reputation_to_moderate(),
+        }
+    }
+
+    /// Check whether an account is allowed to perform an action in a blog.
+    /// Blog owner can do anything, other accounts are checked against `Blog::writers` and their blog roles.
+    pub fn has_blog_permission(account: &T::AccountId, blog: &Blog<T>, permission: BlogPermission) -> bool {
+        if blog.is_owner(account) {
+            return true;
+        }
+
+        let roles = Self::blog_roles_by_account((blog.id, account.clone()));
+        let has_role = |role: BlogRole| roles.contains(&role);
+        let is_writer = blog.writers.contains(account) || has_role(BlogRole::Writer);
+
+        match permission {
+            BlogPermission::ManageBlog => false,
+            BlogPermission::CreatePost => is_writer,
+            BlogPermission::UpdateAnyPost => is_writer || has_role(BlogRole::Editor),
+            BlogPermission::CreateComment => !blog.comments_restricted || !roles.is_empty() || is_writer,
+            BlogPermission::DeleteAnyComment => has_role(BlogRole::Moderator),
+        }
+    }
+
+    pub fn ensure_writers_unique(writers: &[T::AccountId]) -> DispatchResult {
+        for (i, writer) in writers.iter().enumerate() {
+            ensure!(!writers[i + 1..].contains(writer), Error::<T>::DuplicateBlogWriters);
+        }
+        Ok(())
+    }
+
+    /// Update `BlogIdsByWriter` only for writers that were added to or removed from a blog.
+    /// A removed writer stays indexed while it has `BlogRole::Writer` in the blog.
+    pub fn update_blog_ids_by_writer(blog_id: BlogId, old_writers: &[T::AccountId], new_writers: &[T::AccountId]) {
+        for writer in old_writers.iter().filter(|writer| !new_writers.contains(*writer)) {
+            if !Self::blog_roles_by_account((blog_id, writer.clone())).contains(&BlogRole::Writer) {
+                Self::remove_blog_id_by_writer(writer.clone(), blog_id);
+            }
+        }
+        for writer in new_writers.iter().filter(|writer| !old_writers.contains(*writer)) {
+            Self::add_blog_id_by_writer(writer.clone(), blog_id);
+        }
+    }
+
+    /// Index a blog under a writer, unless it is indexed already via `Blog::writers` or `BlogRole::Writer`.
+    pub fn add_blog_id_by_writer(writer: T::AccountId, blog_id: BlogId) {
+        <BlogIdsByWriter<T>>::mutate(writer, |ids| if !ids.contains(&blog_id) { ids.push(blog_id) });
+    }
+
+    pub fn remove_blog_id_by_writer(writer: T::AccountId, blog_id: BlogId) {
+        <BlogIdsByWriter<T>>::mutate(writer, |ids| Self::vec_remove_on(ids, blog_id));
+    }
+
     pub fn vec_remove_on<F: PartialEq>(vector: &mut Vec<F>, element: F) {
         if let Some(index) = vector.iter().position(|x| *x == element) {
             vector.swap_remove(index);
@@ -99,10 +473,11 @@ impl<T: Trait> Module<T> {
 
         if post.created.account != account {
             if let Some(score_diff) = Self::post_score_by_account((account.clone(), post_id, action)) {
-                let reputation_diff = Self::account_reputation_diff_by_account((account.clone(), post.created.account.clone(), action)).ok_or(Error::<T>::ReputationDiffNotFound)?;
+                let reputation_diff = Self::reputation_diff(account.clone(), action, ScoredEntity::Post(post_id))?;
                 post.score = post.score.checked_add(score_diff as i32 * -1).ok_or(Error::<T>::OutOfBoundsRevertingPostScore)?;
                 blog.score = blog.score.checked_add(score_diff as i32 * -1).ok_or(Error::<T>::OutOfBoundsRevertingBlogScore)?;
-                Self::change_social_account_reputation(post.created.account.clone(), account.clone(), reputation_diff * -1, action)?;
+                Self::change_post_hot_score(post_id, score_diff * -1);
+                Self::change_social_account_reputation(post.created.account.clone(), account.clone(), reputation_diff * -1, action, ScoredEntity::Post(post_id))?;
                 <PostScoreByAccount<T>>::remove((account.clone(), post_id, action));
             } else {
                 match action {
@@ -118,10 +493,11 @@ impl<T: Trait> Module<T> {
                     },
                     _ => (),
                 }
-                let score_diff = Self::get_score_diff(social_account.reputation, action);
+                let score_diff = T::ScoringStrategy::score_diff(&social_account, action);
                 post.score = post.score.checked_add(score_diff as i32).ok_or(Error::<T>::OutOfBoundsUpdatingPostScore)?;
                 blog.score = blog.score.checked_add(score_diff as i32).ok_or(Error::<T>::OutOfBoundsUpdatingBlogScore)?;
-                Self::change_social_account_reputation(post.created.account.clone(), account.clone(), score_diff, action)?;
+                Self::change_post_hot_score(post_id, score_diff);
+                Self::change_social_account_reputation(post.created.account.clone(), account.clone(), score_diff, action, ScoredEntity::Post(post_id))?;
                 <PostScoreByAccount<T>>::insert((account.clone(), post_id, action), score_diff);
             }
 
@@ -140,9 +516,10 @@ impl<T: Trait> Module<T> {
 
         if comment.created.account != account {
             if let Some(score_diff) = Self::comment_score_by_account((account.clone(), comment_id, action)) {
-                let reputation_diff = Self::account_reputation_diff_by_account((account.clone(), comment.created.account.clone(), action)).ok_or(Error::<T>::ReputationDiffNotFound)?;
+                let reputation_diff = Self::reputation_diff(account.clone(), action, ScoredEntity::Comment(comment_id))?;
                 comment.score = comment.score.checked_add(score_diff as i32 * -1).ok_or(Error::<T>::OutOfBoundsRevertingCommentScore)?;
-                Self::change_social_account_reputation(comment.created.account.clone(), account.clone(), reputation_diff * -1, action)?;
+                Self::change_comment_hot_score(comment_id, score_diff * -1);
+                Self::change_social_account_reputation(comment.created.account.clone(), account.clone(), reputation_diff * -1, action, ScoredEntity::Comment(comment_id))?;
                 <CommentScoreByAccount<T>>::remove((account.clone(), comment_id, action));
             } else {
                 match action {
@@ -162,9 +539,10 @@ impl<T: Trait> Module<T> {
                     }
                     _ => (),
                 }
-                let score_diff = Self::get_score_diff(social_account.reputation, action);
+                let score_diff = T::ScoringStrategy::score_diff(&social_account, action);
                 comment.score = comment.score.checked_add(score_diff as i32).ok_or(Error::<T>::OutOfBoundsUpdatingCommentScore)?;
-                Self::change_social_account_reputation(comment.created.account.clone(), account.clone(), score_diff, action)?;
+                Self::change_comment_hot_score(comment_id, score_diff);
+                Self::change_social_account_reputation(comment.created.account.clone(), account.clone(), score_diff, action, ScoredEntity::Comment(comment_id))?;
                 <CommentScoreByAccount<T>>::insert((account, comment_id, action), score_diff);
             }
             <CommentById<T>>::insert(comment_id, comment.clone());
@@ -173,7 +551,90 @@ impl<T: Trait> Module<T> {
         Ok(())
     }
 
-    pub fn change_social_account_reputation(account: T::AccountId, scorer: T::AccountId, mut score_diff: i16, action: ScoringAction) -> DispatchResult {
+    /// Decay a hot score `value` from block `from` to block `to`.
+    /// The value is halved for every full half-life passed, the rest of a half-life
+    /// is approximated linearly.
+    pub fn decay_hot_score(value: i64, from: T::BlockNumber, to: T::BlockNumber) -> i64 {
+        let half_life = Self::score_half_life() as u64;
+        let elapsed = to.saturated_into::<u64>().saturating_sub(from.saturated_into::<u64>());
+        if half_life == 0 || elapsed == 0 {
+            return value;
+        }
+
+        let halvings = elapsed / half_life;
+        if halvings >= 63 {
+            return 0;
+        }
+        let value = value / (1i64 << halvings);
+
+        let remainder = (elapsed % half_life) as i128;
+        let decayed = value as i128 - value as i128 * remainder / (2 * half_life as i128);
+        decayed as i64
+    }
+
+    fn apply_hot_score_diff(hot_score: Option<HotScore<T>>, score_diff: i16) -> HotScore<T> {
+        let now = <system::Module<T>>::block_number();
+        let value = hot_score
+            .map(|s| Self::decay_hot_score(s.value, s.updated_at, now))
+            .unwrap_or(0);
+
+        HotScore {
+            value: value.saturating_add(score_diff as i64 * HOT_SCORE_PRECISION),
+            updated_at: now,
+        }
+    }
+
+    /// Reverted score diffs are subtracted from the already decayed value,
+    /// so a hot score may become negative after reverting an old action.
+    pub fn change_post_hot_score(post_id: PostId, score_diff: i16) {
+        let hot_score = Self::apply_hot_score_diff(Self::post_hot_score_by_id(post_id), score_diff);
+        <PostHotScoreById<T>>::insert(post_id, hot_score);
+    }
+
+    pub fn change_comment_hot_score(comment_id: CommentId, score_diff: i16) {
+        let hot_score = Self::apply_hot_score_diff(Self::comment_hot_score_by_id(comment_id), score_diff);
+        <CommentHotScoreById<T>>::insert(comment_id, hot_score);
+    }
+
+    /// Hot score of a post as of block `now`, multiplied by `HOT_SCORE_PRECISION`.
+    pub fn hot_score(post_id: PostId, now: T::BlockNumber) -> i64 {
+        Self::post_hot_score_by_id(post_id)
+            .map(|s| Self::decay_hot_score(s.value, s.updated_at, now))
+            .unwrap_or(0)
+    }
+
+    /// Hot score of a comment as of block `now`, multiplied by `HOT_SCORE_PRECISION`.
+    pub fn comment_hot_score(comment_id: CommentId, now: T::BlockNumber) -> i64 {
+        Self::comment_hot_score_by_id(comment_id)
+            .map(|s| Self::decay_hot_score(s.value, s.updated_at, now))
+            .unwrap_or(0)
+    }
+
+    /// Revert the score given to a post by `account` with `action`, if there is any.
+    pub fn revert_post_score(account: T::AccountId, post: &mut Post<T>, action: ScoringAction) -> DispatchResult {
+        if Self::post_score_by_account((account.clone(), post.id, action)).is_some() {
+            Self::change_post_score(account, post, action)?;
+        }
+        Ok(())
+    }
+
+    /// Revert the score given to a comment by `account` with `action`, if there is any.
+    pub fn revert_comment_score(account: T::AccountId, comment: &mut Comment<T>, action: ScoringAction) -> DispatchResult {
+        if Self::comment_score_by_account((account.clone(), comment.id, action)).is_some() {
+            Self::change_comment_score(account, comment, action)?;
+        }
+        Ok(())
+    }
+
+    /// Change reputation of `account` for `action` performed by `scorer` on `entity`.
+    /// The first call records the diff in the reputation ledger, the next one with the same key reverts it.
+    pub fn change_social_account_reputation(
+        account: T::AccountId,
+        scorer: T::AccountId,
+        mut score_diff: i16,
+        action: ScoringAction,
+        entity: ScoredEntity<T::AccountId>,
+    ) -> DispatchResult {
         let mut social_account = Self::get_or_new_social_account(account.clone());
 
         if social_account.reputation as i64 + score_diff as i64 <= 1 {
@@ -187,10 +648,12 @@ impl<T: Trait> Module<T> {
             social_account.reputation = social_account.reputation.checked_add(score_diff as u32).ok_or(Error::<T>::OutOfBoundsUpdatingAccountReputation)?;
         }
 
-        if Self::account_reputation_diff_by_account((scorer.clone(), account.clone(), action)).is_some() {
-            <AccountReputationDiffByAccount<T>>::remove((scorer.clone(), account.clone(), action));
+        let key = (scorer, action, entity);
+        if <ReputationDiffByKey<T>>::exists(&key) {
+            <ReputationDiffByKey<T>>::remove(&key);
+            <ReputationDiffKeysByAccount<T>>::mutate(account.clone(), |keys| Self::vec_remove_on(keys, key));
         } else {
-            <AccountReputationDiffByAccount<T>>::insert((scorer.clone(), account.clone(), action), score_diff);
+            Self::insert_reputation_diff(account.clone(), key, score_diff);
         }
 
         <SocialAccountById<T>>::insert(account.clone(), social_account.clone());
@@ -200,6 +663,57 @@ impl<T: Trait> Module<T> {
         Ok(())
     }
 
+    pub fn insert_reputation_diff(account: T::AccountId, key: ReputationDiffKey<T::AccountId>, score_diff: i16) {
+        <ReputationDiffByKey<T>>::insert(&key, (account.clone(), score_diff));
+        <ReputationDiffKeysByAccount<T>>::mutate(account, |keys| keys.push(key));
+    }
+
+    /// Reputation diff recorded in the ledger for `action` performed by `scorer` on `entity`.
+    pub fn reputation_diff(scorer: T::AccountId, action: ScoringAction, entity: ScoredEntity<T::AccountId>) -> Result<i16, Error<T>> {
+        Self::reputation_diff_by_key((scorer, action, entity))
+            .map(|(_, score_diff)| score_diff)
+            .ok_or(Error::<T>::ReputationDiffNotFound)
+    }
+
+    /// Compare the stored reputation of an account with the one derived from the reputation ledger:
+    /// a new account starts with reputation 1 and every ledger entry adds its diff to it.
+    pub fn audit_reputation(account: T::AccountId) -> ReputationAudit<T> {
+        let stored_reputation = Self::social_account_by_id(account.clone())
+            .map(|social_account| social_account.reputation)
+            .unwrap_or(1);
+
+        let mut expected_reputation: i64 = 1;
+        let mut ledger_entries_count: u32 = 0;
+        let mut missing_ledger_entries = Vec::new();
+
+        for key in Self::reputation_diff_keys_by_account(account.clone()) {
+            match Self::reputation_diff_by_key(&key) {
+                Some((credited_account, diff)) if credited_account == account => {
+                    expected_reputation += diff as i64;
+                    ledger_entries_count += 1;
+                },
+                _ => missing_ledger_entries.push(key),
+            }
+        }
+
+        let expected_reputation = if expected_reputation < 1 {
+            1
+        } else if expected_reputation > u32::max_value() as i64 {
+            u32::max_value()
+        } else {
+            expected_reputation as u32
+        };
+
+        ReputationAudit {
+            account,
+            stored_reputation,
+            expected_reputation,
+            ledger_entries_count,
+            missing_ledger_entries,
+        }
+    }
+
+    /// The score diff formula of `DefaultScoringStrategy`.
     pub fn get_score_diff(reputation: u32, action: ScoringAction) -> i16 {
         let r = Self::log_2(reputation);
         let d = (reputation - (2 as u32).pow(r)) * 100 / (2 as u32).pow(r);
@@ -223,6 +737,77 @@ impl<T: Trait> Module<T> {
         }
     }
 
+    pub fn scoring_weights() -> ScoringWeights {
+        ScoringWeights {
+            upvote_post: Self::upvote_post_action_weight(),
+            downvote_post: Self::downvote_post_action_weight(),
+            share_post: Self::share_post_action_weight(),
+            create_comment: Self::create_comment_action_weight(),
+            upvote_comment: Self::upvote_comment_action_weight(),
+            downvote_comment: Self::downvote_comment_action_weight(),
+            share_comment: Self::share_comment_action_weight(),
+            follow_blog: Self::follow_blog_action_weight(),
+            follow_account: Self::follow_account_action_weight(),
+        }
+    }
+
+    pub fn content_limits() -> ContentLimits {
+        ContentLimits {
+            slug_min_len: Self::slug_min_len(),
+            slug_max_len: Self::slug_max_len(),
+            ipfs_hash_len: Self::ipfs_hash_len(),
+            username_min_len: Self::username_min_len(),
+            username_max_len: Self::username_max_len(),
+            blog_max_len: Self::blog_max_len(),
+            post_max_len: Self::post_max_len(),
+            comment_max_len: Self::comment_max_len(),
+            max_comment_depth: Self::max_comment_depth(),
+            max_edit_history_records: Self::max_edit_history_records(),
+        }
+    }
+
+    pub fn ensure_scoring_weights_valid(weights: &ScoringWeights) -> DispatchResult {
+        let positive_weights = [
+            weights.upvote_post,
+            weights.share_post,
+            weights.create_comment,
+            weights.upvote_comment,
+            weights.share_comment,
+            weights.follow_blog,
+            weights.follow_account,
+        ];
+        ensure!(positive_weights.iter().all(|w| *w >= 0), Error::<T>::NegativePositiveActionWeight);
+        ensure!(weights.downvote_post <= 0 && weights.downvote_comment <= 0, Error::<T>::PositiveDownvoteWeight);
+
+        Ok(())
+    }
+
+    pub fn ensure_content_limits_valid(limits: &ContentLimits) -> DispatchResult {
+        ensure!(limits.ipfs_hash_len > 0, Error::<T>::ZeroIpfsHashLen);
+        ensure!(limits.slug_min_len <= limits.slug_max_len, Error::<T>::SlugMinLenGreaterThanMax);
+        ensure!(limits.username_min_len <= limits.username_max_len, Error::<T>::UsernameMinLenGreaterThanMax);
+        ensure!(
+            limits.blog_max_len > 0 && limits.post_max_len > 0 && limits.comment_max_len > 0,
+            Error::<T>::ZeroContentMaxLen
+        );
+        // Otherwise every edit would prune its own history record:
+        ensure!(limits.max_edit_history_records > 0, Error::<T>::ZeroMaxEditHistoryRecords);
+
+        Ok(())
+    }
+
+    pub fn ensure_reputation_tiers_valid(tiers: &ReputationTiers) -> DispatchResult {
+        ensure!(tiers.downvote >= 1 && tiers.create_blog >= 1 && tiers.moderate >= 1, Error::<T>::ZeroReputationTier);
+        ensure!(tiers.moderate >= tiers.downvote && tiers.moderate >= tiers.create_blog, Error::<T>::ModerateTierBelowOthers);
+
+        Ok(())
+    }
+
+    pub fn ensure_score_half_life_valid(half_life: u32) -> DispatchResult {
+        ensure!(half_life > 0, Error::<T>::ZeroScoreHalfLife);
+        Ok(())
+    }
+
     fn num_bits<P>() -> usize { sp_std::mem::size_of::<P>() * 8 }
 
     pub fn log_2(x: u32) -> u32 {
@@ -268,6 +853,8 @@ impl<T: Trait> Module<T> {
 
     pub fn share_comment(account: T::AccountId, original_comment_id: CommentId, shared_post_id: PostId) -> DispatchResult {
         let ref mut original_comment = Self::comment_by_id(original_comment_id).ok_or(Error::<T>::OriginalCommentNotFound)?;
+        ensure!(!original_comment.is_deleted(), Error::<T>::CommentIsDeleted);
+
         original_comment.shares_count = original_comment.shares_count.checked_add(1)
             .ok_or(Error::<T>::OverflowTotalSharesSharingComment)?;
 
@@ -278,6 +865,7 @@ impl<T: Trait> Module<T> {
             Self::change_comment_score(account.clone(), original_comment, ScoringAction::ShareComment)?;
         }
 
+        <CommentById<T>>::insert(original_comment_id, original_comment);
         <CommentSharesByAccount<T>>::insert((account.clone(), original_comment_id), shares_count); // TODO Maybe use mutate instead?
         SharedPostIdsByOriginalCommentId::mutate(original_comment_id, |ids| ids.push(shared_post_id));
 
@@ -285,4 +873,530 @@ impl<T: Trait> Module<T> {
 
         Ok(())
     }
+
+    /// Decrease shares of the original post when a post that shares it is deleted.
+    pub fn unshare_post(account: T::AccountId, original_post_id: PostId, shared_post_id: PostId) -> DispatchResult {
+        if let Some(ref mut original_post) = Self::post_by_id(original_post_id) {
+            original_post.shares_count = original_post.shares_count.checked_sub(1)
+                .ok_or(Error::<T>::UnderflowUnsharingPost)?;
+
+            let shares_by_account = Self::post_shares_by_account((account.clone(), original_post_id))
+                .checked_sub(1)
+                .ok_or(Error::<T>::UnderflowUnsharingPost)?;
+
+            if shares_by_account == 0 {
+                Self::revert_post_score(account.clone(), original_post, ScoringAction::SharePost)?;
+                <PostSharesByAccount<T>>::remove((account, original_post_id));
+            } else {
+                <PostSharesByAccount<T>>::insert((account, original_post_id), shares_by_account);
+            }
+
+            <PostById<T>>::insert(original_post_id, original_post);
+        }
+        SharedPostIdsByOriginalPostId::mutate(original_post_id, |ids| Self::vec_remove_on(ids, shared_post_id));
+
+        Ok(())
+    }
+
+    /// Decrease shares of the original comment when a post that shares it is deleted.
+    pub fn unshare_comment(account: T::AccountId, original_comment_id: CommentId, shared_post_id: PostId) -> DispatchResult {
+        if let Some(ref mut original_comment) = Self::comment_by_id(original_comment_id) {
+            original_comment.shares_count = original_comment.shares_count.checked_sub(1)
+                .ok_or(Error::<T>::UnderflowUnsharingComment)?;
+
+            let shares_count = Self::comment_shares_by_account((account.clone(), original_comment_id))
+                .checked_sub(1)
+                .ok_or(Error::<T>::UnderflowUnsharingComment)?;
+
+            if shares_count == 0 {
+                Self::revert_comment_score(account.clone(), original_comment, ScoringAction::ShareComment)?;
+                <CommentSharesByAccount<T>>::remove((account, original_comment_id));
+            } else {
+                <CommentSharesByAccount<T>>::insert((account, original_comment_id), shares_count);
+            }
+
+            <CommentById<T>>::insert(original_comment_id, original_comment);
+        }
+        SharedPostIdsByOriginalCommentId::mutate(original_comment_id, |ids| Self::vec_remove_on(ids, shared_post_id));
+
+        Ok(())
+    }
+
+    /// Revert the share score of every post that shares a given post and drop its shares index.
+    /// Shared posts themselves are kept.
+    pub fn remove_post_shares(post: &mut Post<T>) -> DispatchResult {
+        let post_id = post.id;
+        for shared_post_id in Self::shared_post_ids_by_original_post_id(post_id) {
+            if let Some(shared_post) = Self::post_by_id(shared_post_id) {
+                let sharer = shared_post.created.account;
+                Self::revert_post_score(sharer.clone(), post, ScoringAction::SharePost)?;
+                <PostSharesByAccount<T>>::remove((sharer, post_id));
+            }
+        }
+        SharedPostIdsByOriginalPostId::remove(post_id);
+
+        Ok(())
+    }
+
+    /// Revert the share score of every post that shares a given comment and drop its shares index.
+    /// Shared posts themselves are kept.
+    pub fn remove_comment_shares(comment: &mut Comment<T>) -> DispatchResult {
+        let comment_id = comment.id;
+        for shared_post_id in Self::shared_post_ids_by_original_comment_id(comment_id) {
+            if let Some(shared_post) = Self::post_by_id(shared_post_id) {
+                let sharer = shared_post.created.account;
+                Self::revert_comment_score(sharer.clone(), comment, ScoringAction::ShareComment)?;
+                <CommentSharesByAccount<T>>::remove((sharer, comment_id));
+            }
+        }
+        SharedPostIdsByOriginalCommentId::remove(comment_id);
+
+        Ok(())
+    }
+
+    /// Delete all reactions on a post reverting the score they have given to it.
+    /// Reaction counters of the post are left as is.
+    pub fn remove_post_reactions(post: &mut Post<T>) -> DispatchResult {
+        let target = ReactionTarget::Post(post.id);
+        for reaction_id in Self::reaction_ids_by_target(target.clone()) {
+            if let Some(reaction) = Self::reaction_by_id(reaction_id) {
+                Self::revert_post_reaction_score(reaction.created.account, post)?;
+            }
+        }
+        Self::remove_target_reactions(target);
+
+        Ok(())
+    }
+
+    /// Delete all reactions on a comment reverting the score they have given to it.
+    /// Reaction counters of the comment are left as is.
+    pub fn remove_comment_reactions(comment: &mut Comment<T>) -> DispatchResult {
+        let target = ReactionTarget::Comment(comment.id);
+        for reaction_id in Self::reaction_ids_by_target(target.clone()) {
+            if let Some(reaction) = Self::reaction_by_id(reaction_id) {
+                Self::revert_comment_reaction_score(reaction.created.account, comment)?;
+            }
+        }
+        Self::remove_target_reactions(target);
+
+        Ok(())
+    }
+
+    /// Delete all reactions on a target along with their indexes.
+    pub fn remove_target_reactions(target: ReactionTarget<T::AccountId>) {
+        for reaction_id in Self::reaction_ids_by_target(target.clone()) {
+            if let Some(reaction) = Self::reaction_by_id(reaction_id) {
+                <ReactionIdByAccount<T>>::remove((reaction.created.account, target.clone()));
+                <ReactionCountByTarget<T>>::remove((target.clone(), reaction.kind));
+                <ReactionById<T>>::remove(reaction_id);
+            }
+        }
+        <ReactionIdsByTarget<T>>::remove(target);
+    }
+
+    fn reaction_scorers(target: ReactionTarget<T::AccountId>) -> Vec<T::AccountId> {
+        Self::reaction_ids_by_target(target).into_iter()
+            .filter_map(Self::reaction_by_id)
+            .map(|reaction| reaction.created.account)
+            .collect()
+    }
+
+    /// Accounts that could have scored a post: its reactors, sharers and commenters.
+    pub fn post_scorers(post_id: PostId) -> BTreeSet<T::AccountId> {
+        Self::reaction_scorers(ReactionTarget::Post(post_id)).into_iter()
+            .chain(Self::shared_post_ids_by_original_post_id(post_id).into_iter()
+                .filter_map(Self::post_by_id)
+                .map(|shared_post| shared_post.created.account))
+            .chain(Self::comment_ids_by_post_id(post_id).into_iter()
+                .filter_map(Self::comment_by_id)
+                .map(|comment| comment.created.account))
+            .collect()
+    }
+
+    /// Accounts that could have scored a comment: its reactors and sharers.
+    pub fn comment_scorers(comment_id: CommentId) -> BTreeSet<T::AccountId> {
+        Self::reaction_scorers(ReactionTarget::Comment(comment_id)).into_iter()
+            .chain(Self::shared_post_ids_by_original_comment_id(comment_id).into_iter()
+                .filter_map(Self::post_by_id)
+                .map(|shared_post| shared_post.created.account))
+            .collect()
+    }
+
+    /// Check that the score given to a post by `account` with `action` can be reverted,
+    /// applying the revert to `post_score` and `blog_score`.
+    fn ensure_post_score_revertible(
+        account: T::AccountId,
+        post_id: PostId,
+        action: ScoringAction,
+        post_score: &mut i32,
+        blog_score: &mut i32,
+    ) -> DispatchResult {
+        if let Some(score_diff) = Self::post_score_by_account((account.clone(), post_id, action)) {
+            Self::reputation_diff(account, action, ScoredEntity::Post(post_id))?;
+            *post_score = post_score.checked_sub(score_diff as i32).ok_or(Error::<T>::OutOfBoundsRevertingPostScore)?;
+            *blog_score = blog_score.checked_sub(score_diff as i32).ok_or(Error::<T>::OutOfBoundsRevertingBlogScore)?;
+        }
+        Ok(())
+    }
+
+    /// Check that the score given to a comment by `account` with `action` can be reverted,
+    /// applying the revert to `comment_score`.
+    fn ensure_comment_score_revertible(
+        account: T::AccountId,
+        comment_id: CommentId,
+        action: ScoringAction,
+        comment_score: &mut i32,
+    ) -> DispatchResult {
+        if let Some(score_diff) = Self::comment_score_by_account((account.clone(), comment_id, action)) {
+            Self::reputation_diff(account, action, ScoredEntity::Comment(comment_id))?;
+            *comment_score = comment_score.checked_sub(score_diff as i32).ok_or(Error::<T>::OutOfBoundsRevertingCommentScore)?;
+        }
+        Ok(())
+    }
+
+    /// Check that `delete_post` does not fail once it has started to change the storage:
+    /// the scores given to the post, its comments and the content it shares can be reverted,
+    /// and the counters of its blog and of the shared content can be decreased.
+    /// Reverting reputation is not checked, as it is kept within bounds by clamping it at 1.
+    pub fn ensure_post_removable(post: &Post<T>) -> DispatchResult {
+        let post_actions = [ScoringAction::UpvotePost, ScoringAction::DownvotePost, ScoringAction::SharePost, ScoringAction::CreateComment];
+        let comment_actions = [ScoringAction::UpvoteComment, ScoringAction::DownvoteComment, ScoringAction::ShareComment];
+
+        let blog = Self::blog_by_id(post.blog_id).ok_or(Error::<T>::BlogNotFound)?;
+        blog.posts_count.checked_sub(1).ok_or(Error::<T>::UnderflowRemovingPostFromBlog)?;
+
+        let mut post_score = post.score;
+        let mut blog_score = blog.score;
+        for scorer in Self::post_scorers(post.id) {
+            for action in post_actions.iter() {
+                Self::ensure_post_score_revertible(scorer.clone(), post.id, *action, &mut post_score, &mut blog_score)?;
+            }
+        }
+
+        for comment in Self::comment_ids_by_post_id(post.id).into_iter().filter_map(Self::comment_by_id) {
+            let mut comment_score = comment.score;
+            for scorer in Self::comment_scorers(comment.id) {
+                for action in comment_actions.iter() {
+                    Self::ensure_comment_score_revertible(scorer.clone(), comment.id, *action, &mut comment_score)?;
+                }
+            }
+        }
+
+        let sharer = post.created.account.clone();
+        match post.extension {
+            PostExtension::RegularPost => (),
+            PostExtension::SharedPost(original_post_id) => {
+                if let Some(original_post) = Self::post_by_id(original_post_id) {
+                    original_post.shares_count.checked_sub(1).ok_or(Error::<T>::UnderflowUnsharingPost)?;
+                    let shares_by_account = Self::post_shares_by_account((sharer.clone(), original_post_id))
+                        .checked_sub(1)
+                        .ok_or(Error::<T>::UnderflowUnsharingPost)?;
+
+                    if shares_by_account == 0 {
+                        let original_blog = Self::blog_by_id(original_post.blog_id).ok_or(Error::<T>::BlogNotFound)?;
+                        Self::ensure_post_score_revertible(sharer, original_post_id, ScoringAction::SharePost,
+                            &mut original_post.score.clone(), &mut original_blog.score.clone())?;
+                    }
+                }
+            },
+            PostExtension::SharedComment(original_comment_id) => {
+                if let Some(original_comment) = Self::comment_by_id(original_comment_id) {
+                    original_comment.shares_count.checked_sub(1).ok_or(Error::<T>::UnderflowUnsharingComment)?;
+                    let shares_by_account = Self::comment_shares_by_account((sharer.clone(), original_comment_id))
+                        .checked_sub(1)
+                        .ok_or(Error::<T>::UnderflowUnsharingComment)?;
+
+                    if shares_by_account == 0 {
+                        Self::ensure_comment_score_revertible(sharer, original_comment_id, ScoringAction::ShareComment,
+                            &mut original_comment.score.clone())?;
+                    }
+                }
+            },
+        }
+
+        Ok(())
+    }
+
+    /// Delete a comment with its reactions and shares, reverting the score its author has given
+    /// to the post by commenting. Counters and indexes of the post and parent comment are not updated.
+    pub fn remove_comment(comment: &mut Comment<T>, post: &mut Post<T>) -> DispatchResult {
+        Self::remove_comment_reactions(comment)?;
+        Self::remove_comment_shares(comment)?;
+        Self::revert_post_score(comment.created.account.clone(), post, ScoringAction::CreateComment)?;
+        <CommentById<T>>::remove(comment.id);
+        <CommentHotScoreById<T>>::remove(comment.id);
+        CommentIdsByParentId::remove(comment.id);
+        Self::remove_comment_history(comment.id);
+
+        Ok(())
+    }
+
+    /// Remove a comment that has no replies, updating counters and indexes of its post and parent.
+    /// A tombstoned parent left without replies is removed the same way, up the reply chain.
+    /// The post is not saved.
+    pub fn remove_leaf_comment(mut comment: Comment<T>, post: &mut Post<T>) -> DispatchResult {
+        loop {
+            post.comments_count = post.comments_count.checked_sub(1).ok_or(Error::<T>::UnderflowRemovingCommentFromPost)?;
+
+            let mut empty_tombstone = None;
+            if let Some(parent_id) = comment.parent_id {
+                if let Some(mut parent_comment) = Self::comment_by_id(parent_id) {
+                    parent_comment.direct_replies_count = parent_comment.direct_replies_count
+                        .checked_sub(1).ok_or(Error::<T>::UnderflowRemovingReplyFromComment)?;
+                    <CommentById<T>>::insert(parent_id, parent_comment.clone());
+
+                    if parent_comment.is_deleted() && parent_comment.direct_replies_count == 0 {
+                        empty_tombstone = Some(parent_comment);
+                    }
+                }
+                CommentIdsByParentId::mutate(parent_id, |ids| Self::vec_remove_on(ids, comment.id));
+            }
+
+            Self::remove_comment(&mut comment, post)?;
+            CommentIdsByPostId::mutate(post.id, |ids| Self::vec_remove_on(ids, comment.id));
+
+            match empty_tombstone {
+                Some(parent_comment) => comment = parent_comment,
+                None => return Ok(()),
+            }
+        }
+    }
+
+    /// Items of `items` starting from `offset`, at most `limit` of them and never more than `MAX_PAGE_LIMIT`.
+    pub fn paginate<I: Clone>(items: &[I], offset: u32, limit: u32) -> Vec<I> {
+        items.iter()
+            .skip(offset as usize)
+            .take(limit.min(MAX_PAGE_LIMIT) as usize)
+            .cloned()
+            .collect()
+    }
+
+    /// Posts of a blog in the order of `PostIdsByBlogId`.
+    pub fn posts_by_blog(blog_id: BlogId, offset: u32, limit: u32) -> Vec<Post<T>> {
+        Self::paginate(&Self::post_ids_by_blog_id(blog_id), offset, limit)
+            .into_iter()
+            .filter_map(Self::post_by_id)
+            .collect()
+    }
+
+    /// Comments of a post, including replies, in the order of `CommentIdsByPostId`.
+    pub fn comments_by_post(post_id: PostId, offset: u32, limit: u32) -> Vec<Comment<T>> {
+        Self::paginate(&Self::comment_ids_by_post_id(post_id), offset, limit)
+            .into_iter()
+            .filter_map(Self::comment_by_id)
+            .collect()
+    }
+
+    /// Accounts that follow an account, in the order of `AccountFollowers`.
+    pub fn followers_of_account(account: T::AccountId, offset: u32, limit: u32) -> Vec<T::AccountId> {
+        Self::paginate(&Self::account_followers(account), offset, limit)
+    }
+
+    /// Accounts that follow a blog, in the order of `BlogFollowers`.
+    pub fn followers_of_blog(blog_id: BlogId, offset: u32, limit: u32) -> Vec<T::AccountId> {
+        Self::paginate(&Self::blog_followers(blog_id), offset, limit)
+    }
+
+    pub fn blog_by_slug(slug: Vec<u8>) -> Option<Blog<T>> {
+        Self::blog_id_by_slug(slug).and_then(Self::blog_by_id)
+    }
+
+    pub fn profile_by_username(username: Vec<u8>) -> Option<(T::AccountId, Profile<T>)> {
+        let account = Self::account_by_profile_username(username)?;
+        let profile = Self::social_account_by_id(account.clone())?.profile?;
+        Some((account, profile))
+    }
+
+    /// Posts of the blogs followed by an account, newest first.
+    pub fn feed(account: T::AccountId, offset: u32, limit: u32) -> Vec<Post<T>> {
+        let mut post_ids = Self::blogs_followed_by_account(account)
+            .into_iter()
+            .flat_map(Self::post_ids_by_blog_id)
+            .collect::<Vec<_>>();
+        post_ids.sort_unstable_by(|a, b| b.cmp(a));
+
+        Self::paginate(&post_ids, offset, limit)
+            .into_iter()
+            .filter_map(Self::post_by_id)
+            .collect()
+    }
+
+    /// Indices of edit history records on `page`, given the indices of the first kept record
+    /// and of the next record to be added.
+    fn history_page_indices(first_index: u32, records_count: u32, page: u32) -> sp_std::ops::Range<u32> {
+        let start = first_index.saturating_add(page.saturating_mul(EDIT_HISTORY_PAGE_SIZE)).min(records_count);
+        let end = start.saturating_add(EDIT_HISTORY_PAGE_SIZE).min(records_count);
+        start..end
+    }
+
+    /// Remove the oldest edit history records, so that at most `MaxEditHistoryRecords` of them are left.
+    /// Returns the new first index.
+    fn prune_history_records<F: Fn(u32)>(first_index: u32, records_count: u32, remove_record: F) -> u32 {
+        let new_first_index = records_count.saturating_sub(Self::max_edit_history_records()).max(first_index);
+        for index in first_index..new_first_index {
+            remove_record(index);
+        }
+        new_first_index
+    }
+
+    /// Edit history of a blog on `page`, oldest records first.
+    pub fn blog_edit_history(blog_id: BlogId, page: u32) -> Vec<BlogHistoryRecord<T>> {
+        Self::history_page_indices(Self::blog_history_first_index(blog_id), Self::blog_history_records_count(blog_id), page)
+            .filter_map(|index| Self::blog_history_record_by_index((blog_id, index)))
+            .collect()
+    }
+
+    /// Edit history of a post on `page`, oldest records first.
+    pub fn post_edit_history(post_id: PostId, page: u32) -> Vec<PostHistoryRecord<T>> {
+        Self::history_page_indices(Self::post_history_first_index(post_id), Self::post_history_records_count(post_id), page)
+            .filter_map(|index| Self::post_history_record_by_index((post_id, index)))
+            .collect()
+    }
+
+    /// Edit history of a comment on `page`, oldest records first.
+    pub fn comment_edit_history(comment_id: CommentId, page: u32) -> Vec<CommentHistoryRecord<T>> {
+        Self::history_page_indices(Self::comment_history_first_index(comment_id), Self::comment_history_records_count(comment_id), page)
+            .filter_map(|index| Self::comment_history_record_by_index((comment_id, index)))
+            .collect()
+    }
+
+    /// Edit history of a profile on `page`, oldest records first.
+    pub fn profile_edit_history(account: T::AccountId, page: u32) -> Vec<ProfileHistoryRecord<T>> {
+        let first_index = Self::profile_history_first_index(account.clone());
+        Self::history_page_indices(first_index, Self::profile_history_records_count(account.clone()), page)
+            .filter_map(|index| Self::profile_history_record_by_index((account.clone(), index)))
+            .collect()
+    }
+
+    /// Append a record to the edit history of a blog without pruning it.
+    pub fn insert_blog_history_record(blog_id: BlogId, record: BlogHistoryRecord<T>) -> DispatchResult {
+        let index = Self::blog_history_records_count(blog_id);
+        let records_count = index.checked_add(1).ok_or(Error::<T>::OverflowAddingHistoryRecord)?;
+
+        <BlogHistoryRecordByIndex<T>>::insert((blog_id, index), record);
+        BlogHistoryRecordsCount::insert(blog_id, records_count);
+        Ok(())
+    }
+
+    /// Append a record to the edit history of a blog and prune it down to `MaxEditHistoryRecords` latest records.
+    pub fn add_blog_history_record(blog_id: BlogId, record: BlogHistoryRecord<T>) -> DispatchResult {
+        Self::insert_blog_history_record(blog_id, record)?;
+        Self::prune_blog_history(blog_id);
+        Ok(())
+    }
+
+    /// Append a record to the edit history of a post without pruning it.
+    pub fn insert_post_history_record(post_id: PostId, record: PostHistoryRecord<T>) -> DispatchResult {
+        let index = Self::post_history_records_count(post_id);
+        let records_count = index.checked_add(1).ok_or(Error::<T>::OverflowAddingHistoryRecord)?;
+
+        <PostHistoryRecordByIndex<T>>::insert((post_id, index), record);
+        PostHistoryRecordsCount::insert(post_id, records_count);
+        Ok(())
+    }
+
+    /// Append a record to the edit history of a post and prune it down to `MaxEditHistoryRecords` latest records.
+    pub fn add_post_history_record(post_id: PostId, record: PostHistoryRecord<T>) -> DispatchResult {
+        Self::insert_post_history_record(post_id, record)?;
+        Self::prune_post_history(post_id);
+        Ok(())
+    }
+
+    /// Append a record to the edit history of a comment without pruning it.
+    pub fn insert_comment_history_record(comment_id: CommentId, record: CommentHistoryRecord<T>) -> DispatchResult {
+        let index = Self::comment_history_records_count(comment_id);
+        let records_count = index.checked_add(1).ok_or(Error::<T>::OverflowAddingHistoryRecord)?;
+
+        <CommentHistoryRecordByIndex<T>>::insert((comment_id, index), record);
+        CommentHistoryRecordsCount::insert(comment_id, records_count);
+        Ok(())
+    }
+
+    /// Append a record to the edit history of a comment and prune it down to `MaxEditHistoryRecords` latest records.
+    pub fn add_comment_history_record(comment_id: CommentId, record: CommentHistoryRecord<T>) -> DispatchResult {
+        Self::insert_comment_history_record(comment_id, record)?;
+        Self::prune_comment_history(comment_id);
+        Ok(())
+    }
+
+    /// Append a record to the edit history of a profile without pruning it.
+    pub fn insert_profile_history_record(account: T::AccountId, record: ProfileHistoryRecord<T>) -> DispatchResult {
+        let index = Self::profile_history_records_count(account.clone());
+        let records_count = index.checked_add(1).ok_or(Error::<T>::OverflowAddingHistoryRecord)?;
+
+        <ProfileHistoryRecordByIndex<T>>::insert((account.clone(), index), record);
+        <ProfileHistoryRecordsCount<T>>::insert(account, records_count);
+        Ok(())
+    }
+
+    /// Append a record to the edit history of a profile and prune it down to `MaxEditHistoryRecords` latest records.
+    pub fn add_profile_history_record(account: T::AccountId, record: ProfileHistoryRecord<T>) -> DispatchResult {
+        Self::insert_profile_history_record(account.clone(), record)?;
+        Self::prune_profile_history(account);
+        Ok(())
+    }
+
+    /// Returns the number of pruned records.
+    pub fn prune_blog_history(blog_id: BlogId) -> u32 {
+        let first_index = Self::blog_history_first_index(blog_id);
+        let new_first_index = Self::prune_history_records(first_index, Self::blog_history_records_count(blog_id),
+            |index| <BlogHistoryRecordByIndex<T>>::remove((blog_id, index)));
+
+        BlogHistoryFirstIndex::insert(blog_id, new_first_index);
+        new_first_index - first_index
+    }
+
+    /// Returns the number of pruned records.
+    pub fn prune_post_history(post_id: PostId) -> u32 {
+        let first_index = Self::post_history_first_index(post_id);
+        let new_first_index = Self::prune_history_records(first_index, Self::post_history_records_count(post_id),
+            |index| <PostHistoryRecordByIndex<T>>::remove((post_id, index)));
+
+        PostHistoryFirstIndex::insert(post_id, new_first_index);
+        new_first_index - first_index
+    }
+
+    /// Returns the number of pruned records.
+    pub fn prune_comment_history(comment_id: CommentId) -> u32 {
+        let first_index = Self::comment_history_first_index(comment_id);
+        let new_first_index = Self::prune_history_records(first_index, Self::comment_history_records_count(comment_id),
+            |index| <CommentHistoryRecordByIndex<T>>::remove((comment_id, index)));
+
+        CommentHistoryFirstIndex::insert(comment_id, new_first_index);
+        new_first_index - first_index
+    }
+
+    /// Returns the number of pruned records.
+    pub fn prune_profile_history(account: T::AccountId) -> u32 {
+        let first_index = Self::profile_history_first_index(account.clone());
+        let new_first_index = Self::prune_history_records(first_index, Self::profile_history_records_count(account.clone()),
+            |index| <ProfileHistoryRecordByIndex<T>>::remove((account.clone(), index)));
+
+        <ProfileHistoryFirstIndex<T>>::insert(account, new_first_index);
+        new_first_index - first_index
+    }
+
+    pub fn remove_blog_history(blog_id: BlogId) {
+        for index in Self::blog_history_first_index(blog_id)..Self::blog_history_records_count(blog_id) {
+            <BlogHistoryRecordByIndex<T>>::remove((blog_id, index));
+        }
+        BlogHistoryRecordsCount::remove(blog_id);
+        BlogHistoryFirstIndex::remove(blog_id);
+    }
+
+    pub fn remove_post_history(post_id: PostId) {
+        for index in Self::post_history_first_index(post_id)..Self::post_history_records_count(post_id) {
+            <PostHistoryRecordByIndex<T>>::remove((post_id, index));
+        }
+        PostHistoryRecordsCount::remove(post_id);
+        PostHistoryFirstIndex::remove(post_id);
+    }
+
+    pub fn remove_comment_history(comment_id: CommentId) {
+        for index in Self::comment_history_first_index(comment_id)..Self::comment_history_records_count(comment_id) {
+            <CommentHistoryRecordByIndex<T>>::remove((comment_id, index));
+        }
+        CommentHistoryRecordsCount::remove(comment_id);
+        CommentHistoryFirstIndex::remove(comment_id);
+    }
 }
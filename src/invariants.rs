@@ -0,0 +1,200 @@
+use super::*;
+
+use std::collections::BTreeSet;
+
+#[derive(Clone, Eq, PartialEq, RuntimeDebug)]
+pub enum InvariantEntity<AccountId> {
+    Blog(BlogId),
+    Post(PostId),
+    Comment(CommentId),
+    Account(AccountId),
+}
+
+#[derive(Clone, Eq, PartialEq, RuntimeDebug)]
+pub enum InvariantViolation<AccountId> {
+    /// A denormalised counter of an entity does not match the size of the index it counts.
+    CounterMismatch {
+        entity: InvariantEntity<AccountId>,
+        counter: &'static str,
+        stored: u32,
+        actual: u32,
+    },
+    /// An index of an entity refers to an item that does not exist or belongs to another entity.
+    DanglingIndexEntry {
+        entity: InvariantEntity<AccountId>,
+        index: &'static str,
+        item: InvariantEntity<AccountId>,
+    },
+}
+
+impl<T: Trait> Module<T> {
+
+    /// Walk all the blogs, posts and comments, as well as accounts referenced by them,
+    /// and check that denormalised counters match their indexes.
+    pub fn check_invariants() -> Vec<InvariantViolation<T::AccountId>> {
+        let mut violations = Vec::new();
+        let mut accounts = BTreeSet::new();
+
+        for blog_id in 1..Self::next_blog_id() {
+            if let Some(blog) = Self::blog_by_id(blog_id) {
+                Self::check_blog_invariants(&blog, &mut violations);
+                accounts.insert(blog.owner.clone());
+                accounts.extend(Self::blog_followers(blog_id));
+            }
+        }
+
+        for post_id in 1..Self::next_post_id() {
+            if let Some(post) = Self::post_by_id(post_id) {
+                Self::check_post_invariants(&post, &mut violations);
+                accounts.insert(post.created.account.clone());
+            }
+        }
+
+        for comment_id in 1..Self::next_comment_id() {
+            if let Some(comment) = Self::comment_by_id(comment_id) {
+                Self::check_comment_invariants(&comment, &mut violations);
+                accounts.insert(comment.created.account.clone());
+            }
+        }
+
+        for reaction_id in 1..Self::next_reaction_id() {
+            if let Some(reaction) = Self::reaction_by_id(reaction_id) {
+                accounts.insert(reaction.created.account);
+            }
+        }
+
+        // Accounts that follow or are followed by the accounts found above:
+        for account in accounts.clone() {
+            accounts.extend(Self::account_followers(account.clone()));
+            accounts.extend(Self::accounts_followed_by_account(account));
+        }
+
+        for account in accounts {
+            Self::check_account_invariants(account, &mut violations);
+        }
+
+        violations
+    }
+
+    fn check_counter(
+        violations: &mut Vec<InvariantViolation<T::AccountId>>,
+        entity: InvariantEntity<T::AccountId>,
+        counter: &'static str,
+        stored: u32,
+        actual: usize,
+    ) {
+        if stored != actual as u32 {
+            violations.push(InvariantViolation::CounterMismatch { entity, counter, stored, actual: actual as u32 });
+        }
+    }
+
+    fn check_reaction_counts(
+        violations: &mut Vec<InvariantViolation<T::AccountId>>,
+        entity: InvariantEntity<T::AccountId>,
+        target: ReactionTarget<T::AccountId>,
+        reactions: &[Reaction<T>],
+    ) {
+        // Every kind is checked, so a count left behind for a kind without reactions is reported too:
+        let all_kinds = [ReactionKind::Upvote, ReactionKind::Downvote].iter().cloned()
+            .chain((0..=u8::max_value()).map(ReactionKind::Emoji));
+        for kind in all_kinds {
+            let actual = reactions.iter().filter(|r| r.kind == kind).count();
+            Self::check_counter(violations, entity.clone(), "reaction_count",
+                Self::reaction_count_by_target((target.clone(), kind)), actual);
+        }
+    }
+
+    fn check_blog_invariants(blog: &Blog<T>, violations: &mut Vec<InvariantViolation<T::AccountId>>) {
+        let entity = InvariantEntity::Blog(blog.id);
+        let post_ids = Self::post_ids_by_blog_id(blog.id);
+
+        Self::check_counter(violations, entity.clone(), "posts_count", blog.posts_count, post_ids.len());
+        Self::check_counter(violations, entity.clone(), "followers_count", blog.followers_count, Self::blog_followers(blog.id).len());
+
+        for post_id in post_ids {
+            if Self::post_by_id(post_id).filter(|post| post.blog_id == blog.id).is_none() {
+                violations.push(InvariantViolation::DanglingIndexEntry {
+                    entity: entity.clone(),
+                    index: "PostIdsByBlogId",
+                    item: InvariantEntity::Post(post_id),
+                });
+            }
+        }
+    }
+
+    fn check_post_invariants(post: &Post<T>, violations: &mut Vec<InvariantViolation<T::AccountId>>) {
+        let entity = InvariantEntity::Post(post.id);
+        let comment_ids = Self::comment_ids_by_post_id(post.id);
+        let reactions = Self::reaction_ids_by_post_id(post.id).into_iter()
+            .filter_map(Self::reaction_by_id)
+            .collect::<Vec<_>>();
+        let upvotes = reactions.iter().filter(|r| r.kind == ReactionKind::Upvote).count();
+        let downvotes = reactions.iter().filter(|r| r.kind == ReactionKind::Downvote).count();
+
+        Self::check_counter(violations, entity.clone(), "comments_count", post.comments_count, comment_ids.len());
+        Self::check_counter(violations, entity.clone(), "upvotes_count", post.upvotes_count, upvotes);
+        Self::check_counter(violations, entity.clone(), "downvotes_count", post.downvotes_count, downvotes);
+        Self::check_counter(violations, entity.clone(), "shares_count", post.shares_count,
+            Self::shared_post_ids_by_original_post_id(post.id).len());
+        Self::check_reaction_counts(violations, entity.clone(), ReactionTarget::Post(post.id), &reactions);
+
+        for comment_id in comment_ids {
+            if Self::comment_by_id(comment_id).filter(|comment| comment.post_id == post.id).is_none() {
+                violations.push(InvariantViolation::DanglingIndexEntry {
+                    entity: entity.clone(),
+                    index: "CommentIdsByPostId",
+                    item: InvariantEntity::Comment(comment_id),
+                });
+            }
+        }
+    }
+
+    fn check_comment_invariants(comment: &Comment<T>, violations: &mut Vec<InvariantViolation<T::AccountId>>) {
+        let entity = InvariantEntity::Comment(comment.id);
+        let reply_ids = Self::comment_ids_by_parent_id(comment.id);
+        let reactions = Self::reaction_ids_by_comment_id(comment.id).into_iter()
+            .filter_map(Self::reaction_by_id)
+            .collect::<Vec<_>>();
+        let upvotes = reactions.iter().filter(|r| r.kind == ReactionKind::Upvote).count();
+        let downvotes = reactions.iter().filter(|r| r.kind == ReactionKind::Downvote).count();
+
+        Self::check_counter(violations, entity.clone(), "direct_replies_count", comment.direct_replies_count, reply_ids.len());
+        Self::check_counter(violations, entity.clone(), "upvotes_count", comment.upvotes_count, upvotes);
+        Self::check_counter(violations, entity.clone(), "downvotes_count", comment.downvotes_count, downvotes);
+        Self::check_counter(violations, entity.clone(), "shares_count", comment.shares_count,
+            Self::shared_post_ids_by_original_comment_id(comment.id).len());
+        Self::check_reaction_counts(violations, entity.clone(), ReactionTarget::Comment(comment.id), &reactions);
+
+        for reply_id in reply_ids {
+            if Self::comment_by_id(reply_id).filter(|reply| reply.parent_id == Some(comment.id)).is_none() {
+                violations.push(InvariantViolation::DanglingIndexEntry {
+                    entity: entity.clone(),
+                    index: "CommentIdsByParentId",
+                    item: InvariantEntity::Comment(reply_id),
+                });
+            }
+        }
+    }
+
+    fn check_account_invariants(account: T::AccountId, violations: &mut Vec<InvariantViolation<T::AccountId>>) {
+        let social_account = Self::get_or_new_social_account(account.clone());
+        let entity = InvariantEntity::Account(account.clone());
+
+        Self::check_counter(violations, entity.clone(), "followers_count", social_account.followers_count,
+            Self::account_followers(account.clone()).len());
+        Self::check_counter(violations, entity.clone(), "following_accounts_count", social_account.following_accounts_count,
+            Self::accounts_followed_by_account(account.clone()).len());
+        Self::check_counter(violations, entity.clone(), "following_blogs_count", social_account.following_blogs_count,
+            Self::blogs_followed_by_account(account.clone()).len());
+
+        for blog_id in Self::blogs_followed_by_account(account) {
+            if Self::blog_by_id(blog_id).is_none() {
+                violations.push(InvariantViolation::DanglingIndexEntry {
+                    entity: entity.clone(),
+                    index: "BlogsFollowedByAccount",
+                    item: InvariantEntity::Blog(blog_id),
+                });
+            }
+        }
+    }
+}
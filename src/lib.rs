@@ -5,17 +5,29 @@
 
 pub mod defaults;
 pub mod functions;
+pub mod migration;
+pub mod scoring;
+#[cfg(feature = "std")]
+pub mod invariants;
 mod tests;
 
 use defaults::*;
+use scoring::ScoringStrategy;
 use sp_std::prelude::*;
 use codec::{Encode, Decode};
 use frame_support::{decl_module, decl_storage, decl_event, decl_error, ensure};
 use sp_runtime::RuntimeDebug;
-use system::ensure_signed;
+#[cfg(feature = "std")]
+use serde::{Serialize, Deserialize};
+use system::{ensure_signed, ensure_root};
 use pallet_timestamp;
 
 #[derive(Encode, Decode, Clone, Eq, PartialEq, RuntimeDebug)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(bound(
+  serialize = "T::AccountId: Serialize, T::BlockNumber: Serialize, T::Moment: Serialize",
+  deserialize = "T::AccountId: Deserialize<'de>, T::BlockNumber: Deserialize<'de>, T::Moment: Deserialize<'de>"
+)))]
 pub struct Change<T: Trait> {
   pub account: T::AccountId,
   pub block: T::BlockNumber,
@@ -23,38 +35,87 @@ pub struct Change<T: Trait> {
 }
 
 #[derive(Encode, Decode, Clone, Eq, PartialEq, RuntimeDebug)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(bound(
+  serialize = "T::AccountId: Serialize, T::BlockNumber: Serialize, T::Moment: Serialize",
+  deserialize = "T::AccountId: Deserialize<'de>, T::BlockNumber: Deserialize<'de>, T::Moment: Deserialize<'de>"
+)))]
 pub struct Blog<T: Trait> {
   pub id: BlogId,
   pub created: Change<T>,
   pub updated: Option<Change<T>>,
+  pub owner: T::AccountId,
 
   // Can be updated by the owner:
   pub writers: Vec<T::AccountId>,
   pub slug: Vec<u8>,
   pub ipfs_hash: Vec<u8>,
 
-  pub posts_count: u16,
-  pub followers_count: u32,
+  // If true, only accounts with a role in this blog can comment its posts:
+  pub comments_restricted: bool,
 
-  pub edit_history: Vec<BlogHistoryRecord<T>>,
+  pub posts_count: u32,
+  pub followers_count: u32,
 
   pub score: i32,
 }
 
+impl<T: Trait> Blog<T> {
+  pub fn is_owner(&self, account: &T::AccountId) -> bool {
+    self.owner == *account
+  }
+}
+
 #[derive(Encode, Decode, Clone, Eq, PartialEq, RuntimeDebug)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 pub struct BlogUpdate<AccountId> {
   pub writers: Option<Vec<AccountId>>,
   pub slug: Option<Vec<u8>>,
   pub ipfs_hash: Option<Vec<u8>>,
+  pub comments_restricted: Option<bool>,
+}
+
+#[derive(Encode, Decode, Clone, Copy, Eq, PartialEq, RuntimeDebug)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub enum BlogRole {
+  /// Can edit any post in the blog.
+  Editor,
+  /// Can create posts and edit any post in the blog, same as an account listed in `Blog::writers`.
+  Writer,
+  /// Can delete any comment on posts of the blog.
+  Moderator,
+  /// Can comment posts of the blog even if comments are restricted.
+  Commenter,
+}
+
+/// Actions on a blog and its content that are checked by `Module::has_blog_permission`.
+#[derive(Clone, Copy, Eq, PartialEq, RuntimeDebug)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub enum BlogPermission {
+  ManageBlog,
+  CreatePost,
+  UpdateAnyPost,
+  CreateComment,
+  DeleteAnyComment,
 }
 
 #[derive(Encode, Decode, Clone, Eq, PartialEq, RuntimeDebug)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(bound(
+  serialize = "T::AccountId: Serialize, T::BlockNumber: Serialize, T::Moment: Serialize",
+  deserialize = "T::AccountId: Deserialize<'de>, T::BlockNumber: Deserialize<'de>, T::Moment: Deserialize<'de>"
+)))]
 pub struct BlogHistoryRecord<T: Trait> {
   pub edited: Change<T>,
   pub old_data: BlogUpdate<T::AccountId>,
 }
 
 #[derive(Encode, Decode, Clone, Eq, PartialEq, RuntimeDebug)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(bound(
+  serialize = "T::AccountId: Serialize, T::BlockNumber: Serialize, T::Moment: Serialize",
+  deserialize = "T::AccountId: Deserialize<'de>, T::BlockNumber: Deserialize<'de>, T::Moment: Deserialize<'de>"
+)))]
 pub struct Post<T: Trait> {
   pub id: PostId,
   pub blog_id: BlogId,
@@ -66,29 +127,34 @@ pub struct Post<T: Trait> {
 
   pub ipfs_hash: Vec<u8>,
 
-  pub comments_count: u16,
-  pub upvotes_count: u16,
-  pub downvotes_count: u16,
-  pub shares_count: u16,
-
-  pub edit_history: Vec<PostHistoryRecord<T>>,
+  pub comments_count: u32,
+  pub upvotes_count: u32,
+  pub downvotes_count: u32,
+  pub shares_count: u32,
 
   pub score: i32,
 }
 
 #[derive(Encode, Decode, Clone, Eq, PartialEq, RuntimeDebug)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 pub struct PostUpdate {
   pub blog_id: Option<BlogId>,
   pub ipfs_hash: Option<Vec<u8>>,
 }
 
 #[derive(Encode, Decode, Clone, Eq, PartialEq, RuntimeDebug)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(bound(
+  serialize = "T::AccountId: Serialize, T::BlockNumber: Serialize, T::Moment: Serialize",
+  deserialize = "T::AccountId: Deserialize<'de>, T::BlockNumber: Deserialize<'de>, T::Moment: Deserialize<'de>"
+)))]
 pub struct PostHistoryRecord<T: Trait> {
   pub edited: Change<T>,
   pub old_data: PostUpdate,
 }
 
 #[derive(Encode, Decode, Clone, Copy, Eq, PartialEq, RuntimeDebug)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 pub enum PostExtension {
   RegularPost,
   SharedPost(PostId),
@@ -102,6 +168,11 @@ impl Default for PostExtension {
 }
 
 #[derive(Encode, Decode, Clone, Eq, PartialEq, RuntimeDebug)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(bound(
+  serialize = "T::AccountId: Serialize, T::BlockNumber: Serialize, T::Moment: Serialize",
+  deserialize = "T::AccountId: Deserialize<'de>, T::BlockNumber: Deserialize<'de>, T::Moment: Deserialize<'de>"
+)))]
 pub struct Comment<T: Trait> {
   pub id: CommentId,
   pub parent_id: Option<CommentId>,
@@ -112,31 +183,47 @@ pub struct Comment<T: Trait> {
   // Can be updated by the owner:
   pub ipfs_hash: Vec<u8>,
 
-  pub upvotes_count: u16,
-  pub downvotes_count: u16,
-  pub shares_count: u16,
-  pub direct_replies_count: u16,
-
-  pub edit_history: Vec<CommentHistoryRecord<T>>,
+  pub upvotes_count: u32,
+  pub downvotes_count: u32,
+  pub shares_count: u32,
+  pub direct_replies_count: u32,
+  /// Zero for a root comment of a post, parent's depth plus one for a reply.
+  pub depth: u32,
 
   pub score: i32,
 }
 
+impl<T: Trait> Comment<T> {
+  /// A deleted comment that still has replies is kept as a tombstone with an empty IPFS-hash.
+  pub fn is_deleted(&self) -> bool {
+    self.ipfs_hash.is_empty()
+  }
+}
+
 #[derive(Encode, Decode, Clone, Eq, PartialEq, RuntimeDebug)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 pub struct CommentUpdate {
   pub ipfs_hash: Vec<u8>,
 }
 
 #[derive(Encode, Decode, Clone, Eq, PartialEq, RuntimeDebug)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(bound(
+  serialize = "T::AccountId: Serialize, T::BlockNumber: Serialize, T::Moment: Serialize",
+  deserialize = "T::AccountId: Deserialize<'de>, T::BlockNumber: Deserialize<'de>, T::Moment: Deserialize<'de>"
+)))]
 pub struct CommentHistoryRecord<T: Trait> {
   pub edited: Change<T>,
   pub old_data: CommentUpdate,
 }
 
 #[derive(Encode, Decode, Clone, Copy, Eq, PartialEq, RuntimeDebug)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 pub enum ReactionKind {
   Upvote,
   Downvote,
+  /// Emoji-style reaction. It affects score only if it is mapped to an upvote or a downvote.
+  Emoji(u8),
 }
 
 impl Default for ReactionKind {
@@ -145,7 +232,23 @@ impl Default for ReactionKind {
   }
 }
 
+/// Anything an account can react to.
 #[derive(Encode, Decode, Clone, Eq, PartialEq, RuntimeDebug)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub enum ReactionTarget<AccountId> {
+  Post(PostId),
+  Comment(CommentId),
+  Blog(BlogId),
+  /// Profile of an account.
+  Profile(AccountId),
+}
+
+#[derive(Encode, Decode, Clone, Eq, PartialEq, RuntimeDebug)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(bound(
+  serialize = "T::AccountId: Serialize, T::BlockNumber: Serialize, T::Moment: Serialize",
+  deserialize = "T::AccountId: Deserialize<'de>, T::BlockNumber: Deserialize<'de>, T::Moment: Deserialize<'de>"
+)))]
 pub struct Reaction<T: Trait> {
   pub id: ReactionId,
   pub created: Change<T>,
@@ -154,38 +257,53 @@ pub struct Reaction<T: Trait> {
 }
 
 #[derive(Encode, Decode, Clone, Eq, PartialEq, RuntimeDebug)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(bound(
+  serialize = "T::AccountId: Serialize, T::BlockNumber: Serialize, T::Moment: Serialize",
+  deserialize = "T::AccountId: Deserialize<'de>, T::BlockNumber: Deserialize<'de>, T::Moment: Deserialize<'de>"
+)))]
 pub struct SocialAccount<T: Trait> {
   pub followers_count: u32,
-  pub following_accounts_count: u16,
-  pub following_blogs_count: u16,
+  pub following_accounts_count: u32,
+  pub following_blogs_count: u32,
   pub reputation: u32,
   pub profile: Option<Profile<T>>,
 }
 
 #[derive(Encode, Decode, Clone, Eq, PartialEq, RuntimeDebug)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(bound(
+  serialize = "T::AccountId: Serialize, T::BlockNumber: Serialize, T::Moment: Serialize",
+  deserialize = "T::AccountId: Deserialize<'de>, T::BlockNumber: Deserialize<'de>, T::Moment: Deserialize<'de>"
+)))]
 pub struct Profile<T: Trait> {
   pub created: Change<T>,
   pub updated: Option<Change<T>>,
 
   pub username: Vec<u8>,
   pub ipfs_hash: Vec<u8>,
-
-  pub edit_history: Vec<ProfileHistoryRecord<T>>,
 }
 
 #[derive(Encode, Decode, Clone, Eq, PartialEq, RuntimeDebug)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 pub struct ProfileUpdate {
   pub username: Option<Vec<u8>>,
   pub ipfs_hash: Option<Vec<u8>>,
 }
 
 #[derive(Encode, Decode, Clone, Eq, PartialEq, RuntimeDebug)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(bound(
+  serialize = "T::AccountId: Serialize, T::BlockNumber: Serialize, T::Moment: Serialize",
+  deserialize = "T::AccountId: Deserialize<'de>, T::BlockNumber: Deserialize<'de>, T::Moment: Deserialize<'de>"
+)))]
 pub struct ProfileHistoryRecord<T: Trait> {
   pub edited: Change<T>,
   pub old_data: ProfileUpdate,
 }
 
 #[derive(Encode, Decode, Clone, Copy, Eq, PartialEq, RuntimeDebug)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 pub enum ScoringAction {
   UpvotePost,
   DownvotePost,
@@ -204,6 +322,116 @@ impl Default for ScoringAction {
   }
 }
 
+/// Blog, post, comment or account a scoring action was performed on.
+#[derive(Encode, Decode, Clone, Eq, PartialEq, RuntimeDebug)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub enum ScoredEntity<AccountId> {
+  Blog(BlogId),
+  Post(PostId),
+  Comment(CommentId),
+  Account(AccountId),
+}
+
+/// Key of an entry in the reputation ledger: who performed which scoring action on what.
+pub type ReputationDiffKey<AccountId> = (AccountId, ScoringAction, ScoredEntity<AccountId>);
+
+/// Result of comparing the stored reputation of an account with the one derived from
+/// the reputation ledger (`ReputationDiffByKey`).
+#[derive(Encode, Decode, Clone, Eq, PartialEq, RuntimeDebug)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(bound(
+  serialize = "T::AccountId: Serialize",
+  deserialize = "T::AccountId: Deserialize<'de>"
+)))]
+pub struct ReputationAudit<T: Trait> {
+  pub account: T::AccountId,
+  pub stored_reputation: u32,
+  pub expected_reputation: u32,
+  pub ledger_entries_count: u32,
+  /// Keys in `ReputationDiffKeysByAccount` that have no value in the ledger.
+  pub missing_ledger_entries: Vec<ReputationDiffKey<T::AccountId>>,
+}
+
+impl<T: Trait> ReputationAudit<T> {
+  pub fn is_consistent(&self) -> bool {
+    self.stored_reputation == self.expected_reputation && self.missing_ledger_entries.is_empty()
+  }
+}
+
+/// Anything that has an edit history.
+#[derive(Encode, Decode, Clone, Eq, PartialEq, RuntimeDebug)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub enum HistoryEntity<AccountId> {
+  Blog(BlogId),
+  Post(PostId),
+  Comment(CommentId),
+  /// Profile of an account.
+  Profile(AccountId),
+}
+
+/// Max number of items returned by the paginated getters, such as `Module::posts_by_blog`.
+pub const MAX_PAGE_LIMIT: u32 = 100;
+
+/// Number of edit history records returned per page by the `*_edit_history` getters.
+pub const EDIT_HISTORY_PAGE_SIZE: u32 = 20;
+
+/// Hot scores are stored multiplied by this value to keep precision while decaying.
+pub const HOT_SCORE_PRECISION: i64 = 1_000;
+
+/// Score of a post or comment that halves every `ScoreHalfLife` blocks,
+/// so that fresh content outranks the old one.
+#[derive(Encode, Decode, Clone, Eq, PartialEq, RuntimeDebug)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(bound(
+  serialize = "T::BlockNumber: Serialize",
+  deserialize = "T::BlockNumber: Deserialize<'de>"
+)))]
+pub struct HotScore<T: Trait> {
+  /// Decayed score multiplied by `HOT_SCORE_PRECISION`, as of `updated_at` block.
+  pub value: i64,
+  pub updated_at: T::BlockNumber,
+}
+
+/// Weights of all scoring actions, set at once by `set_scoring_weights`.
+#[derive(Encode, Decode, Clone, Eq, PartialEq, RuntimeDebug)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct ScoringWeights {
+  pub upvote_post: i16,
+  pub downvote_post: i16,
+  pub share_post: i16,
+  pub create_comment: i16,
+  pub upvote_comment: i16,
+  pub downvote_comment: i16,
+  pub share_comment: i16,
+  pub follow_blog: i16,
+  pub follow_account: i16,
+}
+
+/// Minimal reputation required to perform restricted actions, set at once by `set_reputation_tiers`.
+#[derive(Encode, Decode, Clone, Eq, PartialEq, RuntimeDebug)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct ReputationTiers {
+  pub downvote: u32,
+  pub create_blog: u32,
+  pub moderate: u32,
+}
+
+/// Length limits of content, set at once by `set_content_limits`.
+#[derive(Encode, Decode, Clone, Eq, PartialEq, RuntimeDebug)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct ContentLimits {
+  pub slug_min_len: u32,
+  pub slug_max_len: u32,
+  pub ipfs_hash_len: u32,
+  pub username_min_len: u32,
+  pub username_max_len: u32,
+  pub blog_max_len: u32,
+  pub post_max_len: u32,
+  pub comment_max_len: u32,
+  pub max_comment_depth: u32,
+  pub max_edit_history_records: u32,
+}
+
 pub type BlogId = u64;
 pub type PostId = u64;
 pub type CommentId = u64;
@@ -213,6 +441,9 @@ pub type ReactionId = u64;
 pub trait Trait: system::Trait + pallet_timestamp::Trait {
   /// The overarching event type.
   type Event: From<Event<Self>> + Into<<Self as system::Trait>::Event>;
+
+  /// The way score diffs of scoring actions are calculated.
+  type ScoringStrategy: ScoringStrategy<Self>;
 }
 
 decl_error! {
@@ -229,6 +460,22 @@ decl_error! {
     NoUpdatesInBlog,
     /// Only blog owner can manage their blog
     NotABlogOwner,
+    /// Blog still has posts and cannot be deleted
+    BlogHasPosts,
+    /// Only blog owner and writers can post in this blog
+    NotABlogWriter,
+    /// The same account is listed as a blog writer more than once
+    DuplicateBlogWriters,
+    /// Account already has this role in the blog
+    RoleAlreadyGranted,
+    /// Account does not have this role in the blog
+    RoleNotGranted,
+    /// Blog cannot be transferred to its current owner
+    CannotTransferToCurrentOwner,
+    /// There is no pending ownership transfer for this blog
+    NoPendingTransferOnBlog,
+    /// Only the account that blog is being transferred to can accept the transfer
+    NotAPendingBlogOwner,
 
     /// Post was not found by id
     PostNotFound,
@@ -238,11 +485,19 @@ decl_error! {
     NotAPostAuthor,
     /// Overflow caused adding post on blog
     OverflowAddingPostOnBlog,
+    /// Overflow caused adding a record to edit history
+    OverflowAddingHistoryRecord,
+    /// Underflow caused removing post from blog
+    UnderflowRemovingPostFromBlog,
 
     /// Comment was not found by id
     CommentNotFound,
     /// Unknown parent comment id
     UnknownParentComment,
+    /// Parent comment belongs to another post
+    ParentCommentOnAnotherPost,
+    /// Reply is nested deeper than allowed
+    MaxCommentDepthReached,
     /// Only comment author can manage their blog
     NotACommentAuthor,
     /// New comment IPFS-hash is the same as old one
@@ -251,6 +506,14 @@ decl_error! {
     OverflowAddingCommentOnPost,
     /// Overflow replying on comment
     OverflowReplyingOnComment,
+    /// Underflow removing comment from post
+    UnderflowRemovingCommentFromPost,
+    /// Underflow removing reply from comment
+    UnderflowRemovingReplyFromComment,
+    /// Comment is deleted and cannot be changed
+    CommentIsDeleted,
+    /// Only accounts with a role in the blog can comment its posts
+    NotABlogCommenter,
 
     /// Reaction was not found by id
     ReactionNotFound,
@@ -274,6 +537,10 @@ decl_error! {
     OverflowUpvotingComment,
     /// Overflow caused downvoting comment
     OverflowDownvotingComment,
+    /// Account has already reacted to this blog or profile
+    AccountAlreadyReacted,
+    /// There is no reaction by account to this blog or profile
+    ReactionByAccountNotFound,
     /// Only reaction owner can update their reaction
     NotAReactionOwner,
     /// New reaction kind is the same as old one
@@ -341,6 +608,10 @@ decl_error! {
     OverflowTotalSharesSharingComment,
     /// Overflow caused on shares by account counter when sharing comment
     OverflowCommentSharesByAccount,
+    /// Underflow caused on shares counter when unsharing post
+    UnderflowUnsharingPost,
+    /// Underflow caused on shares counter when unsharing comment
+    UnderflowUnsharingComment,
 
     /// Profile for this account already exists
     ProfileAlreadyExists,
@@ -356,48 +627,115 @@ decl_error! {
     UsernameIsTooLong,
     /// Username is not alphanumeric
     UsernameIsNotAlphanumeric,
+
+    /// Weight of a positive scoring action cannot be negative
+    NegativePositiveActionWeight,
+    /// Weight of a downvote cannot be positive
+    PositiveDownvoteWeight,
+    /// Reputation tiers cannot be lower than 1, the minimal reputation of an account
+    ZeroReputationTier,
+    /// Reputation to moderate cannot be lower than reputation to downvote or create a blog
+    ModerateTierBelowOthers,
+    /// Score half-life should be greater than zero
+    ZeroScoreHalfLife,
+    /// IPFS-hash length should be greater than zero
+    ZeroIpfsHashLen,
+    /// Min length of slug cannot be greater than max length
+    SlugMinLenGreaterThanMax,
+    /// Min length of username cannot be greater than max length
+    UsernameMinLenGreaterThanMax,
+    /// Max length of a blog, post or comment should be greater than zero
+    ZeroContentMaxLen,
+    /// Max number of edit history records should be greater than zero
+    ZeroMaxEditHistoryRecords,
+
+    /// Account has not enough reputation to downvote
+    InsufficientReputationToDownvote,
+    /// Account has not enough reputation to create a blog
+    InsufficientReputationToCreateBlog,
+    /// Account has not enough reputation to be an editor or a moderator
+    InsufficientReputationToModerate,
+
+    /// An emoji reaction can only be scored as an upvote or a downvote
+    InvalidEmojiScoringKind,
+    /// Overflow caused counting reactions of a kind
+    OverflowCountingReactions,
+    /// Underflow caused uncounting reactions of a kind
+    UnderflowCountingReactions,
+    /// Underflow caused removing upvote
+    UnderflowRemovingUpvote,
+    /// Underflow caused removing downvote
+    UnderflowRemovingDownvote,
   }
 }
 
 // This pallet's storage items.
 decl_storage! {
   trait Store for Module<T: Trait> as TemplateModule {
-    pub SlugMinLen get(slug_min_len): u32 = DEFAULT_SLUG_MIN_LEN;
-    pub SlugMaxLen get(slug_max_len): u32 = DEFAULT_SLUG_MAX_LEN;
-
-    pub IpfsHashLen get(ipfs_hash_len): u32 = DEFAULT_IPFS_HASH_LEN;
-
-    pub UsernameMinLen get(username_min_len): u32 = DEFAULT_USERNAME_MIN_LEN;
-    pub UsernameMaxLen get(username_max_len): u32 = DEFAULT_USERNAME_MAX_LEN;
-
-    pub BlogMaxLen get(blog_max_len): u32 = DEFAULT_BLOG_MAX_LEN;
-    pub PostMaxLen get(post_max_len): u32 = DEFAULT_POST_MAX_LEN;
-    pub CommentMaxLen get(comment_max_len): u32 = DEFAULT_COMMENT_MAX_LEN;
-
-    pub UpvotePostActionWeight get (upvote_post_action_weight): i16 = DEFAULT_UPVOTE_POST_ACTION_WEIGHT;
-    pub DownvotePostActionWeight get (downvote_post_action_weight): i16 = DEFAULT_DOWNVOTE_POST_ACTION_WEIGHT;
-    pub SharePostActionWeight get (share_post_action_weight): i16 = DEFAULT_SHARE_POST_ACTION_WEIGHT;
-    pub CreateCommentActionWeight get (create_comment_action_weight): i16 = DEFAULT_CREATE_COMMENT_ACTION_WEIGHT;
-    pub UpvoteCommentActionWeight get (upvote_comment_action_weight): i16 = DEFAULT_UPVOTE_COMMENT_ACTION_WEIGHT;
-    pub DownvoteCommentActionWeight get (downvote_comment_action_weight): i16 = DEFAULT_DOWNVOTE_COMMENT_ACTION_WEIGHT;
-    pub ShareCommentActionWeight get (share_comment_action_weight): i16 = DEFAULT_SHARE_COMMENT_ACTION_WEIGHT;
-    pub FollowBlogActionWeight get (follow_blog_action_weight): i16 = DEFAULT_FOLLOW_BLOG_ACTION_WEIGHT;
-    pub FollowAccountActionWeight get (follow_account_action_weight): i16 = DEFAULT_FOLLOW_ACCOUNT_ACTION_WEIGHT;
+    pub SlugMinLen get(slug_min_len) config(): u32 = DEFAULT_SLUG_MIN_LEN;
+    pub SlugMaxLen get(slug_max_len) config(): u32 = DEFAULT_SLUG_MAX_LEN;
+
+    pub IpfsHashLen get(ipfs_hash_len) config(): u32 = DEFAULT_IPFS_HASH_LEN;
+
+    pub UsernameMinLen get(username_min_len) config(): u32 = DEFAULT_USERNAME_MIN_LEN;
+    pub UsernameMaxLen get(username_max_len) config(): u32 = DEFAULT_USERNAME_MAX_LEN;
+
+    pub BlogMaxLen get(blog_max_len) config(): u32 = DEFAULT_BLOG_MAX_LEN;
+    pub PostMaxLen get(post_max_len) config(): u32 = DEFAULT_POST_MAX_LEN;
+    pub CommentMaxLen get(comment_max_len) config(): u32 = DEFAULT_COMMENT_MAX_LEN;
+    /// Top-level comments have depth 0, so with a zero depth comments cannot be replied to.
+    pub MaxCommentDepth get(max_comment_depth) config(): u32 = DEFAULT_MAX_COMMENT_DEPTH;
+    /// Number of the latest edit history records kept per blog, post, comment or profile.
+    pub MaxEditHistoryRecords get(max_edit_history_records) config(): u32 = DEFAULT_MAX_EDIT_HISTORY_RECORDS;
+
+    pub UpvotePostActionWeight get (upvote_post_action_weight) config(): i16 = DEFAULT_UPVOTE_POST_ACTION_WEIGHT;
+    pub DownvotePostActionWeight get (downvote_post_action_weight) config(): i16 = DEFAULT_DOWNVOTE_POST_ACTION_WEIGHT;
+    pub SharePostActionWeight get (share_post_action_weight) config(): i16 = DEFAULT_SHARE_POST_ACTION_WEIGHT;
+    pub CreateCommentActionWeight get (create_comment_action_weight) config(): i16 = DEFAULT_CREATE_COMMENT_ACTION_WEIGHT;
+    pub UpvoteCommentActionWeight get (upvote_comment_action_weight) config(): i16 = DEFAULT_UPVOTE_COMMENT_ACTION_WEIGHT;
+    pub DownvoteCommentActionWeight get (downvote_comment_action_weight) config(): i16 = DEFAULT_DOWNVOTE_COMMENT_ACTION_WEIGHT;
+    pub ShareCommentActionWeight get (share_comment_action_weight) config(): i16 = DEFAULT_SHARE_COMMENT_ACTION_WEIGHT;
+    pub FollowBlogActionWeight get (follow_blog_action_weight) config(): i16 = DEFAULT_FOLLOW_BLOG_ACTION_WEIGHT;
+    pub FollowAccountActionWeight get (follow_account_action_weight) config(): i16 = DEFAULT_FOLLOW_ACCOUNT_ACTION_WEIGHT;
+
+    pub MinReputationToDownvote get(min_reputation_to_downvote) config(): u32 = DEFAULT_MIN_REPUTATION_TO_DOWNVOTE;
+    pub MinReputationToCreateBlog get(min_reputation_to_create_blog) config(): u32 = DEFAULT_MIN_REPUTATION_TO_CREATE_BLOG;
+    /// Minimal reputation of an account to be granted a blog editor or moderator role.
+    pub MinReputationToModerate get(min_reputation_to_moderate) config(): u32 = DEFAULT_MIN_REPUTATION_TO_MODERATE;
+
+    /// Number of blocks after which a hot score of a post or comment is halved.
+    pub ScoreHalfLife get(score_half_life) config(): u32 = DEFAULT_SCORE_HALF_LIFE;
 
     pub BlogById get(blog_by_id): map BlogId => Option<Blog<T>>;
     pub PostById get(post_by_id): map PostId => Option<Post<T>>;
     pub CommentById get(comment_by_id): map CommentId => Option<Comment<T>>;
     pub ReactionById get(reaction_by_id): map ReactionId => Option<Reaction<T>>;
+    pub PostHotScoreById get(post_hot_score_by_id): map PostId => Option<HotScore<T>>;
+    pub CommentHotScoreById get(comment_hot_score_by_id): map CommentId => Option<HotScore<T>>;
     pub SocialAccountById get(social_account_by_id): map T::AccountId => Option<SocialAccount<T>>;
 
     pub BlogIdsByOwner get(blog_ids_by_owner): map T::AccountId => Vec<BlogId>;
+    /// Blogs that list an account in `Blog::writers` or grant it `BlogRole::Writer`.
+    pub BlogIdsByWriter get(blog_ids_by_writer): map T::AccountId => Vec<BlogId>;
+    pub BlogRolesByAccount get(blog_roles_by_account): map (BlogId, T::AccountId) => Vec<BlogRole>;
+    /// Accounts that have at least one role in a blog.
+    pub AccountsWithRolesByBlog get(accounts_with_roles_by_blog): map BlogId => Vec<T::AccountId>;
+    pub PendingBlogOwner get(pending_blog_owner): map BlogId => Option<T::AccountId>;
     pub PostIdsByBlogId get(post_ids_by_blog_id): map BlogId => Vec<PostId>;
     pub CommentIdsByPostId get(comment_ids_by_post_id): map PostId => Vec<CommentId>;
+    pub CommentIdsByParentId get(comment_ids_by_parent_id): map CommentId => Vec<CommentId>;
+
+    pub ReactionIdsByTarget get(reaction_ids_by_target): map ReactionTarget<T::AccountId> => Vec<ReactionId>;
+    pub ReactionIdByAccount get(reaction_id_by_account): map (T::AccountId, ReactionTarget<T::AccountId>) => Option<ReactionId>;
+    pub ReactionCountByTarget get(reaction_count_by_target): map (ReactionTarget<T::AccountId>, ReactionKind) => u32;
+    /// Upvote or downvote an emoji reaction is scored as. Emojis that are not here do not affect score.
+    pub EmojiScoringKind get(emoji_scoring_kind): map u8 => Option<ReactionKind>;
 
-    pub ReactionIdsByPostId get(reaction_ids_by_post_id): map PostId => Vec<ReactionId>;
-    pub ReactionIdsByCommentId get(reaction_ids_by_comment_id): map CommentId => Vec<ReactionId>;
-    pub PostReactionIdByAccount get(post_reaction_id_by_account): map (T::AccountId, PostId) => ReactionId;
-    pub CommentReactionIdByAccount get(comment_reaction_id_by_account): map (T::AccountId, CommentId) => ReactionId;
+    // Reaction indexes of storage version 0, moved to the ones above by `Module::migrate_storage`:
+    ReactionIdsByPostId: map PostId => Vec<ReactionId>;
+    ReactionIdsByCommentId: map CommentId => Vec<ReactionId>;
+    PostReactionIdByAccount: map (T::AccountId, PostId) => ReactionId;
+    CommentReactionIdByAccount: map (T::AccountId, CommentId) => ReactionId;
 
     pub BlogIdBySlug get(blog_id_by_slug): map Vec<u8> => Option<BlogId>;
 
@@ -414,7 +752,32 @@ decl_storage! {
     pub NextCommentId get(next_comment_id): CommentId = 1;
     pub NextReactionId get(next_reaction_id): ReactionId = 1;
 
-    pub AccountReputationDiffByAccount get(account_reputation_diff_by_account): map (T::AccountId, T::AccountId, ScoringAction) => Option<i16>; // TODO shorten name (?refactor)
+    // Edit history is stored apart from the entities, so that reading them does not decode the whole history.
+    // Records of an entity are indexed from zero in order they were added,
+    // records before the first index have been pruned.
+    pub BlogHistoryRecordsCount get(blog_history_records_count): map BlogId => u32;
+    pub BlogHistoryFirstIndex get(blog_history_first_index): map BlogId => u32;
+    pub BlogHistoryRecordByIndex get(blog_history_record_by_index): map (BlogId, u32) => Option<BlogHistoryRecord<T>>;
+    pub PostHistoryRecordsCount get(post_history_records_count): map PostId => u32;
+    pub PostHistoryFirstIndex get(post_history_first_index): map PostId => u32;
+    pub PostHistoryRecordByIndex get(post_history_record_by_index): map (PostId, u32) => Option<PostHistoryRecord<T>>;
+    pub CommentHistoryRecordsCount get(comment_history_records_count): map CommentId => u32;
+    pub CommentHistoryFirstIndex get(comment_history_first_index): map CommentId => u32;
+    pub CommentHistoryRecordByIndex get(comment_history_record_by_index): map (CommentId, u32) => Option<CommentHistoryRecord<T>>;
+    pub ProfileHistoryRecordsCount get(profile_history_records_count): map T::AccountId => u32;
+    pub ProfileHistoryFirstIndex get(profile_history_first_index): map T::AccountId => u32;
+    pub ProfileHistoryRecordByIndex get(profile_history_record_by_index): map (T::AccountId, u32) => Option<ProfileHistoryRecord<T>>;
+
+    /// Version of the storage layout, used by `on_runtime_upgrade` to decide which migrations to run.
+    pub StorageVersion get(storage_version) build(|_: &GenesisConfig| migration::CURRENT_STORAGE_VERSION): u32;
+
+    /// Reputation ledger: the account whose reputation was changed by a scoring action and the applied diff.
+    pub ReputationDiffByKey get(reputation_diff_by_key): map ReputationDiffKey<T::AccountId> => Option<(T::AccountId, i16)>;
+    /// Keys of the reputation ledger entries that changed reputation of an account.
+    pub ReputationDiffKeysByAccount get(reputation_diff_keys_by_account): map T::AccountId => Vec<ReputationDiffKey<T::AccountId>>;
+    // Reputation ledger of storage versions before 3, keyed without the scored entity.
+    // Read and cleared by `Module::migrate_storage`:
+    AccountReputationDiffByAccount: map (T::AccountId, T::AccountId, ScoringAction) => Option<i16>;
     pub PostScoreByAccount get(post_score_by_account): map (T::AccountId, PostId, ScoringAction) => Option<i16>;
     pub CommentScoreByAccount get(comment_score_by_account): map (T::AccountId, CommentId, ScoringAction) => Option<i16>;
 
@@ -426,6 +789,45 @@ decl_storage! {
 
     pub AccountByProfileUsername get(account_by_profile_username): map Vec<u8> => Option<T::AccountId>;
   }
+  add_extra_genesis {
+    build(|config: &GenesisConfig| {
+      <Module<T>>::ensure_score_half_life_valid(config.score_half_life).expect("ScoreHalfLife in genesis config should be valid");
+
+      let tiers = ReputationTiers {
+        downvote: config.min_reputation_to_downvote,
+        create_blog: config.min_reputation_to_create_blog,
+        moderate: config.min_reputation_to_moderate,
+      };
+      <Module<T>>::ensure_reputation_tiers_valid(&tiers).expect("Reputation tiers in genesis config should be valid");
+
+      let limits = ContentLimits {
+        slug_min_len: config.slug_min_len,
+        slug_max_len: config.slug_max_len,
+        ipfs_hash_len: config.ipfs_hash_len,
+        username_min_len: config.username_min_len,
+        username_max_len: config.username_max_len,
+        blog_max_len: config.blog_max_len,
+        post_max_len: config.post_max_len,
+        comment_max_len: config.comment_max_len,
+        max_comment_depth: config.max_comment_depth,
+        max_edit_history_records: config.max_edit_history_records,
+      };
+      <Module<T>>::ensure_content_limits_valid(&limits).expect("Content limits in genesis config should be valid");
+
+      let weights = ScoringWeights {
+        upvote_post: config.upvote_post_action_weight,
+        downvote_post: config.downvote_post_action_weight,
+        share_post: config.share_post_action_weight,
+        create_comment: config.create_comment_action_weight,
+        upvote_comment: config.upvote_comment_action_weight,
+        downvote_comment: config.downvote_comment_action_weight,
+        share_comment: config.share_comment_action_weight,
+        follow_blog: config.follow_blog_action_weight,
+        follow_account: config.follow_account_action_weight,
+      };
+      <Module<T>>::ensure_scoring_weights_valid(&weights).expect("Scoring weights in genesis config should be valid");
+    })
+  }
 }
 
 // The pallet's dispatchable functions.
@@ -436,9 +838,14 @@ decl_module! {
     // this is needed only if you are using events in your pallet
     fn deposit_event() = default;
 
+    fn on_runtime_upgrade() {
+      Self::migrate_storage();
+    }
+
     pub fn create_blog(origin, slug: Vec<u8>, ipfs_hash: Vec<u8>) {
       let owner = ensure_signed(origin)?;
 
+      Self::ensure_reputation_at_least(&owner, Self::min_reputation_to_create_blog(), Error::<T>::InsufficientReputationToCreateBlog)?;
       ensure!(slug.len() >= Self::slug_min_len() as usize, Error::<T>::SlugIsTooShort);
       ensure!(slug.len() <= Self::slug_max_len() as usize, Error::<T>::SlugIsTooLong);
       ensure!(!BlogIdBySlug::exists(slug.clone()), Error::<T>::SlugIsNotUnique);
@@ -449,12 +856,13 @@ decl_module! {
         id: blog_id,
         created: Self::new_change(owner.clone()),
         updated: None,
+        owner: owner.clone(),
         writers: vec![],
         slug: slug.clone(),
         ipfs_hash,
+        comments_restricted: false,
         posts_count: 0,
         followers_count: 0,
-        edit_history: vec![],
         score: 0
       };
 
@@ -472,25 +880,23 @@ decl_module! {
       let has_updates =
         update.writers.is_some() ||
         update.slug.is_some() ||
-        update.ipfs_hash.is_some();
+        update.ipfs_hash.is_some() ||
+        update.comments_restricted.is_some();
 
       ensure!(has_updates, Error::<T>::NoUpdatesInBlog);
 
       let mut blog = Self::blog_by_id(blog_id).ok_or(Error::<T>::BlogNotFound)?;
-
-      // TODO ensure: blog writers also should be able to edit this blog:
-      ensure!(owner == blog.created.account, Error::<T>::NotABlogOwner);
+      ensure!(Self::has_blog_permission(&owner, &blog, BlogPermission::ManageBlog), Error::<T>::NotABlogOwner);
 
       let mut fields_updated = 0;
       let mut new_history_record = BlogHistoryRecord {
         edited: Self::new_change(owner.clone()),
-        old_data: BlogUpdate {writers: None, slug: None, ipfs_hash: None}
+        old_data: BlogUpdate {writers: None, slug: None, ipfs_hash: None, comments_restricted: None}
       };
 
       if let Some(writers) = update.writers {
         if writers != blog.writers {
-          // TODO validate writers.
-          // TODO update BlogIdsByWriter: insert new, delete removed, update only changed writers.
+          Self::ensure_writers_unique(&writers)?;
           new_history_record.old_data.writers = Some(blog.writers);
           blog.writers = writers;
           fields_updated += 1;
@@ -521,15 +927,158 @@ decl_module! {
         }
       }
 
+      if let Some(comments_restricted) = update.comments_restricted {
+        if comments_restricted != blog.comments_restricted {
+          new_history_record.old_data.comments_restricted = Some(blog.comments_restricted);
+          blog.comments_restricted = comments_restricted;
+          fields_updated += 1;
+        }
+      }
+
       // Update this blog only if at least one field should be updated:
       if fields_updated > 0 {
+        if let Some(old_writers) = &new_history_record.old_data.writers {
+          Self::update_blog_ids_by_writer(blog_id, old_writers, &blog.writers);
+        }
+
         blog.updated = Some(Self::new_change(owner.clone()));
-        blog.edit_history.push(new_history_record);
+        Self::add_blog_history_record(blog_id, new_history_record)?;
         <BlogById<T>>::insert(blog_id, blog);
         Self::deposit_event(RawEvent::BlogUpdated(owner.clone(), blog_id));
       }
     }
 
+    /// Delete a blog along with its slug, owner index and followers.
+    /// Only an empty blog can be deleted: its posts should be deleted or moved to another blog first.
+    pub fn delete_blog(origin, blog_id: BlogId) {
+      let owner = ensure_signed(origin)?;
+
+      let ref mut blog = Self::blog_by_id(blog_id).ok_or(Error::<T>::BlogNotFound)?;
+      ensure!(Self::has_blog_permission(&owner, blog, BlogPermission::ManageBlog), Error::<T>::NotABlogOwner);
+      ensure!(blog.posts_count == 0, Error::<T>::BlogHasPosts);
+      Self::ensure_blog_followers_removable(blog)?;
+
+      for follower in Self::blog_followers(blog_id) {
+        Self::remove_blog_follower(follower.clone(), blog)?;
+        Self::deposit_event(RawEvent::BlogUnfollowed(follower, blog_id));
+      }
+
+      <BlogById<T>>::remove(blog_id);
+      Self::remove_blog_history(blog_id);
+      BlogIdBySlug::remove(blog.slug.clone());
+      <BlogIdsByOwner<T>>::mutate(owner.clone(), |ids| Self::vec_remove_on(ids, blog_id));
+      Self::update_blog_ids_by_writer(blog_id, &blog.writers, &[]);
+      <BlogFollowers<T>>::remove(blog_id);
+      <PendingBlogOwner<T>>::remove(blog_id);
+      for account in <AccountsWithRolesByBlog<T>>::take(blog_id) {
+        Self::remove_blog_id_by_writer(account.clone(), blog_id);
+        <BlogRolesByAccount<T>>::remove((blog_id, account));
+      }
+      PostIdsByBlogId::remove(blog_id);
+      Self::remove_target_reactions(ReactionTarget::Blog(blog_id));
+
+      Self::deposit_event(RawEvent::BlogDeleted(owner, blog_id));
+    }
+
+    /// Offer the blog to another account. Ownership changes only when the new owner accepts it.
+    pub fn transfer_blog_ownership(origin, blog_id: BlogId, new_owner: T::AccountId) {
+      let owner = ensure_signed(origin)?;
+
+      let blog = Self::blog_by_id(blog_id).ok_or(Error::<T>::BlogNotFound)?;
+      ensure!(Self::has_blog_permission(&owner, &blog, BlogPermission::ManageBlog), Error::<T>::NotABlogOwner);
+      ensure!(owner != new_owner, Error::<T>::CannotTransferToCurrentOwner);
+
+      <PendingBlogOwner<T>>::insert(blog_id, new_owner.clone());
+
+      Self::deposit_event(RawEvent::BlogOwnershipTransferCreated(owner, blog_id, new_owner));
+    }
+
+    pub fn accept_blog_ownership(origin, blog_id: BlogId) {
+      let new_owner = ensure_signed(origin)?;
+
+      let mut blog = Self::blog_by_id(blog_id).ok_or(Error::<T>::BlogNotFound)?;
+      let pending_owner = Self::pending_blog_owner(blog_id).ok_or(Error::<T>::NoPendingTransferOnBlog)?;
+      ensure!(new_owner == pending_owner, Error::<T>::NotAPendingBlogOwner);
+
+      let old_owner = blog.owner;
+      blog.owner = new_owner.clone();
+
+      <BlogById<T>>::insert(blog_id, blog);
+      <PendingBlogOwner<T>>::remove(blog_id);
+      <BlogIdsByOwner<T>>::mutate(old_owner.clone(), |ids| Self::vec_remove_on(ids, blog_id));
+      <BlogIdsByOwner<T>>::mutate(new_owner.clone(), |ids| ids.push(blog_id));
+
+      Self::deposit_event(RawEvent::BlogOwnershipTransferred(old_owner, blog_id, new_owner));
+    }
+
+    /// Cancel a pending transfer. It can be done either by the blog owner or by the account
+    /// that the blog is being transferred to.
+    pub fn cancel_blog_ownership_transfer(origin, blog_id: BlogId) {
+      let who = ensure_signed(origin)?;
+
+      let blog = Self::blog_by_id(blog_id).ok_or(Error::<T>::BlogNotFound)?;
+      let pending_owner = Self::pending_blog_owner(blog_id).ok_or(Error::<T>::NoPendingTransferOnBlog)?;
+      ensure!(
+        who == pending_owner || Self::has_blog_permission(&who, &blog, BlogPermission::ManageBlog),
+        Error::<T>::NotABlogOwner
+      );
+
+      <PendingBlogOwner<T>>::remove(blog_id);
+
+      Self::deposit_event(RawEvent::BlogOwnershipTransferCancelled(who, blog_id));
+    }
+
+    pub fn grant_blog_role(origin, blog_id: BlogId, account: T::AccountId, role: BlogRole) {
+      let owner = ensure_signed(origin)?;
+
+      let blog = Self::blog_by_id(blog_id).ok_or(Error::<T>::BlogNotFound)?;
+      ensure!(Self::has_blog_permission(&owner, &blog, BlogPermission::ManageBlog), Error::<T>::NotABlogOwner);
+
+      let mut roles = Self::blog_roles_by_account((blog_id, account.clone()));
+      ensure!(!roles.contains(&role), Error::<T>::RoleAlreadyGranted);
+
+      match role {
+        BlogRole::Editor | BlogRole::Moderator => {
+          Self::ensure_reputation_at_least(&account, Self::min_reputation_to_moderate(), Error::<T>::InsufficientReputationToModerate)?;
+        },
+        _ => (),
+      }
+
+      if roles.is_empty() {
+        <AccountsWithRolesByBlog<T>>::mutate(blog_id, |accounts| accounts.push(account.clone()));
+      }
+      roles.push(role);
+      <BlogRolesByAccount<T>>::insert((blog_id, account.clone()), roles);
+      if role == BlogRole::Writer {
+        Self::add_blog_id_by_writer(account.clone(), blog_id);
+      }
+
+      Self::deposit_event(RawEvent::BlogRoleGranted(owner, blog_id, account, role));
+    }
+
+    pub fn revoke_blog_role(origin, blog_id: BlogId, account: T::AccountId, role: BlogRole) {
+      let owner = ensure_signed(origin)?;
+
+      let blog = Self::blog_by_id(blog_id).ok_or(Error::<T>::BlogNotFound)?;
+      ensure!(Self::has_blog_permission(&owner, &blog, BlogPermission::ManageBlog), Error::<T>::NotABlogOwner);
+
+      let mut roles = Self::blog_roles_by_account((blog_id, account.clone()));
+      ensure!(roles.contains(&role), Error::<T>::RoleNotGranted);
+
+      Self::vec_remove_on(&mut roles, role);
+      if roles.is_empty() {
+        <BlogRolesByAccount<T>>::remove((blog_id, account.clone()));
+        <AccountsWithRolesByBlog<T>>::mutate(blog_id, |accounts| Self::vec_remove_on(accounts, account.clone()));
+      } else {
+        <BlogRolesByAccount<T>>::insert((blog_id, account.clone()), roles);
+      }
+      if role == BlogRole::Writer && !blog.writers.contains(&account) {
+        Self::remove_blog_id_by_writer(account.clone(), blog_id);
+      }
+
+      Self::deposit_event(RawEvent::BlogRoleRevoked(owner, blog_id, account, role));
+    }
+
     pub fn follow_blog(origin, blog_id: BlogId) {
       let follower = ensure_signed(origin)?;
 
@@ -545,24 +1094,7 @@ decl_module! {
       let ref mut blog = Self::blog_by_id(blog_id).ok_or(Error::<T>::BlogNotFound)?;
       ensure!(Self::blog_followed_by_account((follower.clone(), blog_id)), Error::<T>::AccountIsNotFollowingBlog);
 
-      let mut social_account = Self::social_account_by_id(follower.clone()).ok_or(Error::<T>::SocialAccountNotFound)?;
-      social_account.following_blogs_count = social_account.following_blogs_count
-        .checked_sub(1)
-        .ok_or(Error::<T>::UnderflowUnfollowingBlog)?;
-      blog.followers_count = blog.followers_count.checked_sub(1).ok_or(Error::<T>::UnderflowUnfollowingBlog)?;
-
-      if blog.created.account != follower {
-        let author = blog.created.account.clone();
-        if let Some(score_diff) = Self::account_reputation_diff_by_account((follower.clone(), author.clone(), ScoringAction::FollowBlog)) {
-          blog.score = blog.score.checked_sub(score_diff as i32).ok_or(Error::<T>::OutOfBoundsUpdatingBlogScore)?;
-          Self::change_social_account_reputation(author.clone(), follower.clone(), score_diff * -1, ScoringAction::FollowBlog)?;
-        }
-      }
-
-      <BlogsFollowedByAccount<T>>::mutate(follower.clone(), |blog_ids| Self::vec_remove_on(blog_ids, blog_id));
-      <BlogFollowers<T>>::mutate(blog_id, |account_ids| Self::vec_remove_on(account_ids, follower.clone()));
-      <BlogFollowedByAccount<T>>::remove((follower.clone(), blog_id));
-      <SocialAccountById<T>>::insert(follower.clone(), social_account);
+      Self::remove_blog_follower(follower.clone(), blog)?;
       <BlogById<T>>::insert(blog_id, blog);
 
       Self::deposit_event(RawEvent::BlogUnfollowed(follower.clone(), blog_id));
@@ -582,13 +1114,17 @@ decl_module! {
       followed_account.followers_count = followed_account.followers_count
         .checked_add(1).ok_or(Error::<T>::OverflowFollowingAccount)?;
 
-      Self::change_social_account_reputation(account.clone(), follower.clone(),
-        Self::get_score_diff(follower_account.reputation.clone(), ScoringAction::FollowAccount),
-        ScoringAction::FollowAccount
-      )?;
+      let score_diff = T::ScoringStrategy::score_diff(&follower_account, ScoringAction::FollowAccount);
 
+      // Accounts are saved before the reputation change, which reads and updates the followed account:
       <SocialAccountById<T>>::insert(follower.clone(), follower_account);
       <SocialAccountById<T>>::insert(account.clone(), followed_account);
+      Self::change_social_account_reputation(account.clone(), follower.clone(),
+        score_diff,
+        ScoringAction::FollowAccount,
+        ScoredEntity::Account(account.clone())
+      )?;
+
       <AccountsFollowedByAccount<T>>::mutate(follower.clone(), |ids| ids.push(account.clone()));
       <AccountFollowers<T>>::mutate(account.clone(), |ids| ids.push(follower.clone()));
       <AccountFollowedByAccount<T>>::insert((follower.clone(), account.clone()), true);
@@ -611,16 +1147,19 @@ decl_module! {
       followed_account.followers_count = followed_account.followers_count
         .checked_sub(1).ok_or(Error::<T>::UnderflowUnfollowingAccount)?;
 
-      let reputation_diff = Self::account_reputation_diff_by_account(
-        (follower.clone(), account.clone(), ScoringAction::FollowAccount)
-      ).ok_or(Error::<T>::ReputationDiffNotFound)?;
-      Self::change_social_account_reputation(account.clone(), follower.clone(),
-        reputation_diff,
-        ScoringAction::FollowAccount
+      let reputation_diff = Self::reputation_diff(
+        follower.clone(), ScoringAction::FollowAccount, ScoredEntity::Account(account.clone())
       )?;
 
+      // Accounts are saved before the reputation change, which reads and updates the followed account:
       <SocialAccountById<T>>::insert(follower.clone(), follower_account);
       <SocialAccountById<T>>::insert(account.clone(), followed_account);
+      Self::change_social_account_reputation(account.clone(), follower.clone(),
+        reputation_diff * -1,
+        ScoringAction::FollowAccount,
+        ScoredEntity::Account(account.clone())
+      )?;
+
       <AccountsFollowedByAccount<T>>::mutate(follower.clone(), |account_ids| Self::vec_remove_on(account_ids, account.clone()));
       <AccountFollowers<T>>::mutate(account.clone(), |account_ids| Self::vec_remove_on(account_ids, follower.clone()));
       <AccountFollowedByAccount<T>>::remove((follower.clone(), account.clone()));
@@ -642,7 +1181,6 @@ decl_module! {
           updated: None,
           username: username.clone(),
           ipfs_hash,
-          edit_history: vec![]
         }
       );
       <AccountByProfileUsername<T>>::insert(username.clone(), owner.clone());
@@ -690,7 +1228,7 @@ decl_module! {
 
       if is_update_applied {
         profile.updated = Some(Self::new_change(owner.clone()));
-        profile.edit_history.push(new_history_record);
+        Self::add_profile_history_record(owner.clone(), new_history_record)?;
         social_account.profile = Some(profile);
         <SocialAccountById<T>>::insert(owner.clone(), social_account);
 
@@ -702,6 +1240,7 @@ decl_module! {
       let owner = ensure_signed(origin)?;
 
       let mut blog = Self::blog_by_id(blog_id).ok_or(Error::<T>::BlogNotFound)?;
+      ensure!(Self::has_blog_permission(&owner, &blog, BlogPermission::CreatePost), Error::<T>::NotABlogWriter);
       blog.posts_count = blog.posts_count.checked_add(1).ok_or(Error::<T>::OverflowAddingPostOnBlog)?;
 
       let new_post_id = Self::next_post_id();
@@ -732,7 +1271,6 @@ decl_module! {
         upvotes_count: 0,
         downvotes_count: 0,
         shares_count: 0,
-        edit_history: vec![],
         score: 0,
       };
 
@@ -754,9 +1292,12 @@ decl_module! {
       ensure!(has_updates, Error::<T>::NoUpdatesInPost);
 
       let mut post = Self::post_by_id(post_id).ok_or(Error::<T>::PostNotFound)?;
+      let mut blog = Self::blog_by_id(post.blog_id).ok_or(Error::<T>::BlogNotFound)?;
 
-      // TODO ensure: blog writers also should be able to edit this post:
-      ensure!(owner == post.created.account, Error::<T>::NotAPostAuthor);
+      ensure!(
+        owner == post.created.account || Self::has_blog_permission(&owner, &blog, BlogPermission::UpdateAnyPost),
+        Error::<T>::NotAPostAuthor
+      );
 
       let mut fields_updated = 0;
       let mut new_history_record = PostHistoryRecord {
@@ -776,35 +1317,108 @@ decl_module! {
       // Move this post to another blog:
       if let Some(blog_id) = update.blog_id {
         if blog_id != post.blog_id {
-          Self::ensure_blog_exists(blog_id)?;
+          let mut new_blog = Self::blog_by_id(blog_id).ok_or(Error::<T>::BlogNotFound)?;
+          ensure!(Self::has_blog_permission(&owner, &new_blog, BlogPermission::CreatePost), Error::<T>::NotABlogWriter);
+
+          // Move the post and its score from the old blog to the new one:
+          blog.posts_count = blog.posts_count.checked_sub(1).ok_or(Error::<T>::UnderflowRemovingPostFromBlog)?;
+          blog.score = blog.score.checked_sub(post.score).ok_or(Error::<T>::OutOfBoundsUpdatingBlogScore)?;
+          new_blog.posts_count = new_blog.posts_count.checked_add(1).ok_or(Error::<T>::OverflowAddingPostOnBlog)?;
+          new_blog.score = new_blog.score.checked_add(post.score).ok_or(Error::<T>::OutOfBoundsUpdatingBlogScore)?;
+
+          let old_blog_id = post.blog_id;
 
           // Remove post_id from its old blog:
-          PostIdsByBlogId::mutate(post.blog_id, |post_ids| Self::vec_remove_on(post_ids, post_id));
+          PostIdsByBlogId::mutate(old_blog_id, |post_ids| Self::vec_remove_on(post_ids, post_id));
 
           // Add post_id to its new blog:
-          PostIdsByBlogId::mutate(blog_id.clone(), |ids| ids.push(post_id));
-          new_history_record.old_data.blog_id = Some(post.blog_id);
+          PostIdsByBlogId::mutate(blog_id, |ids| ids.push(post_id));
+
+          <BlogById<T>>::insert(old_blog_id, blog);
+          <BlogById<T>>::insert(blog_id, new_blog);
+
+          new_history_record.old_data.blog_id = Some(old_blog_id);
           post.blog_id = blog_id;
           fields_updated += 1;
+
+          Self::deposit_event(RawEvent::PostMoved(owner.clone(), post_id, old_blog_id, blog_id));
         }
       }
 
       // Update this post only if at least one field should be updated:
       if fields_updated > 0 {
         post.updated = Some(Self::new_change(owner.clone()));
-        post.edit_history.push(new_history_record);
+        Self::add_post_history_record(post_id, new_history_record)?;
         <PostById<T>>::insert(post_id, post);
 
         Self::deposit_event(RawEvent::PostUpdated(owner.clone(), post_id));
       }
     }
 
+    /// Delete a post along with its comments and reactions, reverting all the score
+    /// and reputation that were given for it. If the post is a share, it is unshared.
+    pub fn delete_post(origin, post_id: PostId) {
+      let owner = ensure_signed(origin)?;
+
+      let ref mut post = Self::post_by_id(post_id).ok_or(Error::<T>::PostNotFound)?;
+      ensure!(owner == post.created.account, Error::<T>::NotAPostAuthor);
+      Self::ensure_post_removable(post)?;
+
+      for comment_id in Self::comment_ids_by_post_id(post_id) {
+        if let Some(ref mut comment) = Self::comment_by_id(comment_id) {
+          Self::remove_comment(comment, post)?;
+        }
+      }
+      Self::remove_post_reactions(post)?;
+      Self::remove_post_shares(post)?;
+
+      match post.extension {
+        PostExtension::RegularPost => (),
+        PostExtension::SharedPost(original_post_id) => {
+          Self::unshare_post(owner.clone(), original_post_id, post_id)?;
+        },
+        PostExtension::SharedComment(original_comment_id) => {
+          Self::unshare_comment(owner.clone(), original_comment_id, post_id)?;
+        },
+      }
+
+      // Blog should be read after the post score is reverted as it changes the blog score too:
+      let blog_id = post.blog_id;
+      let mut blog = Self::blog_by_id(blog_id).ok_or(Error::<T>::BlogNotFound)?;
+      blog.posts_count = blog.posts_count.checked_sub(1).ok_or(Error::<T>::UnderflowRemovingPostFromBlog)?;
+
+      <PostById<T>>::remove(post_id);
+      <PostHotScoreById<T>>::remove(post_id);
+      Self::remove_post_history(post_id);
+      PostIdsByBlogId::mutate(blog_id, |ids| Self::vec_remove_on(ids, post_id));
+      CommentIdsByPostId::remove(post_id);
+      <BlogById<T>>::insert(blog_id, blog);
+
+      Self::deposit_event(RawEvent::PostDeleted(owner, post_id));
+    }
+
     pub fn create_comment(origin, post_id: PostId, parent_id: Option<CommentId>, ipfs_hash: Vec<u8>) {
       let owner = ensure_signed(origin)?;
 
       let ref mut post = Self::post_by_id(post_id).ok_or(Error::<T>::PostNotFound)?;
+      let blog = Self::blog_by_id(post.blog_id).ok_or(Error::<T>::BlogNotFound)?;
+      ensure!(Self::has_blog_permission(&owner, &blog, BlogPermission::CreateComment), Error::<T>::NotABlogCommenter);
       Self::is_ipfs_hash_valid(ipfs_hash.clone())?;
 
+      let mut depth = 0;
+      let mut parent_comment_opt = None;
+      if let Some(id) = parent_id {
+        let mut parent_comment = Self::comment_by_id(id).ok_or(Error::<T>::UnknownParentComment)?;
+        ensure!(parent_comment.post_id == post_id, Error::<T>::ParentCommentOnAnotherPost);
+        ensure!(!parent_comment.is_deleted(), Error::<T>::CommentIsDeleted);
+
+        depth = parent_comment.depth + 1;
+        ensure!(depth <= Self::max_comment_depth(), Error::<T>::MaxCommentDepthReached);
+
+        parent_comment.direct_replies_count = parent_comment.direct_replies_count.checked_add(1).ok_or(Error::<T>::OverflowReplyingOnComment)?;
+        parent_comment_opt = Some(parent_comment);
+      }
+
       let comment_id = Self::next_comment_id();
       let new_comment: Comment<T> = Comment {
         id: comment_id,
@@ -817,7 +1431,7 @@ decl_module! {
         downvotes_count: 0,
         shares_count: 0,
         direct_replies_count: 0,
-        edit_history: vec![],
+        depth,
         score: 0,
       };
 
@@ -825,10 +1439,10 @@ decl_module! {
 
       Self::change_post_score(owner.clone(), post, ScoringAction::CreateComment)?;
 
-      if let Some(id) = parent_id {
-        let mut parent_comment = Self::comment_by_id(id).ok_or(Error::<T>::UnknownParentComment)?;
-        parent_comment.direct_replies_count = parent_comment.direct_replies_count.checked_add(1).ok_or(Error::<T>::OverflowReplyingOnComment)?;
-        <CommentById<T>>::insert(id, parent_comment);
+      if let Some(parent_comment) = parent_comment_opt {
+        let parent_id = parent_comment.id;
+        <CommentById<T>>::insert(parent_id, parent_comment);
+        CommentIdsByParentId::mutate(parent_id, |ids| ids.push(comment_id));
       }
 
       <CommentById<T>>::insert(comment_id, new_comment);
@@ -844,6 +1458,7 @@ decl_module! {
 
       let mut comment = Self::comment_by_id(comment_id).ok_or(Error::<T>::CommentNotFound)?;
       ensure!(owner == comment.created.account, Error::<T>::NotACommentAuthor);
+      ensure!(!comment.is_deleted(), Error::<T>::CommentIsDeleted);
 
       let ipfs_hash = update.ipfs_hash;
       ensure!(ipfs_hash != comment.ipfs_hash, Error::<T>::CommentIPFSHashNotDiffer);
@@ -853,7 +1468,7 @@ decl_module! {
         edited: Self::new_change(owner.clone()),
         old_data: CommentUpdate {ipfs_hash: comment.ipfs_hash}
       };
-      comment.edit_history.push(new_history_record);
+      Self::add_comment_history_record(comment_id, new_history_record)?;
 
       comment.ipfs_hash = ipfs_hash;
       comment.updated = Some(Self::new_change(owner.clone()));
@@ -862,228 +1477,195 @@ decl_module! {
       Self::deposit_event(RawEvent::CommentUpdated(owner.clone(), comment_id));
     }
 
-    pub fn create_post_reaction(origin, post_id: PostId, kind: ReactionKind) {
+    /// Delete a comment. A comment without replies is removed completely, while a comment
+    /// that has replies is turned into a tombstone so its reply tree stays navigable.
+    /// A tombstone is removed as soon as its last reply is removed.
+    pub fn delete_comment(origin, comment_id: CommentId) {
       let owner = ensure_signed(origin)?;
 
-      ensure!(
-        !<PostReactionIdByAccount<T>>::exists((owner.clone(), post_id)),
-        Error::<T>::AccountAlreadyReactedToPost
-      );
-
+      let ref mut comment = Self::comment_by_id(comment_id).ok_or(Error::<T>::CommentNotFound)?;
+      let post_id = comment.post_id;
       let ref mut post = Self::post_by_id(post_id).ok_or(Error::<T>::PostNotFound)?;
-      let reaction_id = Self::new_reaction(owner.clone(), kind.clone());
-      let action: ScoringAction;
+      let blog = Self::blog_by_id(post.blog_id).ok_or(Error::<T>::BlogNotFound)?;
 
-      match kind {
-        ReactionKind::Upvote => {
-          post.upvotes_count = post.upvotes_count.checked_add(1).ok_or(Error::<T>::OverflowUpvotingPost)?;
-          action = ScoringAction::UpvotePost;
-        },
-        ReactionKind::Downvote => {
-          post.downvotes_count = post.downvotes_count.checked_add(1).ok_or(Error::<T>::OverflowDownvotingPost)?;
-          action = ScoringAction::DownvotePost;
-        },
-      }
+      // Blog moderators are able to delete any comment in their blog:
+      ensure!(
+        owner == comment.created.account || Self::has_blog_permission(&owner, &blog, BlogPermission::DeleteAnyComment),
+        Error::<T>::NotACommentAuthor
+      );
+      ensure!(!comment.is_deleted(), Error::<T>::CommentIsDeleted);
 
-      if post.created.account != owner {
-        Self::change_post_score(owner.clone(), post, action)?;
-      }
-      else {
+      if comment.direct_replies_count > 0 {
+        comment.ipfs_hash = vec![];
+        comment.updated = Some(Self::new_change(owner.clone()));
+        <CommentById<T>>::insert(comment_id, comment);
+      } else {
+        Self::remove_leaf_comment(comment.clone(), post)?;
         <PostById<T>>::insert(post_id, post);
       }
 
-      ReactionIdsByPostId::mutate(post_id, |ids| ids.push(reaction_id));
-      <PostReactionIdByAccount<T>>::insert((owner.clone(), post_id), reaction_id);
-
-      Self::deposit_event(RawEvent::PostReactionCreated(owner.clone(), post_id, reaction_id));
+      Self::deposit_event(RawEvent::CommentDeleted(owner, comment_id));
     }
 
-    pub fn update_post_reaction(origin, post_id: PostId, reaction_id: ReactionId, new_kind: ReactionKind) {
+    pub fn react(origin, target: ReactionTarget<T::AccountId>, kind: ReactionKind) {
       let owner = ensure_signed(origin)?;
+      Self::do_react(owner, target, kind)?;
+    }
 
-      ensure!(
-        <PostReactionIdByAccount<T>>::exists((owner.clone(), post_id)),
-        Error::<T>::AccountNotYetReactedToPost
-      );
-
-      let mut reaction = Self::reaction_by_id(reaction_id).ok_or(Error::<T>::ReactionNotFound)?;
-      let ref mut post = Self::post_by_id(post_id).ok_or(Error::<T>::PostNotFound)?;
-
-      ensure!(owner == reaction.created.account, Error::<T>::NotAReactionOwner);
-      ensure!(reaction.kind != new_kind, Error::<T>::NewReactionKindNotDiffer);
+    pub fn update_reaction(origin, target: ReactionTarget<T::AccountId>, new_kind: ReactionKind) {
+      let owner = ensure_signed(origin)?;
+      Self::do_update_reaction(owner, target, new_kind)?;
+    }
 
-      reaction.kind = new_kind;
-      reaction.updated = Some(Self::new_change(owner.clone()));
-      let action: ScoringAction;
-      let action_to_cancel: ScoringAction;
+    pub fn delete_reaction(origin, target: ReactionTarget<T::AccountId>) {
+      let owner = ensure_signed(origin)?;
+      Self::do_delete_reaction(owner, target)?;
+    }
 
-      match new_kind {
-        ReactionKind::Upvote => {
-          post.upvotes_count += 1;
-          post.downvotes_count -= 1;
-          action_to_cancel = ScoringAction::DownvotePost;
-          action = ScoringAction::UpvotePost;
-        },
-        ReactionKind::Downvote => {
-          post.downvotes_count += 1;
-          post.upvotes_count -= 1;
-          action_to_cancel = ScoringAction::UpvotePost;
-          action = ScoringAction::DownvotePost;
-        },
-      }
-      Self::change_post_score(owner.clone(), post, action_to_cancel)?;
-      Self::change_post_score(owner.clone(), post, action)?;
+    // Post and comment reaction calls are kept for existing clients.
+    // Besides the generic reaction events, they emit the events of the post and comment reactions.
+    pub fn create_post_reaction(origin, post_id: PostId, kind: ReactionKind) {
+      let owner = ensure_signed(origin)?;
+      let reaction_id = Self::do_react(owner.clone(), ReactionTarget::Post(post_id), kind)?;
+      Self::deposit_event(RawEvent::PostReactionCreated(owner, post_id, reaction_id));
+    }
 
-      <ReactionById<T>>::insert(reaction_id, reaction);
-      <PostById<T>>::insert(post_id, post);
+    pub fn update_post_reaction(origin, post_id: PostId, new_kind: ReactionKind) {
+      let owner = ensure_signed(origin)?;
+      let reaction_id = Self::do_update_reaction(owner.clone(), ReactionTarget::Post(post_id), new_kind)?;
+      Self::deposit_event(RawEvent::PostReactionUpdated(owner, post_id, reaction_id));
+    }
 
-      Self::deposit_event(RawEvent::PostReactionUpdated(owner.clone(), post_id, reaction_id));
+    pub fn delete_post_reaction(origin, post_id: PostId) {
+      let owner = ensure_signed(origin)?;
+      let reaction_id = Self::do_delete_reaction(owner.clone(), ReactionTarget::Post(post_id))?;
+      Self::deposit_event(RawEvent::PostReactionDeleted(owner, post_id, reaction_id));
     }
 
-    pub fn delete_post_reaction(origin, post_id: PostId, reaction_id: ReactionId) {
+    pub fn create_comment_reaction(origin, comment_id: CommentId, kind: ReactionKind) {
       let owner = ensure_signed(origin)?;
+      let reaction_id = Self::do_react(owner.clone(), ReactionTarget::Comment(comment_id), kind)?;
+      Self::deposit_event(RawEvent::CommentReactionCreated(owner, comment_id, reaction_id));
+    }
 
-      ensure!(
-        <PostReactionIdByAccount<T>>::exists((owner.clone(), post_id)),
-        Error::<T>::PostReactionByAccountNotFound
-      );
+    pub fn update_comment_reaction(origin, comment_id: CommentId, new_kind: ReactionKind) {
+      let owner = ensure_signed(origin)?;
+      let reaction_id = Self::do_update_reaction(owner.clone(), ReactionTarget::Comment(comment_id), new_kind)?;
+      Self::deposit_event(RawEvent::CommentReactionUpdated(owner, comment_id, reaction_id));
+    }
 
-      let action_to_cancel: ScoringAction;
-      let reaction = Self::reaction_by_id(reaction_id).ok_or(Error::<T>::ReactionNotFound)?;
-      let ref mut post = Self::post_by_id(post_id).ok_or(Error::<T>::PostNotFound)?;
+    pub fn delete_comment_reaction(origin, comment_id: CommentId) {
+      let owner = ensure_signed(origin)?;
+      let reaction_id = Self::do_delete_reaction(owner.clone(), ReactionTarget::Comment(comment_id))?;
+      Self::deposit_event(RawEvent::CommentReactionDeleted(owner, comment_id, reaction_id));
+    }
 
-      ensure!(owner == reaction.created.account, Error::<T>::NotAReactionOwner);
+    /// Rebuild reputation of an account from the reputation ledger.
+    pub fn recalculate_reputation(origin, account: T::AccountId) {
+      ensure_root(origin)?;
 
-      match reaction.kind {
-        ReactionKind::Upvote => {
-          post.upvotes_count -= 1;
-          action_to_cancel = ScoringAction::UpvotePost;
-        },
-        ReactionKind::Downvote => {
-          post.downvotes_count -= 1;
-          action_to_cancel = ScoringAction::DownvotePost;
+      let audit = Self::audit_reputation(account.clone());
+      let mut social_account = Self::social_account_by_id(account.clone()).ok_or(Error::<T>::SocialAccountNotFound)?;
 
-        },
+      for key in audit.missing_ledger_entries.iter() {
+        <ReputationDiffKeysByAccount<T>>::mutate(account.clone(), |keys| Self::vec_remove_on(keys, key.clone()));
       }
-      Self::change_post_score(owner.clone(), post, action_to_cancel)?;
 
-      <PostById<T>>::insert(post_id, post);
-      <ReactionById<T>>::remove(reaction_id);
-      ReactionIdsByPostId::mutate(post_id, |ids| Self::vec_remove_on(ids, reaction_id));
-      <PostReactionIdByAccount<T>>::remove((owner.clone(), post_id));
+      let old_reputation = social_account.reputation;
+      social_account.reputation = audit.expected_reputation;
+      <SocialAccountById<T>>::insert(account.clone(), social_account);
 
-      Self::deposit_event(RawEvent::PostReactionDeleted(owner.clone(), post_id, reaction_id));
+      Self::deposit_event(RawEvent::AccountReputationRecalculated(account, old_reputation, audit.expected_reputation));
     }
 
-    pub fn create_comment_reaction(origin, comment_id: CommentId, kind: ReactionKind) {
-      let owner = ensure_signed(origin)?;
+    pub fn set_scoring_weights(origin, weights: ScoringWeights) {
+      ensure_root(origin)?;
 
-      ensure!(
-        !<CommentReactionIdByAccount<T>>::exists((owner.clone(), comment_id)),
-        Error::<T>::AccountAlreadyReactedToComment
-      );
+      Self::ensure_scoring_weights_valid(&weights)?;
 
-      let ref mut comment = Self::comment_by_id(comment_id).ok_or(Error::<T>::CommentNotFound)?;
-      let reaction_id = Self::new_reaction(owner.clone(), kind.clone());
-      let action: ScoringAction;
+      UpvotePostActionWeight::put(weights.upvote_post);
+      DownvotePostActionWeight::put(weights.downvote_post);
+      SharePostActionWeight::put(weights.share_post);
+      CreateCommentActionWeight::put(weights.create_comment);
+      UpvoteCommentActionWeight::put(weights.upvote_comment);
+      DownvoteCommentActionWeight::put(weights.downvote_comment);
+      ShareCommentActionWeight::put(weights.share_comment);
+      FollowBlogActionWeight::put(weights.follow_blog);
+      FollowAccountActionWeight::put(weights.follow_account);
 
-      match kind {
-        ReactionKind::Upvote => {
-          comment.upvotes_count = comment.upvotes_count.checked_add(1).ok_or(Error::<T>::OverflowUpvotingComment)?;
-          action = ScoringAction::UpvoteComment;
-        },
-        ReactionKind::Downvote => {
-          comment.downvotes_count = comment.downvotes_count.checked_add(1).ok_or(Error::<T>::OverflowDownvotingComment)?;
-          action = ScoringAction::DownvoteComment;
-        },
-      }
-      if comment.created.account != owner {
-        Self::change_comment_score(owner.clone(), comment, action)?;
-      }
-      else {
-        <CommentById<T>>::insert(comment_id, comment);
-      }
+      Self::deposit_event(RawEvent::ScoringWeightsUpdated(weights));
+    }
 
-      ReactionIdsByCommentId::mutate(comment_id, |ids| ids.push(reaction_id));
-      <CommentReactionIdByAccount<T>>::insert((owner.clone(), comment_id), reaction_id);
+    pub fn set_reputation_tiers(origin, tiers: ReputationTiers) {
+      ensure_root(origin)?;
 
-      Self::deposit_event(RawEvent::CommentReactionCreated(owner.clone(), comment_id, reaction_id));
-    }
+      Self::ensure_reputation_tiers_valid(&tiers)?;
 
-    pub fn update_comment_reaction(origin, comment_id: CommentId, reaction_id: ReactionId, new_kind: ReactionKind) {
-      let owner = ensure_signed(origin)?;
+      MinReputationToDownvote::put(tiers.downvote);
+      MinReputationToCreateBlog::put(tiers.create_blog);
+      MinReputationToModerate::put(tiers.moderate);
 
-      ensure!(
-        <CommentReactionIdByAccount<T>>::exists((owner.clone(), comment_id)),
-        Error::<T>::AccountNotYetReactedToComment
-      );
+      Self::deposit_event(RawEvent::ReputationTiersUpdated(tiers));
+    }
 
-      let mut reaction = Self::reaction_by_id(reaction_id).ok_or(Error::<T>::ReactionNotFound)?;
-      let ref mut comment = Self::comment_by_id(comment_id).ok_or(Error::<T>::CommentNotFound)?;
+    /// Set the number of blocks in which hot scores halve.
+    /// Hot scores are decayed with the new half-life from the block they were last updated at.
+    pub fn set_score_half_life(origin, half_life: u32) {
+      ensure_root(origin)?;
 
-      ensure!(owner == reaction.created.account, Error::<T>::NotAReactionOwner);
-      ensure!(reaction.kind != new_kind, Error::<T>::NewReactionKindNotDiffer);
+      Self::ensure_score_half_life_valid(half_life)?;
+      ScoreHalfLife::put(half_life);
 
-      reaction.kind = new_kind;
-      reaction.updated = Some(Self::new_change(owner.clone()));
-      let action: ScoringAction;
-      let action_to_cancel: ScoringAction;
+      Self::deposit_event(RawEvent::ScoreHalfLifeUpdated(half_life));
+    }
 
-      match new_kind {
-        ReactionKind::Upvote => {
-          comment.upvotes_count += 1;
-          comment.downvotes_count -= 1;
-          action_to_cancel = ScoringAction::DownvoteComment;
-          action = ScoringAction::UpvoteComment;
-        },
-        ReactionKind::Downvote => {
-          comment.downvotes_count += 1;
-          comment.upvotes_count -= 1;
-          action_to_cancel = ScoringAction::UpvoteComment;
-          action = ScoringAction::DownvoteComment;
-        },
-      }
-      Self::change_comment_score(owner.clone(), comment, action_to_cancel)?;
-      Self::change_comment_score(owner.clone(), comment, action)?;
+    pub fn set_content_limits(origin, limits: ContentLimits) {
+      ensure_root(origin)?;
 
-      <ReactionById<T>>::insert(reaction_id, reaction);
-      <CommentById<T>>::insert(comment_id, comment);
+      Self::ensure_content_limits_valid(&limits)?;
 
-      Self::deposit_event(RawEvent::CommentReactionUpdated(owner.clone(), comment_id, reaction_id));
+      SlugMinLen::put(limits.slug_min_len);
+      SlugMaxLen::put(limits.slug_max_len);
+      IpfsHashLen::put(limits.ipfs_hash_len);
+      UsernameMinLen::put(limits.username_min_len);
+      UsernameMaxLen::put(limits.username_max_len);
+      BlogMaxLen::put(limits.blog_max_len);
+      PostMaxLen::put(limits.post_max_len);
+      CommentMaxLen::put(limits.comment_max_len);
+      MaxCommentDepth::put(limits.max_comment_depth);
+      MaxEditHistoryRecords::put(limits.max_edit_history_records);
+
+      Self::deposit_event(RawEvent::ContentLimitsUpdated(limits));
     }
 
-    pub fn delete_comment_reaction(origin, comment_id: CommentId, reaction_id: ReactionId) {
-      let owner = ensure_signed(origin)?;
+    /// Prune edit history of an entity down to `MaxEditHistoryRecords` latest records.
+    /// Useful after lowering the limit, as otherwise history is only pruned on the next edit.
+    pub fn prune_history(origin, entity: HistoryEntity<T::AccountId>) {
+      ensure_root(origin)?;
 
-      ensure!(
-        <CommentReactionIdByAccount<T>>::exists((owner.clone(), comment_id)),
-        Error::<T>::CommentReactionByAccountNotFound
-      );
+      let pruned_count = match entity.clone() {
+        HistoryEntity::Blog(blog_id) => Self::prune_blog_history(blog_id),
+        HistoryEntity::Post(post_id) => Self::prune_post_history(post_id),
+        HistoryEntity::Comment(comment_id) => Self::prune_comment_history(comment_id),
+        HistoryEntity::Profile(account) => Self::prune_profile_history(account),
+      };
 
-      let action_to_cancel: ScoringAction;
-      let reaction = Self::reaction_by_id(reaction_id).ok_or(Error::<T>::ReactionNotFound)?;
-      let ref mut comment = Self::comment_by_id(comment_id).ok_or(Error::<T>::CommentNotFound)?;
+      Self::deposit_event(RawEvent::HistoryPruned(entity, pruned_count));
+    }
 
-      ensure!(owner == reaction.created.account, Error::<T>::NotAReactionOwner);
+    /// Set the kind an emoji reaction is scored as, or make it not affect score with `None`.
+    /// Reactions already given with this emoji keep the score they have given until they are updated or deleted.
+    pub fn set_emoji_scoring_kind(origin, emoji: u8, scoring_kind: Option<ReactionKind>) {
+      ensure_root(origin)?;
 
-      match reaction.kind {
-        ReactionKind::Upvote => {
-          comment.upvotes_count -= 1;
-          action_to_cancel = ScoringAction::UpvoteComment
-        },
-        ReactionKind::Downvote => {
-          comment.downvotes_count -= 1;
-          action_to_cancel = ScoringAction::DownvoteComment
-        },
+      if let Some(kind) = scoring_kind {
+        ensure!(kind == ReactionKind::Upvote || kind == ReactionKind::Downvote, Error::<T>::InvalidEmojiScoringKind);
+        EmojiScoringKind::insert(emoji, kind);
+      } else {
+        EmojiScoringKind::remove(emoji);
       }
-      Self::change_comment_score(owner.clone(), comment, action_to_cancel)?;
-
-      <CommentById<T>>::insert(comment_id, comment);
-      ReactionIdsByCommentId::mutate(comment_id, |ids| Self::vec_remove_on(ids, reaction_id));
-      <ReactionById<T>>::remove(reaction_id);
-      <CommentReactionIdByAccount<T>>::remove((owner.clone(), comment_id));
 
-      Self::deposit_event(RawEvent::CommentReactionDeleted(owner.clone(), comment_id, reaction_id));
+      Self::deposit_event(RawEvent::EmojiScoringKindUpdated(emoji, scoring_kind));
     }
   }
 }
@@ -1096,16 +1678,26 @@ decl_event!(
     BlogUpdated(AccountId, BlogId),
     BlogDeleted(AccountId, BlogId),
 
+    BlogOwnershipTransferCreated(AccountId, BlogId, AccountId),
+    BlogOwnershipTransferred(AccountId, BlogId, AccountId),
+    BlogOwnershipTransferCancelled(AccountId, BlogId),
+
+    BlogRoleGranted(AccountId, BlogId, AccountId, BlogRole),
+    BlogRoleRevoked(AccountId, BlogId, AccountId, BlogRole),
+
     BlogFollowed(AccountId, BlogId),
     BlogUnfollowed(AccountId, BlogId),
 
     AccountReputationChanged(AccountId, ScoringAction, u32),
+    /// Account, old reputation, new reputation.
+    AccountReputationRecalculated(AccountId, u32, u32),
 
     AccountFollowed(AccountId, AccountId),
     AccountUnfollowed(AccountId, AccountId),
 
     PostCreated(AccountId, PostId),
     PostUpdated(AccountId, PostId),
+    PostMoved(AccountId, PostId, BlogId, BlogId),
     PostDeleted(AccountId, PostId),
     PostShared(AccountId, PostId),
 
@@ -1122,7 +1714,20 @@ decl_event!(
     CommentReactionUpdated(AccountId, CommentId, ReactionId),
     CommentReactionDeleted(AccountId, CommentId, ReactionId),
 
+    ReactionCreated(AccountId, ReactionTarget<AccountId>, ReactionId),
+    ReactionUpdated(AccountId, ReactionTarget<AccountId>, ReactionId),
+    ReactionDeleted(AccountId, ReactionTarget<AccountId>, ReactionId),
+
     ProfileCreated(AccountId),
     ProfileUpdated(AccountId),
+
+    ScoringWeightsUpdated(ScoringWeights),
+    ContentLimitsUpdated(ContentLimits),
+    ReputationTiersUpdated(ReputationTiers),
+    ScoreHalfLifeUpdated(u32),
+    EmojiScoringKindUpdated(u8, Option<ReactionKind>),
+    HistoryPruned(HistoryEntity<AccountId>, u32),
+    /// Storage version being migrated to, number of values removed because they could not be decoded.
+    StorageValuesDropped(u32, u32),
   }
 );
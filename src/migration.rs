@@ -0,0 +1,530 @@
+use super::*;
+
+use frame_support::{dispatch::DispatchResult, storage::{unhashed, StorageMap, StoragePrefixedMap}};
+use sp_std::{cell::Cell, collections::{btree_map::BTreeMap, btree_set::BTreeSet}};
+
+/// Version of the storage layout written by this code.
+/// Bump it along with adding a migration step to `Module::migrate_storage`.
+pub const CURRENT_STORAGE_VERSION: u32 = 3;
+
+/// Layout of a blog history record in storage version 0, before blogs could restrict comments.
+#[derive(Encode, Decode, Clone, Eq, PartialEq, RuntimeDebug)]
+pub struct BlogUpdateV0<AccountId> {
+    pub writers: Option<Vec<AccountId>>,
+    pub slug: Option<Vec<u8>>,
+    pub ipfs_hash: Option<Vec<u8>>,
+}
+
+#[derive(Encode, Decode, Clone, Eq, PartialEq, RuntimeDebug)]
+pub struct BlogHistoryRecordV0<T: Trait> {
+    pub edited: Change<T>,
+    pub old_data: BlogUpdateV0<T::AccountId>,
+}
+
+/// Layout of a blog in storage version 0: no owner apart from its creator,
+/// no restriction of comments and `u16` counters.
+#[derive(Encode, Decode, Clone, Eq, PartialEq, RuntimeDebug)]
+pub struct BlogV0<T: Trait> {
+    pub id: BlogId,
+    pub created: Change<T>,
+    pub updated: Option<Change<T>>,
+    pub writers: Vec<T::AccountId>,
+    pub slug: Vec<u8>,
+    pub ipfs_hash: Vec<u8>,
+    pub posts_count: u16,
+    pub followers_count: u32,
+    pub edit_history: Vec<BlogHistoryRecordV0<T>>,
+    pub score: i32,
+}
+
+/// Layout of a post in storage version 0, with `u16` counters.
+#[derive(Encode, Decode, Clone, Eq, PartialEq, RuntimeDebug)]
+pub struct PostV0<T: Trait> {
+    pub id: PostId,
+    pub blog_id: BlogId,
+    pub created: Change<T>,
+    pub updated: Option<Change<T>>,
+    pub extension: PostExtension,
+    pub ipfs_hash: Vec<u8>,
+    pub comments_count: u16,
+    pub upvotes_count: u16,
+    pub downvotes_count: u16,
+    pub shares_count: u16,
+    pub edit_history: Vec<PostHistoryRecord<T>>,
+    pub score: i32,
+}
+
+/// Layout of a comment in storage version 0: no depth and `u16` counters.
+#[derive(Encode, Decode, Clone, Eq, PartialEq, RuntimeDebug)]
+pub struct CommentV0<T: Trait> {
+    pub id: CommentId,
+    pub parent_id: Option<CommentId>,
+    pub post_id: PostId,
+    pub created: Change<T>,
+    pub updated: Option<Change<T>>,
+    pub ipfs_hash: Vec<u8>,
+    pub upvotes_count: u16,
+    pub downvotes_count: u16,
+    pub shares_count: u16,
+    pub direct_replies_count: u16,
+    pub edit_history: Vec<CommentHistoryRecord<T>>,
+    pub score: i32,
+}
+
+/// Layout of a social account in storage version 0, with `u16` counters.
+#[derive(Encode, Decode, Clone, Eq, PartialEq, RuntimeDebug)]
+pub struct SocialAccountV0<T: Trait> {
+    pub followers_count: u32,
+    pub following_accounts_count: u16,
+    pub following_blogs_count: u16,
+    pub reputation: u32,
+    pub profile: Option<ProfileV1<T>>,
+}
+
+/// Layout of a blog before its edit history was moved to separate storage (storage version 1).
+#[derive(Encode, Decode, Clone, Eq, PartialEq, RuntimeDebug)]
+pub struct BlogV1<T: Trait> {
+    pub id: BlogId,
+    pub created: Change<T>,
+    pub updated: Option<Change<T>>,
+    pub owner: T::AccountId,
+    pub writers: Vec<T::AccountId>,
+    pub slug: Vec<u8>,
+    pub ipfs_hash: Vec<u8>,
+    pub comments_restricted: bool,
+    pub posts_count: u32,
+    pub followers_count: u32,
+    pub edit_history: Vec<BlogHistoryRecord<T>>,
+    pub score: i32,
+}
+
+/// Layout of a post before its edit history was moved to separate storage (storage version 1).
+#[derive(Encode, Decode, Clone, Eq, PartialEq, RuntimeDebug)]
+pub struct PostV1<T: Trait> {
+    pub id: PostId,
+    pub blog_id: BlogId,
+    pub created: Change<T>,
+    pub updated: Option<Change<T>>,
+    pub extension: PostExtension,
+    pub ipfs_hash: Vec<u8>,
+    pub comments_count: u32,
+    pub upvotes_count: u32,
+    pub downvotes_count: u32,
+    pub shares_count: u32,
+    pub edit_history: Vec<PostHistoryRecord<T>>,
+    pub score: i32,
+}
+
+/// Layout of a comment before its edit history was moved to separate storage (storage version 1).
+#[derive(Encode, Decode, Clone, Eq, PartialEq, RuntimeDebug)]
+pub struct CommentV1<T: Trait> {
+    pub id: CommentId,
+    pub parent_id: Option<CommentId>,
+    pub post_id: PostId,
+    pub created: Change<T>,
+    pub updated: Option<Change<T>>,
+    pub ipfs_hash: Vec<u8>,
+    pub upvotes_count: u32,
+    pub downvotes_count: u32,
+    pub shares_count: u32,
+    pub direct_replies_count: u32,
+    pub depth: u32,
+    pub edit_history: Vec<CommentHistoryRecord<T>>,
+    pub score: i32,
+}
+
+/// Layout of a profile before its edit history was moved to separate storage (storage versions 0 and 1).
+#[derive(Encode, Decode, Clone, Eq, PartialEq, RuntimeDebug)]
+pub struct ProfileV1<T: Trait> {
+    pub created: Change<T>,
+    pub updated: Option<Change<T>>,
+    pub username: Vec<u8>,
+    pub ipfs_hash: Vec<u8>,
+    pub edit_history: Vec<ProfileHistoryRecord<T>>,
+}
+
+/// Layout of a social account before profile edit history was moved to separate storage (storage version 1).
+#[derive(Encode, Decode, Clone, Eq, PartialEq, RuntimeDebug)]
+pub struct SocialAccountV1<T: Trait> {
+    pub followers_count: u32,
+    pub following_accounts_count: u32,
+    pub following_blogs_count: u32,
+    pub reputation: u32,
+    pub profile: Option<ProfileV1<T>>,
+}
+
+impl<T: Trait> From<BlogHistoryRecordV0<T>> for BlogHistoryRecord<T> {
+    fn from(old: BlogHistoryRecordV0<T>) -> Self {
+        BlogHistoryRecord {
+            edited: old.edited,
+            old_data: BlogUpdate {
+                writers: old.old_data.writers,
+                slug: old.old_data.slug,
+                ipfs_hash: old.old_data.ipfs_hash,
+                comments_restricted: None,
+            },
+        }
+    }
+}
+
+impl<T: Trait> From<BlogV0<T>> for BlogV1<T> {
+    fn from(old: BlogV0<T>) -> Self {
+        BlogV1 {
+            id: old.id,
+            // Only the creator of a blog could own it in storage version 0:
+            owner: old.created.account.clone(),
+            created: old.created,
+            updated: old.updated,
+            writers: old.writers,
+            slug: old.slug,
+            ipfs_hash: old.ipfs_hash,
+            comments_restricted: false,
+            posts_count: old.posts_count.into(),
+            followers_count: old.followers_count,
+            edit_history: old.edit_history.into_iter().map(Into::into).collect(),
+            score: old.score,
+        }
+    }
+}
+
+impl<T: Trait> From<PostV0<T>> for PostV1<T> {
+    fn from(old: PostV0<T>) -> Self {
+        PostV1 {
+            id: old.id,
+            blog_id: old.blog_id,
+            created: old.created,
+            updated: old.updated,
+            extension: old.extension,
+            ipfs_hash: old.ipfs_hash,
+            comments_count: old.comments_count.into(),
+            upvotes_count: old.upvotes_count.into(),
+            downvotes_count: old.downvotes_count.into(),
+            shares_count: old.shares_count.into(),
+            edit_history: old.edit_history,
+            score: old.score,
+        }
+    }
+}
+
+impl<T: Trait> CommentV0<T> {
+    /// `depth` is not stored in version 0, so it is computed from the parents of a comment.
+    fn into_v1(self, depth: u32) -> CommentV1<T> {
+        CommentV1 {
+            id: self.id,
+            parent_id: self.parent_id,
+            post_id: self.post_id,
+            created: self.created,
+            updated: self.updated,
+            ipfs_hash: self.ipfs_hash,
+            upvotes_count: self.upvotes_count.into(),
+            downvotes_count: self.downvotes_count.into(),
+            shares_count: self.shares_count.into(),
+            direct_replies_count: self.direct_replies_count.into(),
+            depth,
+            edit_history: self.edit_history,
+            score: self.score,
+        }
+    }
+}
+
+impl<T: Trait> From<SocialAccountV0<T>> for SocialAccountV1<T> {
+    fn from(old: SocialAccountV0<T>) -> Self {
+        SocialAccountV1 {
+            followers_count: old.followers_count,
+            following_accounts_count: old.following_accounts_count.into(),
+            following_blogs_count: old.following_blogs_count.into(),
+            reputation: old.reputation,
+            profile: old.profile,
+        }
+    }
+}
+
+impl<T: Trait> Module<T> {
+
+    /// Bring the storage from the version it is stored in up to `CURRENT_STORAGE_VERSION`.
+    /// The version is stored after every step, so that a step is never applied twice.
+    pub fn migrate_storage() {
+        let storage_version = Self::storage_version();
+
+        if storage_version < 1 {
+            Self::finish_migration_step(1, Self::migrate_from_v0());
+        }
+        if storage_version < 2 {
+            Self::finish_migration_step(2, Self::migrate_edit_history());
+        }
+        if storage_version < 3 {
+            Self::finish_migration_step(3, Self::migrate_reputation_ledger());
+        }
+    }
+
+    /// `dropped` is the number of values that a step could not decode in the old layout.
+    /// Such values are removed by `translate_values`, so they are reported with an event.
+    fn finish_migration_step(version: u32, dropped: u32) {
+        if dropped > 0 {
+            Self::deposit_event(RawEvent::StorageValuesDropped(version, dropped));
+        }
+        StorageVersion::put(version);
+    }
+
+    fn dropped_values(translated: Result<(), u32>) -> u32 {
+        translated.err().unwrap_or(0)
+    }
+
+    /// Convert blogs, posts, comments and social accounts from the layout of storage version 0:
+    /// widen their counters from `u16` to `u32`, fill in the fields added since then
+    /// and build the `BlogIdsByWriter` and `CommentIdsByParentId` indexes.
+    /// Reactions are moved to the indexes keyed by `ReactionTarget`.
+    fn migrate_from_v0() -> u32 {
+        let comment_depths = Self::comment_depths_v0();
+        let mut dropped = 0;
+
+        dropped += Self::dropped_values(<BlogById<T>>::translate_values(|old: BlogV0<T>| {
+            for writer in old.writers.iter() {
+                <BlogIdsByWriter<T>>::mutate(writer.clone(), |ids| if !ids.contains(&old.id) { ids.push(old.id) });
+            }
+            BlogV1::from(old)
+        }));
+        dropped += Self::dropped_values(<PostById<T>>::translate_values(|old: PostV0<T>| PostV1::from(old)));
+        dropped += Self::dropped_values(<CommentById<T>>::translate_values(|old: CommentV0<T>| {
+            let depth = comment_depths.get(&old.id).copied().unwrap_or_default();
+            old.into_v1(depth)
+        }));
+        dropped += Self::dropped_values(<SocialAccountById<T>>::translate_values(
+            |old: SocialAccountV0<T>| SocialAccountV1::from(old)));
+        Self::migrate_reactions_v0();
+
+        dropped
+    }
+
+    /// Move reactions of posts and comments from the indexes of storage version 0
+    /// to `ReactionIdsByTarget` and `ReactionIdByAccount`, counting them per kind.
+    fn migrate_reactions_v0() {
+        for post_id in 1..Self::next_post_id() {
+            let target = ReactionTarget::Post(post_id);
+            for reaction_id in ReactionIdsByPostId::take(post_id) {
+                if let Some(reaction) = Self::reaction_by_id(reaction_id) {
+                    <PostReactionIdByAccount<T>>::remove((reaction.created.account.clone(), post_id));
+                    Self::index_reaction_v0(reaction, target.clone());
+                }
+            }
+        }
+
+        for comment_id in 1..Self::next_comment_id() {
+            let target = ReactionTarget::Comment(comment_id);
+            for reaction_id in ReactionIdsByCommentId::take(comment_id) {
+                if let Some(reaction) = Self::reaction_by_id(reaction_id) {
+                    <CommentReactionIdByAccount<T>>::remove((reaction.created.account.clone(), comment_id));
+                    Self::index_reaction_v0(reaction, target.clone());
+                }
+            }
+        }
+    }
+
+    fn index_reaction_v0(reaction: Reaction<T>, target: ReactionTarget<T::AccountId>) {
+        <ReactionIdsByTarget<T>>::mutate(target.clone(), |ids| ids.push(reaction.id));
+        <ReactionIdByAccount<T>>::insert((reaction.created.account, target.clone()), reaction.id);
+        <ReactionCountByTarget<T>>::mutate((target, reaction.kind), |count| *count = count.saturating_add(1));
+    }
+
+    /// Depths of comments stored in the version 0 layout. Replies are added to `CommentIdsByParentId` on the way.
+    fn comment_depths_v0() -> BTreeMap<CommentId, u32> {
+        let mut depths = BTreeMap::new();
+
+        // A reply is always created after its parent, so the depth of the parent is known by then:
+        for comment_id in 1..Self::next_comment_id() {
+            let key = <CommentById<T>>::hashed_key_for(comment_id);
+            if let Some(comment) = unhashed::get::<CommentV0<T>>(&key) {
+                let depth = match comment.parent_id {
+                    Some(parent_id) => {
+                        CommentIdsByParentId::mutate(parent_id, |ids| if !ids.contains(&comment_id) { ids.push(comment_id) });
+                        depths.get(&parent_id).map_or(0, |parent_depth| parent_depth + 1)
+                    },
+                    None => 0,
+                };
+                depths.insert(comment_id, depth);
+            }
+        }
+
+        depths
+    }
+
+    /// Move edit history of blogs, posts, comments and profiles out of their structs
+    /// to the `*HistoryRecordByIndex` maps, keeping the order of records.
+    /// All records are moved, even beyond `MaxEditHistoryRecords`: they can be pruned by `prune_history`.
+    /// Records that could not be moved are counted as dropped values.
+    fn migrate_edit_history() -> u32 {
+        let mut dropped = 0;
+        let failed_records = Cell::new(0);
+
+        dropped += Self::dropped_values(<BlogById<T>>::translate_values(|old: BlogV1<T>| {
+            for record in old.edit_history {
+                Self::count_failed_record(&failed_records, Self::insert_blog_history_record(old.id, record));
+            }
+            Blog {
+                id: old.id,
+                created: old.created,
+                updated: old.updated,
+                owner: old.owner,
+                writers: old.writers,
+                slug: old.slug,
+                ipfs_hash: old.ipfs_hash,
+                comments_restricted: old.comments_restricted,
+                posts_count: old.posts_count,
+                followers_count: old.followers_count,
+                score: old.score,
+            }
+        }));
+
+        dropped += Self::dropped_values(<PostById<T>>::translate_values(|old: PostV1<T>| {
+            for record in old.edit_history {
+                Self::count_failed_record(&failed_records, Self::insert_post_history_record(old.id, record));
+            }
+            Post {
+                id: old.id,
+                blog_id: old.blog_id,
+                created: old.created,
+                updated: old.updated,
+                extension: old.extension,
+                ipfs_hash: old.ipfs_hash,
+                comments_count: old.comments_count,
+                upvotes_count: old.upvotes_count,
+                downvotes_count: old.downvotes_count,
+                shares_count: old.shares_count,
+                score: old.score,
+            }
+        }));
+
+        dropped += Self::dropped_values(<CommentById<T>>::translate_values(|old: CommentV1<T>| {
+            for record in old.edit_history {
+                Self::count_failed_record(&failed_records, Self::insert_comment_history_record(old.id, record));
+            }
+            Comment {
+                id: old.id,
+                parent_id: old.parent_id,
+                post_id: old.post_id,
+                created: old.created,
+                updated: old.updated,
+                ipfs_hash: old.ipfs_hash,
+                upvotes_count: old.upvotes_count,
+                downvotes_count: old.downvotes_count,
+                shares_count: old.shares_count,
+                direct_replies_count: old.direct_replies_count,
+                depth: old.depth,
+                score: old.score,
+            }
+        }));
+
+        dropped += Self::dropped_values(<SocialAccountById<T>>::translate_values(|old: SocialAccountV1<T>| {
+            let profile = old.profile.map(|profile| {
+                // A profile is stored under the account that created it:
+                for record in profile.edit_history {
+                    Self::count_failed_record(&failed_records, Self::insert_profile_history_record(profile.created.account.clone(), record));
+                }
+                Profile {
+                    created: profile.created,
+                    updated: profile.updated,
+                    username: profile.username,
+                    ipfs_hash: profile.ipfs_hash,
+                }
+            });
+            SocialAccount {
+                followers_count: old.followers_count,
+                following_accounts_count: old.following_accounts_count,
+                following_blogs_count: old.following_blogs_count,
+                reputation: old.reputation,
+                profile,
+            }
+        }));
+
+        dropped + failed_records.get()
+    }
+
+    fn count_failed_record(failed_records: &Cell<u32>, result: DispatchResult) {
+        if result.is_err() {
+            failed_records.set(failed_records.get().saturating_add(1));
+        }
+    }
+
+    /// Rebuild the reputation ledger keyed by scored entities. Diffs of votes, shares and comments
+    /// are taken from `PostScoreByAccount` and `CommentScoreByAccount`, the scorers are found
+    /// via reactions, shared posts and comments of each post or comment.
+    /// Diffs of follows are taken from the ledger of the previous versions, so a follow is not restored
+    /// if its entry was shared with another follow of the same owner's blog, or if the follower
+    /// is not referenced by any blog, post, comment or reaction.
+    fn migrate_reputation_ledger() -> u32 {
+        <ReputationDiffKeysByAccount<T>>::remove_all();
+
+        let post_actions = [ScoringAction::UpvotePost, ScoringAction::DownvotePost, ScoringAction::SharePost, ScoringAction::CreateComment];
+        let comment_actions = [ScoringAction::UpvoteComment, ScoringAction::DownvoteComment, ScoringAction::ShareComment];
+        let mut accounts = BTreeSet::new();
+
+        for post_id in 1..Self::next_post_id() {
+            if let Some(post) = Self::post_by_id(post_id) {
+                let scorers = Self::post_scorers(post_id);
+
+                for scorer in scorers.iter() {
+                    for action in post_actions.iter() {
+                        if let Some(diff) = Self::post_score_by_account((scorer.clone(), post_id, *action)) {
+                            let key = (scorer.clone(), *action, ScoredEntity::Post(post_id));
+                            Self::restore_reputation_diff(post.created.account.clone(), key, diff);
+                        }
+                    }
+                }
+                accounts.extend(scorers);
+                accounts.insert(post.created.account);
+            }
+        }
+
+        for comment_id in 1..Self::next_comment_id() {
+            if let Some(comment) = Self::comment_by_id(comment_id) {
+                let scorers = Self::comment_scorers(comment_id);
+
+                for scorer in scorers.iter() {
+                    for action in comment_actions.iter() {
+                        if let Some(diff) = Self::comment_score_by_account((scorer.clone(), comment_id, *action)) {
+                            let key = (scorer.clone(), *action, ScoredEntity::Comment(comment_id));
+                            Self::restore_reputation_diff(comment.created.account.clone(), key, diff);
+                        }
+                    }
+                }
+                accounts.extend(scorers);
+                accounts.insert(comment.created.account);
+            }
+        }
+
+        for blog_id in 1..Self::next_blog_id() {
+            if let Some(blog) = Self::blog_by_id(blog_id) {
+                for follower in Self::blog_followers(blog_id) {
+                    if follower != blog.owner {
+                        let legacy_key = (follower.clone(), blog.owner.clone(), ScoringAction::FollowBlog);
+                        if let Some(diff) = <AccountReputationDiffByAccount<T>>::get(legacy_key) {
+                            let key = (follower.clone(), ScoringAction::FollowBlog, ScoredEntity::Blog(blog_id));
+                            Self::restore_reputation_diff(blog.owner.clone(), key, diff);
+                        }
+                    }
+                    accounts.insert(follower);
+                }
+                accounts.insert(blog.owner);
+            }
+        }
+
+        for follower in accounts {
+            for account in Self::accounts_followed_by_account(follower.clone()) {
+                let legacy_key = (follower.clone(), account.clone(), ScoringAction::FollowAccount);
+                if let Some(diff) = <AccountReputationDiffByAccount<T>>::get(legacy_key) {
+                    let key = (follower.clone(), ScoringAction::FollowAccount, ScoredEntity::Account(account.clone()));
+                    Self::restore_reputation_diff(account, key, diff);
+                }
+            }
+        }
+
+        <AccountReputationDiffByAccount<T>>::remove_all();
+        0
+    }
+
+    fn restore_reputation_diff(account: T::AccountId, key: ReputationDiffKey<T::AccountId>, diff: i16) {
+        if !<ReputationDiffByKey<T>>::exists(&key) {
+            Self::insert_reputation_diff(account, key, diff);
+        }
+    }
+}
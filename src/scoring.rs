@@ -0,0 +1,17 @@
+use super::*;
+
+/// Defines how much an action changes the score of content and the reputation of its author.
+pub trait ScoringStrategy<T: Trait> {
+    /// Score diff that `action` performed by `actor` gives to the content and its author.
+    fn score_diff(actor: &SocialAccount<T>, action: ScoringAction) -> i16;
+}
+
+/// Score diff grows logarithmically with the reputation of an actor
+/// and is multiplied by the weight of a scoring action.
+pub struct DefaultScoringStrategy;
+
+impl<T: Trait> ScoringStrategy<T> for DefaultScoringStrategy {
+    fn score_diff(actor: &SocialAccount<T>, action: ScoringAction) -> i16 {
+        Module::<T>::get_score_diff(actor.reputation, action)
+    }
+}
@@ -3,9 +3,9 @@
 pub use super::*;
 
 use sp_core::H256;
-use frame_support::{impl_outer_origin, assert_ok, assert_noop, parameter_types, weights::Weight, dispatch::DispatchResult};
+use frame_support::{impl_outer_origin, assert_ok, assert_noop, parameter_types, weights::Weight, dispatch::DispatchResult, StorageValue, StorageMap};
 use sp_runtime::{
-  traits::{BlakeTwo256, IdentityLookup}, testing::Header, Perbill,
+  traits::{BlakeTwo256, IdentityLookup}, testing::Header, Perbill, DispatchError,
 };
 
 impl_outer_origin! {
@@ -53,6 +53,7 @@ impl pallet_timestamp::Trait for Test {
 
 impl Trait for Test {
   type Event = ();
+  type ScoringStrategy = scoring::DefaultScoringStrategy;
 }
 
 type Social = Module<Test>;
@@ -63,10 +64,23 @@ fn new_test_ext() -> sp_io::TestExternalities {
   system::GenesisConfig::default().build_storage::<Test>().unwrap().into()
 }
 
+fn new_test_ext_with_config(config: GenesisConfig) -> sp_io::TestExternalities {
+  let mut storage = system::GenesisConfig::default().build_storage::<Test>().unwrap();
+  config.assimilate_storage::<Test>(&mut storage).unwrap();
+  storage.into()
+}
+
+// Dispatches made through the helpers below should always leave the storage consistent.
+fn with_invariants_checked(result: DispatchResult) -> DispatchResult {
+  assert_eq!(Social::check_invariants(), vec![]);
+  result
+}
+
 pub type AccountId = u64;
 
 const ACCOUNT1 : AccountId = 1;
 const ACCOUNT2 : AccountId = 2;
+const ACCOUNT3 : AccountId = 3;
 
 fn blog_slug() -> Vec<u8> {
   b"blog_slug".to_vec()
@@ -80,7 +94,17 @@ fn blog_update(writers: Option<Vec<AccountId>>, slug: Option<Vec<u8>>, ipfs_hash
   BlogUpdate {
     writers,
     slug,
-    ipfs_hash
+    ipfs_hash,
+    comments_restricted: None
+  }
+}
+
+fn blog_update_comments_restricted(comments_restricted: bool) -> BlogUpdate<u64> {
+  BlogUpdate {
+    writers: None,
+    slug: None,
+    ipfs_hash: None,
+    comments_restricted: Some(comments_restricted)
   }
 }
 
@@ -126,6 +150,9 @@ fn reaction_upvote() -> ReactionKind {
 fn reaction_downvote() -> ReactionKind {
   ReactionKind::Downvote
 }
+fn reaction_emoji() -> ReactionKind {
+  ReactionKind::Emoji(1)
+}
 
 fn scoring_action_upvote_post() -> ScoringAction {
   ScoringAction::UpvotePost
@@ -155,6 +182,35 @@ fn scoring_action_follow_account() -> ScoringAction {
   ScoringAction::FollowAccount
 }
 
+fn scoring_weights() -> ScoringWeights {
+  ScoringWeights {
+    upvote_post: 10,
+    downvote_post: -6,
+    share_post: 8,
+    create_comment: 4,
+    upvote_comment: 6,
+    downvote_comment: -4,
+    share_comment: 5,
+    follow_blog: 9,
+    follow_account: 2,
+  }
+}
+
+fn content_limits() -> ContentLimits {
+  ContentLimits {
+    slug_min_len: 3,
+    slug_max_len: 40,
+    ipfs_hash_len: DEFAULT_IPFS_HASH_LEN,
+    username_min_len: 4,
+    username_max_len: 30,
+    blog_max_len: 500,
+    post_max_len: 5_000,
+    comment_max_len: 500,
+    max_comment_depth: 5,
+    max_edit_history_records: 50,
+  }
+}
+
 fn extension_regular_post() -> PostExtension {
   PostExtension::RegularPost
 }
@@ -170,19 +226,70 @@ fn _create_default_blog() -> DispatchResult {
 }
 
 fn _create_blog(origin: Option<Origin>, slug: Option<Vec<u8>>, ipfs_hash: Option<Vec<u8>>) -> DispatchResult {
-  Social::create_blog(
+  with_invariants_checked(Social::create_blog(
     origin.unwrap_or(Origin::signed(ACCOUNT1)),
     slug.unwrap_or(self::blog_slug()),
     ipfs_hash.unwrap_or(self::blog_ipfs_hash())
-  )
+  ))
 }
 
 fn _update_blog(origin: Option<Origin>, blog_id: Option<u32>, update: Option<BlogUpdate<u64>>) -> DispatchResult {
-  Social::update_blog(
+  with_invariants_checked(Social::update_blog(
     origin.unwrap_or(Origin::signed(ACCOUNT1)),
     blog_id.unwrap_or(1).into(),
     update.unwrap_or(self::blog_update(None, None, None))
-  )
+  ))
+}
+
+fn _delete_blog(origin: Option<Origin>, blog_id: Option<BlogId>) -> DispatchResult {
+  with_invariants_checked(Social::delete_blog(
+    origin.unwrap_or(Origin::signed(ACCOUNT1)),
+    blog_id.unwrap_or(1)
+  ))
+}
+
+fn _grant_blog_role(origin: Option<Origin>, account: Option<AccountId>, role: BlogRole) -> DispatchResult {
+  with_invariants_checked(Social::grant_blog_role(
+    origin.unwrap_or(Origin::signed(ACCOUNT1)),
+    1,
+    account.unwrap_or(ACCOUNT2),
+    role
+  ))
+}
+
+fn _revoke_blog_role(origin: Option<Origin>, account: Option<AccountId>, role: BlogRole) -> DispatchResult {
+  with_invariants_checked(Social::revoke_blog_role(
+    origin.unwrap_or(Origin::signed(ACCOUNT1)),
+    1,
+    account.unwrap_or(ACCOUNT2),
+    role
+  ))
+}
+
+fn _transfer_default_blog_ownership() -> DispatchResult {
+  _transfer_blog_ownership(None, None, None)
+}
+
+fn _transfer_blog_ownership(origin: Option<Origin>, blog_id: Option<BlogId>, new_owner: Option<AccountId>) -> DispatchResult {
+  with_invariants_checked(Social::transfer_blog_ownership(
+    origin.unwrap_or(Origin::signed(ACCOUNT1)),
+    blog_id.unwrap_or(1),
+    new_owner.unwrap_or(ACCOUNT2)
+  ))
+}
+
+fn _accept_blog_ownership(origin: Option<Origin>, blog_id: Option<BlogId>) -> DispatchResult {
+  with_invariants_checked(Social::accept_blog_ownership(
+    origin.unwrap_or(Origin::signed(ACCOUNT2)),
+    blog_id.unwrap_or(1)
+  ))
+}
+
+fn _cancel_blog_ownership_transfer(origin: Option<Origin>, blog_id: Option<BlogId>) -> DispatchResult {
+  with_invariants_checked(Social::cancel_blog_ownership_transfer(
+    origin.unwrap_or(Origin::signed(ACCOUNT1)),
+    blog_id.unwrap_or(1)
+  ))
 }
 
 fn _default_follow_blog() -> DispatchResult {
@@ -190,10 +297,10 @@ fn _default_follow_blog() -> DispatchResult {
 }
 
 fn _follow_blog(origin: Option<Origin>, blog_id: Option<BlogId>) -> DispatchResult {
-  Social::follow_blog(
+  with_invariants_checked(Social::follow_blog(
     origin.unwrap_or(Origin::signed(ACCOUNT2)),
     blog_id.unwrap_or(1)
-  )
+  ))
 }
 
 fn _default_unfollow_blog() -> DispatchResult {
@@ -201,10 +308,10 @@ fn _default_unfollow_blog() -> DispatchResult {
 }
 
 fn _unfollow_blog(origin: Option<Origin>, blog_id: Option<BlogId>) -> DispatchResult {
-  Social::unfollow_blog(
+  with_invariants_checked(Social::unfollow_blog(
     origin.unwrap_or(Origin::signed(ACCOUNT2)),
     blog_id.unwrap_or(1)
-  )
+  ))
 }
 
 fn _create_default_post() -> DispatchResult {
@@ -212,20 +319,27 @@ fn _create_default_post() -> DispatchResult {
 }
 
 fn _create_post(origin: Option<Origin>, blog_id: Option<BlogId>, ipfs_hash: Option<Vec<u8>>, extension: Option<PostExtension>) -> DispatchResult {
-  Social::create_post(
+  with_invariants_checked(Social::create_post(
     origin.unwrap_or(Origin::signed(ACCOUNT1)),
     blog_id.unwrap_or(1),
     ipfs_hash.unwrap_or(self::post_ipfs_hash()),
     extension.unwrap_or(self::extension_regular_post())
-  )
+  ))
 }
 
 fn _update_post(origin: Option<Origin>, post_id: Option<PostId>, update: Option<PostUpdate>) -> DispatchResult {
-  Social::update_post(
+  with_invariants_checked(Social::update_post(
     origin.unwrap_or(Origin::signed(ACCOUNT1)),
     post_id.unwrap_or(1),
     update.unwrap_or(self::post_update(None, None))
-  )
+  ))
+}
+
+fn _delete_post(origin: Option<Origin>, post_id: Option<PostId>) -> DispatchResult {
+  with_invariants_checked(Social::delete_post(
+    origin.unwrap_or(Origin::signed(ACCOUNT1)),
+    post_id.unwrap_or(1)
+  ))
 }
 
 fn _create_default_comment() -> DispatchResult {
@@ -233,20 +347,27 @@ fn _create_default_comment() -> DispatchResult {
 }
 
 fn _create_comment(origin: Option<Origin>, post_id: Option<PostId>, parent_id: Option<CommentId>, ipfs_hash: Option<Vec<u8>>) -> DispatchResult {
-  Social::create_comment(
+  with_invariants_checked(Social::create_comment(
     origin.unwrap_or(Origin::signed(ACCOUNT1)),
     post_id.unwrap_or(1),
     parent_id,
     ipfs_hash.unwrap_or(self::comment_ipfs_hash())
-  )
+  ))
 }
 
 fn _update_comment(origin: Option<Origin>, comment_id: Option<CommentId>, update: Option<CommentUpdate>) -> DispatchResult {
-  Social::update_comment(
+  with_invariants_checked(Social::update_comment(
     origin.unwrap_or(Origin::signed(ACCOUNT1)),
     comment_id.unwrap_or(1),
     update.unwrap_or(self::comment_update(self::subcomment_ipfs_hash()))
-  )
+  ))
+}
+
+fn _delete_comment(origin: Option<Origin>, comment_id: Option<CommentId>) -> DispatchResult {
+  with_invariants_checked(Social::delete_comment(
+    origin.unwrap_or(Origin::signed(ACCOUNT1)),
+    comment_id.unwrap_or(1)
+  ))
 }
 
 fn _create_default_post_reaction() -> DispatchResult {
@@ -258,53 +379,72 @@ fn _create_default_comment_reaction() -> DispatchResult {
 }
 
 fn _create_post_reaction(origin: Option<Origin>, post_id: Option<PostId>, kind: Option<ReactionKind>) -> DispatchResult {
-  Social::create_post_reaction(
+  with_invariants_checked(Social::create_post_reaction(
     origin.unwrap_or(Origin::signed(ACCOUNT1)),
     post_id.unwrap_or(1),
     kind.unwrap_or(self::reaction_upvote())
-  )
+  ))
 }
 
 fn _create_comment_reaction(origin: Option<Origin>, comment_id: Option<CommentId>, kind: Option<ReactionKind>) -> DispatchResult {
-  Social::create_comment_reaction(
+  with_invariants_checked(Social::create_comment_reaction(
     origin.unwrap_or(Origin::signed(ACCOUNT1)),
     comment_id.unwrap_or(1),
     kind.unwrap_or(self::reaction_upvote())
-  )
+  ))
 }
 
-fn _update_post_reaction(origin: Option<Origin>, post_id: Option<PostId>, reaction_id: ReactionId, kind: Option<ReactionKind>) -> DispatchResult {
-  Social::update_post_reaction(
+fn _update_post_reaction(origin: Option<Origin>, post_id: Option<PostId>, kind: Option<ReactionKind>) -> DispatchResult {
+  with_invariants_checked(Social::update_post_reaction(
     origin.unwrap_or(Origin::signed(ACCOUNT1)),
     post_id.unwrap_or(1),
-    reaction_id,
     kind.unwrap_or(self::reaction_upvote())
-  )
+  ))
 }
 
-fn _update_comment_reaction(origin: Option<Origin>, comment_id: Option<CommentId>, reaction_id: ReactionId, kind: Option<ReactionKind>) -> DispatchResult {
-  Social::update_comment_reaction(
+fn _update_comment_reaction(origin: Option<Origin>, comment_id: Option<CommentId>, kind: Option<ReactionKind>) -> DispatchResult {
+  with_invariants_checked(Social::update_comment_reaction(
     origin.unwrap_or(Origin::signed(ACCOUNT1)),
     comment_id.unwrap_or(1),
-    reaction_id,
     kind.unwrap_or(self::reaction_upvote())
-  )
+  ))
 }
 
-fn _delete_post_reaction(origin: Option<Origin>, post_id: Option<PostId>, reaction_id: ReactionId) -> DispatchResult {
-  Social::delete_post_reaction(
+fn _delete_post_reaction(origin: Option<Origin>, post_id: Option<PostId>) -> DispatchResult {
+  with_invariants_checked(Social::delete_post_reaction(
     origin.unwrap_or(Origin::signed(ACCOUNT1)),
-    post_id.unwrap_or(1),
-    reaction_id
-  )
+    post_id.unwrap_or(1)
+  ))
 }
 
-fn _delete_comment_reaction(origin: Option<Origin>, comment_id: Option<CommentId>, reaction_id: ReactionId) -> DispatchResult {
-  Social::delete_comment_reaction(
+fn _delete_comment_reaction(origin: Option<Origin>, comment_id: Option<CommentId>) -> DispatchResult {
+  with_invariants_checked(Social::delete_comment_reaction(
     origin.unwrap_or(Origin::signed(ACCOUNT1)),
-    comment_id.unwrap_or(1),
-    reaction_id
-  )
+    comment_id.unwrap_or(1)
+  ))
+}
+
+fn _react(origin: Option<Origin>, target: Option<ReactionTarget<AccountId>>, kind: Option<ReactionKind>) -> DispatchResult {
+  with_invariants_checked(Social::react(
+    origin.unwrap_or(Origin::signed(ACCOUNT1)),
+    target.unwrap_or(ReactionTarget::Post(1)),
+    kind.unwrap_or(self::reaction_upvote())
+  ))
+}
+
+fn _update_reaction(origin: Option<Origin>, target: Option<ReactionTarget<AccountId>>, kind: Option<ReactionKind>) -> DispatchResult {
+  with_invariants_checked(Social::update_reaction(
+    origin.unwrap_or(Origin::signed(ACCOUNT1)),
+    target.unwrap_or(ReactionTarget::Post(1)),
+    kind.unwrap_or(self::reaction_upvote())
+  ))
+}
+
+fn _delete_reaction(origin: Option<Origin>, target: Option<ReactionTarget<AccountId>>) -> DispatchResult {
+  with_invariants_checked(Social::delete_reaction(
+    origin.unwrap_or(Origin::signed(ACCOUNT1)),
+    target.unwrap_or(ReactionTarget::Post(1))
+  ))
 }
 
 fn _create_default_profile() -> DispatchResult {
@@ -312,21 +452,21 @@ fn _create_default_profile() -> DispatchResult {
 }
 
 fn _create_profile(origin: Option<Origin>, username: Option<Vec<u8>>, ipfs_hash: Option<Vec<u8>>) -> DispatchResult {
-  Social::create_profile(
+  with_invariants_checked(Social::create_profile(
     origin.unwrap_or(Origin::signed(ACCOUNT1)),
     username.unwrap_or(self::alice_username()),
     ipfs_hash.unwrap_or(self::profile_ipfs_hash())
-  )
+  ))
 }
 
 fn _update_profile(origin: Option<Origin>, username: Option<Vec<u8>>, ipfs_hash: Option<Vec<u8>>) -> DispatchResult {
-  Social::update_profile(
+  with_invariants_checked(Social::update_profile(
     origin.unwrap_or(Origin::signed(ACCOUNT1)),
     ProfileUpdate {
       username,
       ipfs_hash
     }
-  )
+  ))
 }
 
 fn _default_follow_account() -> DispatchResult {
@@ -334,10 +474,10 @@ fn _default_follow_account() -> DispatchResult {
 }
 
 fn _follow_account(origin: Option<Origin>, account: Option<AccountId>) -> DispatchResult {
-  Social::follow_account(
+  with_invariants_checked(Social::follow_account(
     origin.unwrap_or(Origin::signed(ACCOUNT2)),
     account.unwrap_or(ACCOUNT1)
-  )
+  ))
 }
 
 fn _default_unfollow_account() -> DispatchResult {
@@ -345,10 +485,10 @@ fn _default_unfollow_account() -> DispatchResult {
 }
 
 fn _unfollow_account(origin: Option<Origin>, account: Option<AccountId>) -> DispatchResult {
-  Social::unfollow_account(
+  with_invariants_checked(Social::unfollow_account(
     origin.unwrap_or(Origin::signed(ACCOUNT2)),
     account.unwrap_or(ACCOUNT1)
-  )
+  ))
 }
 
 fn _change_post_score_by_id(account: AccountId, post_id: PostId, action: ScoringAction) -> DispatchResult {
@@ -382,12 +522,13 @@ fn create_blog_should_work() {
     let blog = Social::blog_by_id(1).unwrap();
 
     assert_eq!(blog.created.account, ACCOUNT1);
+    assert_eq!(blog.owner, ACCOUNT1);
     assert_eq!(blog.slug, self::blog_slug());
     assert_eq!(blog.ipfs_hash, self::blog_ipfs_hash());
     assert!(blog.writers.is_empty());
     assert_eq!(blog.posts_count, 0);
     assert_eq!(blog.followers_count, 1);
-    assert!(blog.edit_history.is_empty());
+    assert!(Social::blog_edit_history(1, 0).is_empty());
   });
 }
 
@@ -456,9 +597,9 @@ fn update_blog_should_work() {
     assert_eq!(blog.ipfs_hash, ipfs_hash);
 
     // Check whether history recorded correctly
-    assert_eq!(blog.edit_history[0].old_data.writers, None);
-    assert_eq!(blog.edit_history[0].old_data.slug, Some(self::blog_slug()));
-    assert_eq!(blog.edit_history[0].old_data.ipfs_hash, Some(self::blog_ipfs_hash()));
+    assert_eq!(Social::blog_edit_history(1, 0)[0].old_data.writers, None);
+    assert_eq!(Social::blog_edit_history(1, 0)[0].old_data.slug, Some(self::blog_slug()));
+    assert_eq!(Social::blog_edit_history(1, 0)[0].old_data.ipfs_hash, Some(self::blog_ipfs_hash()));
   });
 }
 
@@ -598,1312 +739,3621 @@ fn update_blog_should_fail_invalid_ipfs_hash() {
   });
 }
 
-// Post tests
 #[test]
-fn create_post_should_work() {
+fn delete_blog_should_work() {
   new_test_ext().execute_with(|| {
     assert_ok!(_create_default_blog()); // BlogId 1
-    assert_ok!(_create_default_post()); // PostId 1
+    assert_ok!(_default_follow_blog()); // Follow BlogId 1 by ACCOUNT2
+    assert_ok!(_update_blog(None, None, Some(self::blog_update(Some(vec![ACCOUNT2]), None, None))));
+
+    assert_ok!(_delete_blog(None, None));
 
     // Check storages
-    assert_eq!(Social::post_ids_by_blog_id(1), vec![1]);
-    assert_eq!(Social::next_post_id(), 2);
+    assert!(Social::blog_by_id(1).is_none());
+    assert_eq!(Social::blog_id_by_slug(self::blog_slug()), None);
+    assert!(Social::blog_ids_by_owner(ACCOUNT1).is_empty());
+    assert!(Social::blog_ids_by_writer(ACCOUNT2).is_empty());
+    assert!(Social::blog_followers(1).is_empty());
+    assert!(Social::blogs_followed_by_account(ACCOUNT1).is_empty());
+    assert!(Social::blogs_followed_by_account(ACCOUNT2).is_empty());
+    assert_eq!(Social::blog_followed_by_account((ACCOUNT2, 1)), false);
 
-    // Check whether data stored correctly
-    let post = Social::post_by_id(1).unwrap();
+    // Check whether follow counters and reputation reverted
+    assert_eq!(Social::social_account_by_id(ACCOUNT1).unwrap().following_blogs_count, 0);
+    assert_eq!(Social::social_account_by_id(ACCOUNT2).unwrap().following_blogs_count, 0);
+    assert_eq!(Social::social_account_by_id(ACCOUNT1).unwrap().reputation, 1);
+    assert_eq!(Social::reputation_diff_by_key((ACCOUNT2, self::scoring_action_follow_blog(), ScoredEntity::Blog(1))), None);
+    assert!(Social::audit_reputation(ACCOUNT1).is_consistent());
 
-    assert_eq!(post.blog_id, 1);
-    assert_eq!(post.created.account, ACCOUNT1);
-    assert_eq!(post.ipfs_hash, self::post_ipfs_hash());
-    assert_eq!(post.comments_count, 0);
-    assert_eq!(post.upvotes_count, 0);
-    assert_eq!(post.downvotes_count, 0);
-    assert_eq!(post.shares_count, 0);
-    assert_eq!(post.extension, self::extension_regular_post());
-    assert!(post.edit_history.is_empty());
+    // Slug should be free to use again
+    assert_ok!(_create_default_blog()); // BlogId 2
   });
 }
 
 #[test]
-fn create_post_should_fail_blog_not_found() {
+fn delete_blog_should_fail_before_removing_any_follower() {
   new_test_ext().execute_with(|| {
-    assert_noop!(_create_default_post(), Error::<Test>::BlogNotFound);
+    assert_ok!(_create_default_blog()); // BlogId 1
+    assert_ok!(_default_follow_blog()); // Follow BlogId 1 by ACCOUNT2
+    assert_ok!(_follow_blog(Some(Origin::signed(ACCOUNT3)), None)); // Follow BlogId 1 by ACCOUNT3
+
+    let mut social_account = Social::social_account_by_id(ACCOUNT3).unwrap();
+    social_account.following_blogs_count = 0;
+    <SocialAccountById<Test>>::insert(ACCOUNT3, social_account);
+
+    // Neither ACCOUNT2, followed before ACCOUNT3, nor the blog should be changed
+    assert_noop!(Social::delete_blog(Origin::signed(ACCOUNT1), 1), Error::<Test>::UnderflowUnfollowingBlog);
+    assert_eq!(Social::social_account_by_id(ACCOUNT2).unwrap().following_blogs_count, 1);
+    assert!(Social::reputation_diff_by_key((ACCOUNT2, self::scoring_action_follow_blog(), ScoredEntity::Blog(1))).is_some());
   });
 }
 
 #[test]
-fn create_post_should_fail_invalid_ipfs_hash() {
-  let ipfs_hash : Vec<u8> = b"QmV9tSDx9UiPeWExXEeH6aoDvmihvx6j".to_vec();
-
+fn delete_blog_should_remove_roles() {
   new_test_ext().execute_with(|| {
     assert_ok!(_create_default_blog()); // BlogId 1
+    assert_ok!(_grant_blog_role(None, None, BlogRole::Writer));
+    assert_ok!(_grant_blog_role(None, None, BlogRole::Commenter));
+    assert_ok!(_grant_blog_role(None, Some(ACCOUNT3), BlogRole::Commenter));
+    assert_eq!(Social::accounts_with_roles_by_blog(1), vec![ACCOUNT2, ACCOUNT3]);
 
-    // Try to catch an error creating a regular post with invalid ipfs_hash
-    assert_noop!(_create_post(None, None, Some(ipfs_hash), None), Error::<Test>::IpfsIsIncorrect);
+    assert_ok!(_delete_blog(None, None));
+
+    assert!(Social::blog_roles_by_account((1, ACCOUNT2)).is_empty());
+    assert!(Social::blog_roles_by_account((1, ACCOUNT3)).is_empty());
+    assert!(Social::accounts_with_roles_by_blog(1).is_empty());
   });
 }
 
 #[test]
-fn update_post_should_work() {
-  let ipfs_hash : Vec<u8> = b"QmRAQB6YaCyidP37UdDnjFY5vQuiBrcqdyoW1CuDgwxkD4".to_vec();
+fn delete_blog_should_fail_blog_not_found() {
+  new_test_ext().execute_with(|| {
+    assert_noop!(_delete_blog(None, None), Error::<Test>::BlogNotFound);
+  });
+}
 
+#[test]
+fn delete_blog_should_fail_not_an_owner() {
   new_test_ext().execute_with(|| {
     assert_ok!(_create_default_blog()); // BlogId 1
-    assert_ok!(_create_default_post()); // PostId 1
-
-    // Post update with ID 1 should be fine
-    assert_ok!(_update_post(None, None,
-      Some(
-        self::post_update(
-          None,
-          Some(ipfs_hash.clone())
-        )
-      )
-    ));
-
-
-    // Check whether post updates correctly
-    let post = Social::post_by_id(1).unwrap();
-    assert_eq!(post.blog_id, 1);
-    assert_eq!(post.ipfs_hash, ipfs_hash);
 
-    // Check whether history recorded correctly
-    assert_eq!(post.edit_history[0].old_data.blog_id, None);
-    assert_eq!(post.edit_history[0].old_data.ipfs_hash, Some(self::post_ipfs_hash()));
+    // Try to catch an error deleting a blog with different account
+    assert_noop!(_delete_blog(Some(Origin::signed(ACCOUNT2)), None), Error::<Test>::NotABlogOwner);
   });
 }
 
 #[test]
-fn update_post_should_fail_nothing_to_update() {
+fn delete_blog_should_fail_blog_has_posts() {
   new_test_ext().execute_with(|| {
     assert_ok!(_create_default_blog()); // BlogId 1
     assert_ok!(_create_default_post()); // PostId 1
 
-    // Try to catch an error updating a post with no changes
-    assert_noop!(_update_post(None, None, None), Error::<Test>::NoUpdatesInPost);
+    // Try to catch an error deleting a blog that still has posts
+    assert_noop!(_delete_blog(None, None), Error::<Test>::BlogHasPosts);
   });
 }
 
 #[test]
-fn update_post_should_fail_post_not_found() {
+fn update_blog_should_work_writers() {
   new_test_ext().execute_with(|| {
     assert_ok!(_create_default_blog()); // BlogId 1
-    assert_ok!(_create_blog(None, Some(b"blog2_slug".to_vec()), None)); // BlogId 2
-    assert_ok!(_create_default_post()); // PostId 1
 
-    // Try to catch an error updating a post with wrong post ID
-    assert_noop!(_update_post(None, Some(2),
-      Some(
-        self::post_update(
-          Some(2),
-          None
-        )
-      )
-    ), Error::<Test>::PostNotFound);
+    assert_ok!(_update_blog(None, None, Some(self::blog_update(Some(vec![ACCOUNT2]), None, None))));
+
+    assert_eq!(Social::blog_by_id(1).unwrap().writers, vec![ACCOUNT2]);
+    assert_eq!(Social::blog_ids_by_writer(ACCOUNT2), vec![1]);
+
+    // Remove ACCOUNT2 from writers
+    assert_ok!(_update_blog(None, None, Some(self::blog_update(Some(vec![]), None, None))));
+
+    let blog = Social::blog_by_id(1).unwrap();
+    assert!(blog.writers.is_empty());
+    assert_eq!(Social::blog_edit_history(1, 0)[1].old_data.writers, Some(vec![ACCOUNT2]));
+    assert!(Social::blog_ids_by_writer(ACCOUNT2).is_empty());
   });
 }
 
 #[test]
-fn update_post_should_fail_not_an_owner() {
+fn update_blog_should_fail_duplicate_writers() {
   new_test_ext().execute_with(|| {
     assert_ok!(_create_default_blog()); // BlogId 1
-    assert_ok!(_create_blog(None, Some(b"blog2_slug".to_vec()), None)); // BlogId 2
-    assert_ok!(_create_default_post()); // PostId 1
 
-    // Try to catch an error updating a post with different account
-    assert_noop!(_update_post(Some(Origin::signed(ACCOUNT2)), None,
+    // Try to catch an error updating a blog with the same writer listed twice
+    assert_noop!(_update_blog(None, None,
       Some(
-        self::post_update(
-          Some(2),
+        self::blog_update(
+          Some(vec![ACCOUNT2, ACCOUNT2]),
+          None,
           None
         )
       )
-    ), Error::<Test>::NotAPostAuthor);
+    ), Error::<Test>::DuplicateBlogWriters);
   });
 }
 
-#[test]
-fn update_post_should_fail_invalid_ipfs_hash() {
-  let ipfs_hash : Vec<u8> = b"QmV9tSDx9UiPeWExXEeH6aoDvmihvx6j".to_vec();
+// Blog roles tests
 
+#[test]
+fn grant_blog_role_should_work() {
   new_test_ext().execute_with(|| {
     assert_ok!(_create_default_blog()); // BlogId 1
-    assert_ok!(_create_default_post()); // PostId 1
 
-    // Try to catch an error updating a post with invalid ipfs_hash
-    assert_noop!(_update_post(None, None,
-      Some(
-        self::post_update(
-          None,
-          Some(ipfs_hash)
-        )
-      )
-    ), Error::<Test>::IpfsIsIncorrect);
+    assert_ok!(_grant_blog_role(None, None, BlogRole::Editor));
+    assert_ok!(_grant_blog_role(None, None, BlogRole::Moderator));
+
+    assert_eq!(Social::blog_roles_by_account((1, ACCOUNT2)), vec![BlogRole::Editor, BlogRole::Moderator]);
   });
 }
 
-// Comment tests
 #[test]
-fn create_comment_should_work() {
+fn grant_blog_role_should_fail_not_an_owner() {
   new_test_ext().execute_with(|| {
     assert_ok!(_create_default_blog()); // BlogId 1
-    assert_ok!(_create_default_post()); // PostId 1
-    assert_ok!(_create_default_comment()); // CommentId 1
 
-    // Check storages
-    assert_eq!(Social::comment_ids_by_post_id(1), vec![1]);
-    assert_eq!(Social::next_comment_id(), 2);
-    assert_eq!(Social::post_by_id(1).unwrap().comments_count, 1);
+    assert_noop!(
+      _grant_blog_role(Some(Origin::signed(ACCOUNT2)), None, BlogRole::Editor),
+      Error::<Test>::NotABlogOwner
+    );
+  });
+}
 
-    // Check whether data stored correctly
-    let comment = Social::comment_by_id(1).unwrap();
+#[test]
+fn grant_blog_role_should_fail_already_granted() {
+  new_test_ext().execute_with(|| {
+    assert_ok!(_create_default_blog()); // BlogId 1
+    assert_ok!(_grant_blog_role(None, None, BlogRole::Editor));
 
-    assert_eq!(comment.parent_id, None);
-    assert_eq!(comment.post_id, 1);
-    assert_eq!(comment.created.account, ACCOUNT1);
-    assert_eq!(comment.ipfs_hash, self::comment_ipfs_hash());
-    assert_eq!(comment.upvotes_count, 0);
-    assert_eq!(comment.downvotes_count, 0);
-    assert_eq!(comment.shares_count, 0);
-    assert_eq!(comment.direct_replies_count, 0);
-    assert!(comment.edit_history.is_empty());
+    assert_noop!(_grant_blog_role(None, None, BlogRole::Editor), Error::<Test>::RoleAlreadyGranted);
   });
 }
 
 #[test]
-fn create_comment_should_work_with_parent() {
+fn revoke_blog_role_should_work() {
   new_test_ext().execute_with(|| {
     assert_ok!(_create_default_blog()); // BlogId 1
-    assert_ok!(_create_default_post()); // PostId 1
-    assert_ok!(_create_default_comment()); // CommentId 1
-    assert_ok!(_create_comment(None, None, Some(1), None)); // CommentId 2 with parent CommentId 1
+    assert_ok!(_grant_blog_role(None, None, BlogRole::Editor));
 
-    // Check storages
-    assert_eq!(Social::comment_ids_by_post_id(1), vec![1, 2]);
-    assert_eq!(Social::next_comment_id(), 3);
-    assert_eq!(Social::post_by_id(1).unwrap().comments_count, 2);
+    assert_ok!(_revoke_blog_role(None, None, BlogRole::Editor));
 
-    // Check whether data stored correctly
-    assert_eq!(Social::comment_by_id(2).unwrap().parent_id, Some(1));
-    assert_eq!(Social::comment_by_id(1).unwrap().direct_replies_count, 1);
+    assert!(Social::blog_roles_by_account((1, ACCOUNT2)).is_empty());
+    assert!(Social::accounts_with_roles_by_blog(1).is_empty());
   });
 }
 
 #[test]
-fn create_comment_should_fail_post_not_found() {
+fn blog_writer_role_should_be_indexed_by_writer() {
   new_test_ext().execute_with(|| {
-    // Try to catch an error creating a comment with wrong post
-    assert_noop!(_create_default_comment(), Error::<Test>::PostNotFound);
+    assert_ok!(_create_default_blog()); // BlogId 1
+
+    assert_ok!(_grant_blog_role(None, None, BlogRole::Writer));
+    assert_eq!(Social::blog_ids_by_writer(ACCOUNT2), vec![1]);
+
+    // The blog is indexed once while the account is a writer in both ways
+    assert_ok!(_update_blog(None, None, Some(self::blog_update(Some(vec![ACCOUNT2]), None, None))));
+    assert_eq!(Social::blog_ids_by_writer(ACCOUNT2), vec![1]);
+    assert_ok!(_revoke_blog_role(None, None, BlogRole::Writer));
+    assert_eq!(Social::blog_ids_by_writer(ACCOUNT2), vec![1]);
+
+    assert_ok!(_grant_blog_role(None, None, BlogRole::Writer));
+    assert_ok!(_update_blog(None, None, Some(self::blog_update(Some(vec![]), None, None))));
+    assert_eq!(Social::blog_ids_by_writer(ACCOUNT2), vec![1]);
+
+    assert_ok!(_revoke_blog_role(None, None, BlogRole::Writer));
+    assert!(Social::blog_ids_by_writer(ACCOUNT2).is_empty());
+
+    assert_ok!(_grant_blog_role(None, None, BlogRole::Writer));
+    assert_ok!(_delete_blog(None, None));
+    assert!(Social::blog_ids_by_writer(ACCOUNT2).is_empty());
   });
 }
 
 #[test]
-fn create_comment_should_fail_parent_not_found() {
+fn revoke_blog_role_should_fail_not_granted() {
   new_test_ext().execute_with(|| {
     assert_ok!(_create_default_blog()); // BlogId 1
-    assert_ok!(_create_default_post()); // PostId 1
 
-    // Try to catch an error creating a comment with wrong parent
-    assert_noop!(_create_comment(None, None, Some(1), None), Error::<Test>::UnknownParentComment);
+    assert_noop!(_revoke_blog_role(None, None, BlogRole::Editor), Error::<Test>::RoleNotGranted);
   });
 }
 
 #[test]
-fn create_comment_should_fail_invalid_ipfs_hash() {
-  let ipfs_hash : Vec<u8> = b"QmV9tSDx9UiPeWExXEeH6aoDvmihvx6j".to_vec();
-
+fn blog_writer_role_should_allow_to_create_post() {
   new_test_ext().execute_with(|| {
     assert_ok!(_create_default_blog()); // BlogId 1
-    assert_ok!(_create_default_post()); // PostId 1
+    assert_ok!(_grant_blog_role(None, None, BlogRole::Writer));
 
-    // Try to catch an error creating a comment with wrong parent
-    assert_noop!(_create_comment(None, None, None, Some(ipfs_hash)), Error::<Test>::IpfsIsIncorrect);
+    assert_ok!(_create_post(Some(Origin::signed(ACCOUNT2)), None, None, None)); // PostId 1 by ACCOUNT2
+    assert_eq!(Social::post_ids_by_blog_id(1), vec![1]);
   });
 }
 
 #[test]
-fn update_comment_should_work() {
+fn blog_editor_role_should_allow_to_update_any_post() {
   new_test_ext().execute_with(|| {
     assert_ok!(_create_default_blog()); // BlogId 1
     assert_ok!(_create_default_post()); // PostId 1
-    assert_ok!(_create_default_comment()); // CommentId 1
+    assert_ok!(_grant_blog_role(None, None, BlogRole::Editor));
 
-    // Post update with ID 1 should be fine
-    assert_ok!(_update_comment(
-      None,
-      None,
-      Some(self::comment_update(self::subcomment_ipfs_hash()))
+    assert_ok!(_update_post(Some(Origin::signed(ACCOUNT2)), None,
+      Some(self::post_update(None, Some(self::subcomment_ipfs_hash())))
     ));
+    assert_eq!(Social::post_by_id(1).unwrap().ipfs_hash, self::subcomment_ipfs_hash());
 
-    // Check whether post updates correctly
-    let comment = Social::comment_by_id(1).unwrap();
-    assert_eq!(comment.ipfs_hash, self::subcomment_ipfs_hash());
-
-    // Check whether history recorded correctly
-    assert_eq!(comment.edit_history[0].old_data.ipfs_hash, self::comment_ipfs_hash());
+    // Editor is not able to create posts
+    assert_noop!(_create_post(Some(Origin::signed(ACCOUNT2)), None, None, None), Error::<Test>::NotABlogWriter);
   });
 }
 
 #[test]
-fn update_comment_should_fail_comment_not_found() {
+fn blog_moderator_role_should_allow_to_delete_any_comment() {
   new_test_ext().execute_with(|| {
-    // Try to catch an error updating a comment with wrong CommentId
-    assert_noop!(_update_comment(
-      None,
-      None,
-      Some(self::comment_update(self::subcomment_ipfs_hash()))
-    ),
-    Error::<Test>::CommentNotFound);
+    assert_ok!(_create_default_blog()); // BlogId 1
+    assert_ok!(_create_default_post()); // PostId 1
+    assert_ok!(_create_default_comment()); // CommentId 1
+
+    assert_noop!(_delete_comment(Some(Origin::signed(ACCOUNT2)), None), Error::<Test>::NotACommentAuthor);
+
+    assert_ok!(_grant_blog_role(None, None, BlogRole::Moderator));
+    assert_ok!(_delete_comment(Some(Origin::signed(ACCOUNT2)), None));
+    assert!(Social::comment_by_id(1).is_none());
   });
 }
 
 #[test]
-fn update_comment_should_fail_not_an_owner() {
+fn blog_commenter_role_should_allow_to_comment_restricted_blog() {
   new_test_ext().execute_with(|| {
     assert_ok!(_create_default_blog()); // BlogId 1
     assert_ok!(_create_default_post()); // PostId 1
-    assert_ok!(_create_default_comment()); // CommentId 1
+    assert_ok!(_update_blog(None, None, Some(self::blog_update_comments_restricted(true))));
 
-    // Try to catch an error updating a comment with wrong Account
-    assert_noop!(_update_comment(
-      Some(Origin::signed(2)),
-      None,
-      Some(self::comment_update(self::subcomment_ipfs_hash()))
-    ),
-    Error::<Test>::NotACommentAuthor);
+    assert_noop!(_create_comment(Some(Origin::signed(ACCOUNT2)), None, None, None), Error::<Test>::NotABlogCommenter);
+
+    assert_ok!(_grant_blog_role(None, None, BlogRole::Commenter));
+    assert_ok!(_create_comment(Some(Origin::signed(ACCOUNT2)), None, None, None)); // CommentId 1 by ACCOUNT2
+    assert_eq!(Social::comment_ids_by_post_id(1), vec![1]);
   });
 }
 
-#[test]
-fn update_comment_should_fail_invalid_ipfs_hash() {
-  let ipfs_hash : Vec<u8> = b"QmV9tSDx9UiPeWExXEeH6aoDvmihvx6j".to_vec();
+// Blog ownership tests
 
+#[test]
+fn transfer_blog_ownership_should_work() {
   new_test_ext().execute_with(|| {
     assert_ok!(_create_default_blog()); // BlogId 1
-    assert_ok!(_create_default_post()); // PostId 1
-    assert_ok!(_create_default_comment()); // CommentId 1
+    assert_ok!(_transfer_default_blog_ownership());
 
-    // Try to catch an error updating a comment with invalid ipfs_hash
-    assert_noop!(_update_comment(
-      None,
-      None,
-      Some(self::comment_update(ipfs_hash))
-    ),
-    Error::<Test>::IpfsIsIncorrect);
+    assert_eq!(Social::pending_blog_owner(1), Some(ACCOUNT2));
+    // Ownership is not changed until the transfer is accepted
+    assert_eq!(Social::blog_by_id(1).unwrap().owner, ACCOUNT1);
   });
 }
 
 #[test]
-fn update_comment_should_fail_ipfs_hash_dont_differ() {
+fn transfer_blog_ownership_should_fail_not_an_owner() {
   new_test_ext().execute_with(|| {
     assert_ok!(_create_default_blog()); // BlogId 1
-    assert_ok!(_create_default_post()); // PostId 1
-    assert_ok!(_create_default_comment()); // CommentId 1
 
-    // Try to catch an error updating a comment with the same ipfs_hash
-    assert_noop!(_update_comment(
-      None,
-      None,
-      Some(self::comment_update(self::comment_ipfs_hash()))
-    ),
-    Error::<Test>::CommentIPFSHashNotDiffer);
+    assert_noop!(
+      _transfer_blog_ownership(Some(Origin::signed(ACCOUNT2)), None, Some(ACCOUNT2)),
+      Error::<Test>::NotABlogOwner
+    );
   });
 }
 
-// Reaction tests
 #[test]
-fn create_post_reaction_should_work_upvote() {
+fn transfer_blog_ownership_should_fail_transfer_to_current_owner() {
   new_test_ext().execute_with(|| {
     assert_ok!(_create_default_blog()); // BlogId 1
-    assert_ok!(_create_default_post()); // PostId 1
-
-    assert_ok!(_create_post_reaction(Some(Origin::signed(ACCOUNT2)), None, None)); // ReactionId 1 by ACCOUNT2
-
-    // Check storages
-    assert_eq!(Social::reaction_ids_by_post_id(1), vec![1]);
-    assert_eq!(Social::next_reaction_id(), 2);
-
-    // Check post reaction counters
-    let post = Social::post_by_id(1).unwrap();
-    assert_eq!(post.upvotes_count, 1);
-    assert_eq!(post.downvotes_count, 0);
 
-    // Check whether data stored correctly
-    let reaction = Social::reaction_by_id(1).unwrap();
-    assert_eq!(reaction.created.account, ACCOUNT2);
-    assert_eq!(reaction.kind, self::reaction_upvote());
+    assert_noop!(_transfer_blog_ownership(None, None, Some(ACCOUNT1)), Error::<Test>::CannotTransferToCurrentOwner);
   });
 }
 
 #[test]
-fn create_post_reaction_should_work_downvote() {
+fn accept_blog_ownership_should_work() {
   new_test_ext().execute_with(|| {
     assert_ok!(_create_default_blog()); // BlogId 1
-    assert_ok!(_create_default_post()); // PostId 1
-
-    assert_ok!(_create_post_reaction(Some(Origin::signed(ACCOUNT2)), None, Some(self::reaction_downvote()))); // ReactionId 1 by ACCOUNT2
+    assert_ok!(_transfer_default_blog_ownership());
+    assert_ok!(_accept_blog_ownership(None, None));
 
-    // Check storages
-    assert_eq!(Social::reaction_ids_by_post_id(1), vec![1]);
-    assert_eq!(Social::next_reaction_id(), 2);
+    let blog = Social::blog_by_id(1).unwrap();
+    assert_eq!(blog.owner, ACCOUNT2);
+    assert_eq!(blog.created.account, ACCOUNT1);
 
-    // Check post reaction counters
-    let post = Social::post_by_id(1).unwrap();
-    assert_eq!(post.upvotes_count, 0);
-    assert_eq!(post.downvotes_count, 1);
+    assert!(Social::pending_blog_owner(1).is_none());
+    assert!(Social::blog_ids_by_owner(ACCOUNT1).is_empty());
+    assert_eq!(Social::blog_ids_by_owner(ACCOUNT2), vec![1]);
 
-    // Check whether data stored correctly
-    let reaction = Social::reaction_by_id(1).unwrap();
-    assert_eq!(reaction.created.account, ACCOUNT2);
-    assert_eq!(reaction.kind, self::reaction_downvote());
+    // The new owner is able to manage the blog, while the previous one is not
+    assert_ok!(_update_blog(Some(Origin::signed(ACCOUNT2)), None, Some(self::blog_update_comments_restricted(true))));
+    assert_noop!(_grant_blog_role(None, Some(ACCOUNT1), BlogRole::Editor), Error::<Test>::NotABlogOwner);
   });
 }
 
 #[test]
-fn create_post_reaction_should_fail_already_reacted() {
+fn unfollow_blog_should_revert_reputation_of_previous_owner() {
   new_test_ext().execute_with(|| {
-    assert_ok!(_create_default_blog()); // BlogId 1
-    assert_ok!(_create_default_post()); // PostId 1
-    assert_ok!(_create_default_post_reaction()); // ReactionId1
+    assert_ok!(_create_default_blog()); // BlogId 1 by ACCOUNT1
+    assert_ok!(_default_follow_blog()); // Follow BlogId 1 by ACCOUNT2
+    assert_eq!(Social::social_account_by_id(ACCOUNT1).unwrap().reputation, 1 + DEFAULT_FOLLOW_BLOG_ACTION_WEIGHT as u32);
 
-    // Try to catch an error creating reaction by the same account
-    assert_noop!(_create_default_post_reaction(), Error::<Test>::AccountAlreadyReactedToPost);
+    assert_ok!(_transfer_blog_ownership(None, None, Some(ACCOUNT3)));
+    assert_ok!(_accept_blog_ownership(Some(Origin::signed(ACCOUNT3)), None));
+
+    assert_ok!(_default_unfollow_blog());
+    assert_eq!(Social::blog_by_id(1).unwrap().score, 0);
+    assert_eq!(Social::social_account_by_id(ACCOUNT1).unwrap().reputation, 1);
+    assert_eq!(Social::social_account_by_id(ACCOUNT3).map(|account| account.reputation).unwrap_or(1), 1);
+    assert!(Social::audit_reputation(ACCOUNT1).is_consistent());
   });
 }
 
 #[test]
-fn create_post_reaction_should_fail_post_not_found() {
+fn accept_blog_ownership_should_fail_no_pending_transfer() {
   new_test_ext().execute_with(|| {
-    // Try to catch an error creating reaction by the same account
-    assert_noop!(_create_default_post_reaction(), Error::<Test>::PostNotFound);
+    assert_ok!(_create_default_blog()); // BlogId 1
+
+    assert_noop!(_accept_blog_ownership(None, None), Error::<Test>::NoPendingTransferOnBlog);
   });
 }
 
 #[test]
-fn create_comment_reaction_should_work_upvote() {
+fn accept_blog_ownership_should_fail_not_a_pending_owner() {
   new_test_ext().execute_with(|| {
     assert_ok!(_create_default_blog()); // BlogId 1
-    assert_ok!(_create_default_post()); // PostId 1
-    assert_ok!(_create_default_comment()); // CommentId 1
-    assert_ok!(_create_comment_reaction(Some(Origin::signed(ACCOUNT2)), None, None)); // ReactionId 1 by ACCOUNT2
-
-    // Check storages
-    assert_eq!(Social::reaction_ids_by_comment_id(1), vec![1]);
-    assert_eq!(Social::next_reaction_id(), 2);
-
-    // Check comment reaction counters
-    let comment = Social::comment_by_id(1).unwrap();
-    assert_eq!(comment.upvotes_count, 1);
-    assert_eq!(comment.downvotes_count, 0);
+    assert_ok!(_transfer_default_blog_ownership());
 
-    // Check whether data stored correctly
-    let reaction = Social::reaction_by_id(1).unwrap();
-    assert_eq!(reaction.created.account, ACCOUNT2);
-    assert_eq!(reaction.kind, self::reaction_upvote());
+    assert_noop!(_accept_blog_ownership(Some(Origin::signed(ACCOUNT1)), None), Error::<Test>::NotAPendingBlogOwner);
   });
 }
 
 #[test]
-fn create_comment_reaction_should_work_downvote() {
+fn cancel_blog_ownership_transfer_should_work() {
   new_test_ext().execute_with(|| {
     assert_ok!(_create_default_blog()); // BlogId 1
-    assert_ok!(_create_default_post()); // PostId 1
-    assert_ok!(_create_default_comment()); // CommentId 1
-    assert_ok!(_create_comment_reaction(Some(Origin::signed(ACCOUNT2)), None, Some(self::reaction_downvote()))); // ReactionId 1 by ACCOUNT2
-
-    // Check storages
-    assert_eq!(Social::reaction_ids_by_comment_id(1), vec![1]);
-    assert_eq!(Social::next_reaction_id(), 2);
+    assert_ok!(_transfer_default_blog_ownership());
+    assert_ok!(_cancel_blog_ownership_transfer(None, None));
 
-    // Check comment reaction counters
-    let comment = Social::comment_by_id(1).unwrap();
-    assert_eq!(comment.upvotes_count, 0);
-    assert_eq!(comment.downvotes_count, 1);
-
-    // Check whether data stored correctly
-    let reaction = Social::reaction_by_id(1).unwrap();
-    assert_eq!(reaction.created.account, ACCOUNT2);
-    assert_eq!(reaction.kind, self::reaction_downvote());
+    assert!(Social::pending_blog_owner(1).is_none());
+    assert_noop!(_accept_blog_ownership(None, None), Error::<Test>::NoPendingTransferOnBlog);
   });
 }
 
 #[test]
-fn create_comment_reaction_should_fail_already_reacted() {
+fn cancel_blog_ownership_transfer_should_work_by_pending_owner() {
   new_test_ext().execute_with(|| {
     assert_ok!(_create_default_blog()); // BlogId 1
-    assert_ok!(_create_default_post()); // PostId 1
-    assert_ok!(_create_default_comment()); // CommentId 1
-    assert_ok!(_create_default_comment_reaction()); // ReactionId 1
+    assert_ok!(_transfer_default_blog_ownership());
+    assert_ok!(_cancel_blog_ownership_transfer(Some(Origin::signed(ACCOUNT2)), None));
 
-    // Try to catch an error creating reaction by the same account
-    assert_noop!(_create_default_comment_reaction(), Error::<Test>::AccountAlreadyReactedToComment);
+    assert!(Social::pending_blog_owner(1).is_none());
   });
 }
 
 #[test]
-fn create_comment_reaction_should_fail_comment_not_found() {
+fn cancel_blog_ownership_transfer_should_fail_no_pending_transfer() {
   new_test_ext().execute_with(|| {
-    // Try to catch an error creating reaction by the same account
-    assert_noop!(_create_default_comment_reaction(), Error::<Test>::CommentNotFound);
+    assert_ok!(_create_default_blog()); // BlogId 1
+
+    assert_noop!(_cancel_blog_ownership_transfer(None, None), Error::<Test>::NoPendingTransferOnBlog);
   });
 }
 
-// Rating system tests
-
+// Post tests
 #[test]
-fn score_diff_by_weights_check_result() {
+fn create_post_should_work() {
   new_test_ext().execute_with(|| {
-    assert_eq!(Social::get_score_diff(1, self::scoring_action_upvote_post()), DEFAULT_UPVOTE_POST_ACTION_WEIGHT as i16);
-    assert_eq!(Social::get_score_diff(1, self::scoring_action_downvote_post()), DEFAULT_DOWNVOTE_POST_ACTION_WEIGHT as i16);
-    assert_eq!(Social::get_score_diff(1, self::scoring_action_share_post()), DEFAULT_SHARE_POST_ACTION_WEIGHT as i16);
-    assert_eq!(Social::get_score_diff(1, self::scoring_action_create_comment()), DEFAULT_CREATE_COMMENT_ACTION_WEIGHT as i16);
-    assert_eq!(Social::get_score_diff(1, self::scoring_action_upvote_comment()), DEFAULT_UPVOTE_COMMENT_ACTION_WEIGHT as i16);
-    assert_eq!(Social::get_score_diff(1, self::scoring_action_downvote_comment()), DEFAULT_DOWNVOTE_COMMENT_ACTION_WEIGHT as i16);
-    assert_eq!(Social::get_score_diff(1, self::scoring_action_share_comment()), DEFAULT_SHARE_COMMENT_ACTION_WEIGHT as i16);
-    assert_eq!(Social::get_score_diff(1, self::scoring_action_follow_blog()), DEFAULT_FOLLOW_BLOG_ACTION_WEIGHT as i16);
-    assert_eq!(Social::get_score_diff(1, self::scoring_action_follow_account()), DEFAULT_FOLLOW_ACCOUNT_ACTION_WEIGHT as i16);
+    assert_ok!(_create_default_blog()); // BlogId 1
+    assert_ok!(_create_default_post()); // PostId 1
+
+    // Check storages
+    assert_eq!(Social::post_ids_by_blog_id(1), vec![1]);
+    assert_eq!(Social::next_post_id(), 2);
+
+    // Check whether data stored correctly
+    let post = Social::post_by_id(1).unwrap();
+
+    assert_eq!(post.blog_id, 1);
+    assert_eq!(post.created.account, ACCOUNT1);
+    assert_eq!(post.ipfs_hash, self::post_ipfs_hash());
+    assert_eq!(post.comments_count, 0);
+    assert_eq!(post.upvotes_count, 0);
+    assert_eq!(post.downvotes_count, 0);
+    assert_eq!(post.shares_count, 0);
+    assert_eq!(post.extension, self::extension_regular_post());
+    assert!(Social::post_edit_history(1, 0).is_empty());
   });
 }
 
 #[test]
-fn random_score_diff_check_result() {
+fn create_post_should_fail_blog_not_found() {
   new_test_ext().execute_with(|| {
-    assert_eq!(Social::get_score_diff(32768, self::scoring_action_upvote_post()), 80); // 2^15
-    assert_eq!(Social::get_score_diff(32769, self::scoring_action_upvote_post()), 80); // 2^15 + 1
-    assert_eq!(Social::get_score_diff(65535, self::scoring_action_upvote_post()), 80); // 2^16 - 1
-    assert_eq!(Social::get_score_diff(65536, self::scoring_action_upvote_post()), 85); // 2^16
+    assert_noop!(_create_default_post(), Error::<Test>::BlogNotFound);
   });
 }
 
-//--------------------------------------------------------------------------------------------------
-
 #[test]
-fn change_blog_score_should_work_follow_blog() {
+fn create_post_should_work_by_writer() {
   new_test_ext().execute_with(|| {
     assert_ok!(_create_default_blog()); // BlogId 1
+    assert_ok!(_update_blog(None, None, Some(self::blog_update(Some(vec![ACCOUNT2]), None, None))));
 
-    assert_ok!(Social::follow_blog(Origin::signed(ACCOUNT2), 1));
+    assert_ok!(_create_post(Some(Origin::signed(ACCOUNT2)), None, None, None)); // PostId 1 by ACCOUNT2
 
-    assert_eq!(Social::blog_by_id(1).unwrap().score, DEFAULT_FOLLOW_BLOG_ACTION_WEIGHT as i32);
-    assert_eq!(Social::social_account_by_id(ACCOUNT1).unwrap().reputation, 1 + DEFAULT_FOLLOW_BLOG_ACTION_WEIGHT as u32);
-    assert_eq!(Social::social_account_by_id(ACCOUNT2).unwrap().reputation, 1);
+    assert_eq!(Social::post_ids_by_blog_id(1), vec![1]);
+    assert_eq!(Social::post_by_id(1).unwrap().created.account, ACCOUNT2);
   });
 }
 
 #[test]
-fn change_blog_score_should_work_revert_follow_blog() {
+fn create_post_should_fail_not_a_writer() {
   new_test_ext().execute_with(|| {
     assert_ok!(_create_default_blog()); // BlogId 1
 
-    assert_ok!(Social::follow_blog(Origin::signed(ACCOUNT2), 1));
-    assert_ok!(Social::unfollow_blog(Origin::signed(ACCOUNT2), 1));
-
-    assert_eq!(Social::blog_by_id(1).unwrap().score, 0);
-    assert_eq!(Social::social_account_by_id(ACCOUNT1).unwrap().reputation, 1);
-    assert_eq!(Social::social_account_by_id(ACCOUNT2).unwrap().reputation, 1);
+    // Try to catch an error creating a post in a blog of another account
+    assert_noop!(_create_post(Some(Origin::signed(ACCOUNT2)), None, None, None), Error::<Test>::NotABlogWriter);
   });
 }
 
 #[test]
-fn change_blog_score_should_work_upvote_post() {
+fn create_post_should_fail_invalid_ipfs_hash() {
+  let ipfs_hash : Vec<u8> = b"QmV9tSDx9UiPeWExXEeH6aoDvmihvx6j".to_vec();
+
   new_test_ext().execute_with(|| {
     assert_ok!(_create_default_blog()); // BlogId 1
-    assert_ok!(_create_default_post());
-    assert_ok!(_create_post_reaction(Some(Origin::signed(ACCOUNT2)), None, None)); // ReactionId 1
 
-    assert_eq!(Social::blog_by_id(1).unwrap().score, DEFAULT_UPVOTE_POST_ACTION_WEIGHT as i32);
-    assert_eq!(Social::social_account_by_id(ACCOUNT1).unwrap().reputation, 1 + DEFAULT_UPVOTE_POST_ACTION_WEIGHT as u32);
+    // Try to catch an error creating a regular post with invalid ipfs_hash
+    assert_noop!(_create_post(None, None, Some(ipfs_hash), None), Error::<Test>::IpfsIsIncorrect);
   });
 }
 
 #[test]
-fn change_blog_score_should_work_downvote_post() {
+fn update_post_should_work() {
+  let ipfs_hash : Vec<u8> = b"QmRAQB6YaCyidP37UdDnjFY5vQuiBrcqdyoW1CuDgwxkD4".to_vec();
+
   new_test_ext().execute_with(|| {
     assert_ok!(_create_default_blog()); // BlogId 1
-    assert_ok!(_create_default_post());
-    assert_ok!(_create_post_reaction(Some(Origin::signed(ACCOUNT2)), None, Some(self::reaction_downvote()))); // ReactionId 1
+    assert_ok!(_create_default_post()); // PostId 1
 
-    assert_eq!(Social::blog_by_id(1).unwrap().score, DEFAULT_DOWNVOTE_POST_ACTION_WEIGHT as i32);
-    assert_eq!(Social::social_account_by_id(ACCOUNT1).unwrap().reputation, 1);
+    // Post update with ID 1 should be fine
+    assert_ok!(_update_post(None, None,
+      Some(
+        self::post_update(
+          None,
+          Some(ipfs_hash.clone())
+        )
+      )
+    ));
+
+
+    // Check whether post updates correctly
+    let post = Social::post_by_id(1).unwrap();
+    assert_eq!(post.blog_id, 1);
+    assert_eq!(post.ipfs_hash, ipfs_hash);
+
+    // Check whether history recorded correctly
+    assert_eq!(Social::post_edit_history(1, 0)[0].old_data.blog_id, None);
+    assert_eq!(Social::post_edit_history(1, 0)[0].old_data.ipfs_hash, Some(self::post_ipfs_hash()));
   });
 }
 
-//--------------------------------------------------------------------------------------------------
-
 #[test]
-fn change_post_score_should_work_create_comment() {
+fn update_post_should_work_by_writer() {
   new_test_ext().execute_with(|| {
     assert_ok!(_create_default_blog()); // BlogId 1
     assert_ok!(_create_default_post()); // PostId 1
-    assert_ok!(_create_comment(Some(Origin::signed(ACCOUNT2)), None, None, None)); // CommentId 1
+    assert_ok!(_update_blog(None, None, Some(self::blog_update(Some(vec![ACCOUNT2]), None, None))));
 
-    assert_eq!(Social::post_by_id(1).unwrap().score, DEFAULT_CREATE_COMMENT_ACTION_WEIGHT as i32);
-    assert_eq!(Social::blog_by_id(1).unwrap().score, DEFAULT_CREATE_COMMENT_ACTION_WEIGHT as i32);
-    assert_eq!(Social::social_account_by_id(ACCOUNT1).unwrap().reputation, 1 + DEFAULT_CREATE_COMMENT_ACTION_WEIGHT as u32);
-    assert_eq!(Social::post_score_by_account((ACCOUNT2, 1, self::scoring_action_create_comment())), Some(DEFAULT_CREATE_COMMENT_ACTION_WEIGHT));
+    // Blog writer should be able to edit a post of the blog owner
+    assert_ok!(_update_post(Some(Origin::signed(ACCOUNT2)), None,
+      Some(
+        self::post_update(
+          None,
+          Some(self::subcomment_ipfs_hash())
+        )
+      )
+    ));
+
+    let post = Social::post_by_id(1).unwrap();
+    assert_eq!(post.ipfs_hash, self::subcomment_ipfs_hash());
+    assert_eq!(post.updated.unwrap().account, ACCOUNT2);
   });
 }
 
 #[test]
-fn change_post_score_should_work_upvote() {
+fn update_post_should_work_move_to_another_blog() {
   new_test_ext().execute_with(|| {
-    assert_ok!(_create_default_blog());
+    assert_ok!(_create_default_blog()); // BlogId 1
+    assert_ok!(_create_blog(None, Some(b"blog2_slug".to_vec()), None)); // BlogId 2
     assert_ok!(_create_default_post()); // PostId 1
+    assert_ok!(_create_post_reaction(Some(Origin::signed(ACCOUNT2)), None, None)); // ReactionId 1 by ACCOUNT2
 
-    assert_ok!(_create_post_reaction(Some(Origin::signed(ACCOUNT2)), None, None));
+    let post_score = Social::post_by_id(1).unwrap().score;
+    assert_ne!(post_score, 0);
+    assert_eq!(Social::blog_by_id(1).unwrap().score, post_score);
 
-    assert_eq!(Social::post_by_id(1).unwrap().score, DEFAULT_UPVOTE_POST_ACTION_WEIGHT as i32);
-    assert_eq!(Social::social_account_by_id(ACCOUNT1).unwrap().reputation, 1 + DEFAULT_UPVOTE_POST_ACTION_WEIGHT as u32);
-    assert_eq!(Social::post_score_by_account((ACCOUNT2, 1, self::scoring_action_upvote_post())), Some(DEFAULT_UPVOTE_POST_ACTION_WEIGHT));
+    assert_ok!(_update_post(None, None, Some(self::post_update(Some(2), None))));
+
+    let post = Social::post_by_id(1).unwrap();
+    assert_eq!(post.blog_id, 2);
+    assert_eq!(Social::post_edit_history(1, 0)[0].old_data.blog_id, Some(1));
+
+    assert!(Social::post_ids_by_blog_id(1).is_empty());
+    assert_eq!(Social::post_ids_by_blog_id(2), vec![1]);
+
+    // Check whether posts count and score moved along with the post
+    let old_blog = Social::blog_by_id(1).unwrap();
+    assert_eq!(old_blog.posts_count, 0);
+    assert_eq!(old_blog.score, 0);
+
+    let new_blog = Social::blog_by_id(2).unwrap();
+    assert_eq!(new_blog.posts_count, 1);
+    assert_eq!(new_blog.score, post_score);
   });
 }
 
 #[test]
-fn change_post_score_should_work_downvote() {
+fn update_post_should_fail_move_to_blog_without_rights() {
   new_test_ext().execute_with(|| {
-    assert_ok!(_create_default_blog());
+    assert_ok!(_create_default_blog()); // BlogId 1
+    assert_ok!(_create_blog(Some(Origin::signed(ACCOUNT2)), Some(b"blog2_slug".to_vec()), None)); // BlogId 2 by ACCOUNT2
     assert_ok!(_create_default_post()); // PostId 1
 
-    assert_ok!(_create_post_reaction(Some(Origin::signed(ACCOUNT2)), None, Some(self::reaction_downvote())));
-
-    assert_eq!(Social::post_by_id(1).unwrap().score, DEFAULT_DOWNVOTE_POST_ACTION_WEIGHT as i32);
-    assert_eq!(Social::social_account_by_id(ACCOUNT1).unwrap().reputation, 1);
-    assert_eq!(Social::post_score_by_account((ACCOUNT2, 1, self::scoring_action_downvote_post())), Some(DEFAULT_DOWNVOTE_POST_ACTION_WEIGHT));
+    assert_noop!(_update_post(None, None, Some(self::post_update(Some(2), None))), Error::<Test>::NotABlogWriter);
   });
 }
 
 #[test]
-fn change_post_score_should_revert_upvote() {
+fn update_post_should_fail_move_to_nonexistent_blog() {
   new_test_ext().execute_with(|| {
-    assert_ok!(_create_default_blog());
+    assert_ok!(_create_default_blog()); // BlogId 1
     assert_ok!(_create_default_post()); // PostId 1
 
-    assert_ok!(_create_post_reaction(Some(Origin::signed(ACCOUNT2)), None, None)); // ReactionId 1
-    assert_ok!(_delete_post_reaction(Some(Origin::signed(ACCOUNT2)), None, 1));
-
-    assert_eq!(Social::post_by_id(1).unwrap().score, 0);
-    assert_eq!(Social::social_account_by_id(ACCOUNT1).unwrap().reputation, 1);
-    assert_eq!(Social::post_score_by_account((ACCOUNT2, 1, self::scoring_action_upvote_post())), None);
+    assert_noop!(_update_post(None, None, Some(self::post_update(Some(2), None))), Error::<Test>::BlogNotFound);
   });
 }
 
 #[test]
-fn change_post_score_should_revert_downvote() {
+fn update_post_should_fail_nothing_to_update() {
   new_test_ext().execute_with(|| {
-    assert_ok!(_create_default_blog());
+    assert_ok!(_create_default_blog()); // BlogId 1
     assert_ok!(_create_default_post()); // PostId 1
 
-    assert_ok!(_create_post_reaction(Some(Origin::signed(ACCOUNT2)), None, Some(self::reaction_downvote()))); // ReactionId 1
-    assert_ok!(_delete_post_reaction(Some(Origin::signed(ACCOUNT2)), None, 1));
+    // Try to catch an error updating a post with no changes
+    assert_noop!(_update_post(None, None, None), Error::<Test>::NoUpdatesInPost);
+  });
+}
 
-    assert_eq!(Social::post_by_id(1).unwrap().score, 0);
-    assert_eq!(Social::social_account_by_id(ACCOUNT1).unwrap().reputation, 1);
-    assert_eq!(Social::post_score_by_account((ACCOUNT2, 1, self::scoring_action_downvote_post())), None);
+#[test]
+fn update_post_should_fail_post_not_found() {
+  new_test_ext().execute_with(|| {
+    assert_ok!(_create_default_blog()); // BlogId 1
+    assert_ok!(_create_blog(None, Some(b"blog2_slug".to_vec()), None)); // BlogId 2
+    assert_ok!(_create_default_post()); // PostId 1
+
+    // Try to catch an error updating a post with wrong post ID
+    assert_noop!(_update_post(None, Some(2),
+      Some(
+        self::post_update(
+          Some(2),
+          None
+        )
+      )
+    ), Error::<Test>::PostNotFound);
   });
 }
 
 #[test]
-fn change_post_score_cancel_upvote_with_downvote() {
+fn update_post_should_fail_not_an_owner() {
   new_test_ext().execute_with(|| {
-    assert_ok!(_create_default_blog());
+    assert_ok!(_create_default_blog()); // BlogId 1
+    assert_ok!(_create_blog(None, Some(b"blog2_slug".to_vec()), None)); // BlogId 2
     assert_ok!(_create_default_post()); // PostId 1
 
-    assert_ok!(_create_post_reaction(Some(Origin::signed(ACCOUNT2)), None, None)); // ReactionId 1
-    assert_ok!(_update_post_reaction(Some(Origin::signed(ACCOUNT2)), None, 1, Some(self::reaction_downvote())));
+    // Try to catch an error updating a post with different account
+    assert_noop!(_update_post(Some(Origin::signed(ACCOUNT2)), None,
+      Some(
+        self::post_update(
+          Some(2),
+          None
+        )
+      )
+    ), Error::<Test>::NotAPostAuthor);
+  });
+}
 
-    assert_eq!(Social::post_by_id(1).unwrap().score, DEFAULT_DOWNVOTE_POST_ACTION_WEIGHT as i32);
+#[test]
+fn update_post_should_fail_invalid_ipfs_hash() {
+  let ipfs_hash : Vec<u8> = b"QmV9tSDx9UiPeWExXEeH6aoDvmihvx6j".to_vec();
+
+  new_test_ext().execute_with(|| {
+    assert_ok!(_create_default_blog()); // BlogId 1
+    assert_ok!(_create_default_post()); // PostId 1
+
+    // Try to catch an error updating a post with invalid ipfs_hash
+    assert_noop!(_update_post(None, None,
+      Some(
+        self::post_update(
+          None,
+          Some(ipfs_hash)
+        )
+      )
+    ), Error::<Test>::IpfsIsIncorrect);
+  });
+}
+
+#[test]
+fn delete_post_should_work() {
+  new_test_ext().execute_with(|| {
+    assert_ok!(_create_default_blog()); // BlogId 1
+    assert_ok!(_create_default_post()); // PostId 1
+    assert_ok!(_create_comment(Some(Origin::signed(ACCOUNT2)), None, None, None)); // CommentId 1 by ACCOUNT2
+    assert_ok!(_create_comment_reaction(None, None, None)); // ReactionId 1 on CommentId 1
+    assert_ok!(_create_post_reaction(Some(Origin::signed(ACCOUNT2)), None, None)); // ReactionId 2 on PostId 1
+
+    assert_ok!(_delete_post(None, None));
+
+    // Check storages
+    assert!(Social::post_by_id(1).is_none());
+    assert!(Social::post_ids_by_blog_id(1).is_empty());
+    assert!(Social::comment_by_id(1).is_none());
+    assert!(Social::comment_ids_by_post_id(1).is_empty());
+    assert!(Social::reaction_by_id(1).is_none());
+    assert!(Social::reaction_by_id(2).is_none());
+    assert!(Social::reaction_ids_by_post_id(1).is_empty());
+    assert!(Social::reaction_ids_by_comment_id(1).is_empty());
+    assert!(Social::reaction_id_by_account((ACCOUNT2, ReactionTarget::Post(1))).is_none());
+    assert!(Social::reaction_id_by_account((ACCOUNT1, ReactionTarget::Comment(1))).is_none());
+
+    // Check whether counters, scores and reputation reverted
+    let blog = Social::blog_by_id(1).unwrap();
+    assert_eq!(blog.posts_count, 0);
+    assert_eq!(blog.score, 0);
     assert_eq!(Social::social_account_by_id(ACCOUNT1).unwrap().reputation, 1);
+    assert_eq!(Social::social_account_by_id(ACCOUNT2).unwrap().reputation, 1);
+    assert_eq!(Social::post_score_by_account((ACCOUNT2, 1, self::scoring_action_create_comment())), None);
     assert_eq!(Social::post_score_by_account((ACCOUNT2, 1, self::scoring_action_upvote_post())), None);
-    assert_eq!(Social::post_score_by_account((ACCOUNT2, 1, self::scoring_action_downvote_post())), Some(DEFAULT_DOWNVOTE_POST_ACTION_WEIGHT));
+    assert_eq!(Social::comment_score_by_account((ACCOUNT1, 1, self::scoring_action_upvote_comment())), None);
   });
 }
 
 #[test]
-fn change_post_score_cancel_downvote_with_upvote() {
+fn delete_post_should_work_shared_post() {
   new_test_ext().execute_with(|| {
-    assert_ok!(_create_default_blog());
+    assert_ok!(_create_default_blog()); // BlogId 1
+    assert_ok!(_create_blog(Some(Origin::signed(ACCOUNT2)), Some(b"blog2_slug".to_vec()), None)); // BlogId 2 by ACCOUNT2
     assert_ok!(_create_default_post()); // PostId 1
+    assert_ok!(_create_post(
+      Some(Origin::signed(ACCOUNT2)),
+      Some(2),
+      Some(vec![]),
+      Some(self::extension_shared_post(1))
+    )); // Share PostId 1 on BlogId 2 by ACCOUNT2
 
-    assert_ok!(_create_post_reaction(Some(Origin::signed(ACCOUNT2)), None, Some(self::reaction_downvote()))); // ReactionId 1
-    assert_ok!(_update_post_reaction(Some(Origin::signed(ACCOUNT2)), None, 1, None));
+    assert_ok!(_delete_post(Some(Origin::signed(ACCOUNT2)), Some(2)));
 
-    assert_eq!(Social::post_by_id(1).unwrap().score, DEFAULT_UPVOTE_POST_ACTION_WEIGHT as i32);
-    assert_eq!(Social::social_account_by_id(ACCOUNT1).unwrap().reputation, 1 + DEFAULT_UPVOTE_POST_ACTION_WEIGHT as u32);
-    assert_eq!(Social::post_score_by_account((ACCOUNT2, 1, self::scoring_action_downvote_post())), None);
-    assert_eq!(Social::post_score_by_account((ACCOUNT2, 1, self::scoring_action_upvote_post())), Some(DEFAULT_UPVOTE_POST_ACTION_WEIGHT));
+    // Check whether the original post is unshared
+    let original_post = Social::post_by_id(1).unwrap();
+    assert_eq!(original_post.shares_count, 0);
+    assert_eq!(original_post.score, 0);
+    assert!(Social::shared_post_ids_by_original_post_id(1).is_empty());
+    assert_eq!(Social::post_shares_by_account((ACCOUNT2, 1)), 0);
+    assert_eq!(Social::post_score_by_account((ACCOUNT2, 1, self::scoring_action_share_post())), None);
+    assert_eq!(Social::social_account_by_id(ACCOUNT1).unwrap().reputation, 1);
+
+    assert!(Social::post_ids_by_blog_id(2).is_empty());
+    assert_eq!(Social::blog_by_id(2).unwrap().posts_count, 0);
   });
 }
 
 #[test]
-fn change_post_score_should_fail_post_not_found() {
+fn delete_post_should_work_shared_comment() {
   new_test_ext().execute_with(|| {
-    assert_ok!(_create_default_blog());
-    assert_noop!(_change_post_score_by_id(ACCOUNT1, 1, self::scoring_action_upvote_post()), Error::<Test>::PostNotFound);
+    assert_ok!(_create_default_blog()); // BlogId 1
+    assert_ok!(_create_blog(Some(Origin::signed(ACCOUNT2)), Some(b"blog2_slug".to_vec()), None)); // BlogId 2 by ACCOUNT2
+    assert_ok!(_create_default_post()); // PostId 1
+    assert_ok!(_create_default_comment()); // CommentId 1
+    assert_ok!(_create_post(
+      Some(Origin::signed(ACCOUNT2)),
+      Some(2),
+      Some(vec![]),
+      Some(self::extension_shared_comment(1))
+    )); // Share CommentId 1 on BlogId 2 by ACCOUNT2
+
+    assert_ok!(_delete_post(Some(Origin::signed(ACCOUNT2)), Some(2)));
+
+    // Check whether the original comment is unshared
+    let original_comment = Social::comment_by_id(1).unwrap();
+    assert_eq!(original_comment.shares_count, 0);
+    assert_eq!(original_comment.score, 0);
+    assert!(Social::shared_post_ids_by_original_comment_id(1).is_empty());
+    assert_eq!(Social::comment_shares_by_account((ACCOUNT2, 1)), 0);
+    assert_eq!(Social::social_account_by_id(ACCOUNT1).unwrap().reputation, 1);
   });
 }
 
-//--------------------------------------------------------------------------------------------------
+#[test]
+fn delete_post_should_revert_shares_of_deleted_post() {
+  new_test_ext().execute_with(|| {
+    assert_ok!(_create_default_blog()); // BlogId 1
+    assert_ok!(_create_blog(Some(Origin::signed(ACCOUNT2)), Some(b"blog2_slug".to_vec()), None)); // BlogId 2 by ACCOUNT2
+    assert_ok!(_create_default_post()); // PostId 1
+    assert_ok!(_create_post(
+      Some(Origin::signed(ACCOUNT2)),
+      Some(2),
+      Some(vec![]),
+      Some(self::extension_shared_post(1))
+    )); // Share PostId 1 on BlogId 2 by ACCOUNT2
+
+    assert_ok!(_delete_post(None, None));
+
+    // Shared post is kept, but the share is no longer scored
+    assert!(Social::post_by_id(2).is_some());
+    assert!(Social::shared_post_ids_by_original_post_id(1).is_empty());
+    assert_eq!(Social::post_shares_by_account((ACCOUNT2, 1)), 0);
+    assert_eq!(Social::social_account_by_id(ACCOUNT1).unwrap().reputation, 1);
+  });
+}
 
 #[test]
-fn change_social_account_reputation_should_work_max_score_diff() {
+fn delete_post_should_fail_before_changing_storage() {
   new_test_ext().execute_with(|| {
-    assert_ok!(_create_default_blog());
-    assert_ok!(_create_post(Some(Origin::signed(ACCOUNT2)), None, None, None));
-    assert_ok!(Social::change_social_account_reputation(
-      ACCOUNT2,
-      ACCOUNT1,
-      std::i16::MAX,
-      self::scoring_action_follow_account())
-    );
+    assert_ok!(_create_default_blog()); // BlogId 1
+    assert_ok!(_create_default_post()); // PostId 1
+    assert_ok!(_create_post_reaction(Some(Origin::signed(ACCOUNT2)), None, None)); // ReactionId 1 by ACCOUNT2
+    assert_ok!(_create_comment(Some(Origin::signed(ACCOUNT3)), None, None, None)); // CommentId 1 by ACCOUNT3
+
+    let mut blog = Social::blog_by_id(1).unwrap();
+    blog.posts_count = 0;
+    <BlogById<Test>>::insert(1, blog);
+
+    // Comments and reactions of the post should be kept along with the scores they gave
+    assert_noop!(Social::delete_post(Origin::signed(ACCOUNT1), 1), Error::<Test>::UnderflowRemovingPostFromBlog);
+    assert!(Social::comment_by_id(1).is_some());
+    assert!(Social::reaction_by_id(1).is_some());
+
+    // A reverted score has to be found in the reputation ledger too
+    let mut blog = Social::blog_by_id(1).unwrap();
+    blog.posts_count = 1;
+    <BlogById<Test>>::insert(1, blog);
+    <ReputationDiffByKey<Test>>::remove((ACCOUNT3, self::scoring_action_create_comment(), ScoredEntity::Post(1)));
+
+    assert_noop!(Social::delete_post(Origin::signed(ACCOUNT1), 1), Error::<Test>::ReputationDiffNotFound);
+    assert!(Social::reaction_by_id(1).is_some());
   });
 }
 
 #[test]
-fn change_social_account_reputation_should_work_min_score_diff() {
+fn delete_post_should_fail_post_not_found() {
   new_test_ext().execute_with(|| {
-    assert_ok!(_create_default_blog());
-    assert_ok!(_create_post(Some(Origin::signed(ACCOUNT2)), None, None, None));
-    assert_ok!(Social::change_social_account_reputation(
-      ACCOUNT2,
-      ACCOUNT1,
-      std::i16::MIN,
-      self::scoring_action_follow_account())
-    );
+    assert_ok!(_create_default_blog()); // BlogId 1
+
+    assert_noop!(_delete_post(None, None), Error::<Test>::PostNotFound);
   });
 }
 
 #[test]
-fn change_social_account_reputation_should_work() {
+fn delete_post_should_fail_not_an_author() {
   new_test_ext().execute_with(|| {
-    assert_ok!(_create_default_blog());
-    assert_ok!(_create_post(Some(Origin::signed(ACCOUNT2)), None, None, None));
-    assert_ok!(Social::change_social_account_reputation(
-      ACCOUNT2,
-      ACCOUNT1,
-      DEFAULT_DOWNVOTE_POST_ACTION_WEIGHT,
-      self::scoring_action_downvote_post())
-    );
-    assert_eq!(Social::account_reputation_diff_by_account((ACCOUNT1, ACCOUNT2, self::scoring_action_downvote_post())), Some(0));
-    assert_eq!(Social::social_account_by_id(ACCOUNT2).unwrap().reputation, 1);
+    assert_ok!(_create_default_blog()); // BlogId 1
+    assert_ok!(_create_default_post()); // PostId 1
 
-    assert_ok!(Social::change_social_account_reputation(
-      ACCOUNT2,
-      ACCOUNT1,
-      DEFAULT_UPVOTE_POST_ACTION_WEIGHT * 2,
-      self::scoring_action_upvote_post())
-    );
-    assert_eq!(Social::account_reputation_diff_by_account(
-      (ACCOUNT1, ACCOUNT2, self::scoring_action_upvote_post())),
-               Some(DEFAULT_UPVOTE_POST_ACTION_WEIGHT * 2)
-    );
-    assert_eq!(Social::social_account_by_id(ACCOUNT2).unwrap().reputation, 1 + (DEFAULT_UPVOTE_POST_ACTION_WEIGHT * 2) as u32);
+    // Try to catch an error deleting a post with different account
+    assert_noop!(_delete_post(Some(Origin::signed(ACCOUNT2)), None), Error::<Test>::NotAPostAuthor);
+  });
+}
+
+// Comment tests
+#[test]
+fn create_comment_should_work() {
+  new_test_ext().execute_with(|| {
+    assert_ok!(_create_default_blog()); // BlogId 1
+    assert_ok!(_create_default_post()); // PostId 1
+    assert_ok!(_create_default_comment()); // CommentId 1
+
+    // Check storages
+    assert_eq!(Social::comment_ids_by_post_id(1), vec![1]);
+    assert_eq!(Social::next_comment_id(), 2);
+    assert_eq!(Social::post_by_id(1).unwrap().comments_count, 1);
+
+    // Check whether data stored correctly
+    let comment = Social::comment_by_id(1).unwrap();
+
+    assert_eq!(comment.parent_id, None);
+    assert_eq!(comment.post_id, 1);
+    assert_eq!(comment.created.account, ACCOUNT1);
+    assert_eq!(comment.ipfs_hash, self::comment_ipfs_hash());
+    assert_eq!(comment.upvotes_count, 0);
+    assert_eq!(comment.downvotes_count, 0);
+    assert_eq!(comment.shares_count, 0);
+    assert_eq!(comment.direct_replies_count, 0);
+    assert_eq!(comment.depth, 0);
+    assert!(Social::comment_edit_history(1, 0).is_empty());
+  });
+}
+
+#[test]
+fn create_comment_should_work_with_parent() {
+  new_test_ext().execute_with(|| {
+    assert_ok!(_create_default_blog()); // BlogId 1
+    assert_ok!(_create_default_post()); // PostId 1
+    assert_ok!(_create_default_comment()); // CommentId 1
+    assert_ok!(_create_comment(None, None, Some(1), None)); // CommentId 2 with parent CommentId 1
+
+    // Check storages
+    assert_eq!(Social::comment_ids_by_post_id(1), vec![1, 2]);
+    assert_eq!(Social::next_comment_id(), 3);
+    assert_eq!(Social::post_by_id(1).unwrap().comments_count, 2);
+
+    assert_eq!(Social::comment_ids_by_parent_id(1), vec![2]);
+
+    // Check whether data stored correctly
+    assert_eq!(Social::comment_by_id(2).unwrap().parent_id, Some(1));
+    assert_eq!(Social::comment_by_id(2).unwrap().depth, 1);
+    assert_eq!(Social::comment_by_id(1).unwrap().direct_replies_count, 1);
+  });
+}
+
+#[test]
+fn create_comment_should_fail_post_not_found() {
+  new_test_ext().execute_with(|| {
+    // Try to catch an error creating a comment with wrong post
+    assert_noop!(_create_default_comment(), Error::<Test>::PostNotFound);
+  });
+}
+
+#[test]
+fn create_comment_should_fail_parent_not_found() {
+  new_test_ext().execute_with(|| {
+    assert_ok!(_create_default_blog()); // BlogId 1
+    assert_ok!(_create_default_post()); // PostId 1
+
+    // Try to catch an error creating a comment with wrong parent
+    assert_noop!(_create_comment(None, None, Some(1), None), Error::<Test>::UnknownParentComment);
+  });
+}
+
+#[test]
+fn create_comment_should_fail_parent_on_another_post() {
+  new_test_ext().execute_with(|| {
+    assert_ok!(_create_default_blog()); // BlogId 1
+    assert_ok!(_create_default_post()); // PostId 1
+    assert_ok!(_create_default_post()); // PostId 2
+    assert_ok!(_create_default_comment()); // CommentId 1 on PostId 1
+
+    // Try to catch an error replying on a comment from another post
+    assert_noop!(_create_comment(None, Some(2), Some(1), None), Error::<Test>::ParentCommentOnAnotherPost);
+  });
+}
+
+#[test]
+fn create_comment_should_fail_max_depth_reached() {
+  new_test_ext().execute_with(|| {
+    MaxCommentDepth::put(1);
+
+    assert_ok!(_create_default_blog()); // BlogId 1
+    assert_ok!(_create_default_post()); // PostId 1
+    assert_ok!(_create_default_comment()); // CommentId 1
+    assert_ok!(_create_comment(None, None, Some(1), None)); // CommentId 2 with parent CommentId 1
+
+    assert_noop!(_create_comment(None, None, Some(2), None), Error::<Test>::MaxCommentDepthReached);
+  });
+}
+
+#[test]
+fn create_comment_should_fail_invalid_ipfs_hash() {
+  let ipfs_hash : Vec<u8> = b"QmV9tSDx9UiPeWExXEeH6aoDvmihvx6j".to_vec();
+
+  new_test_ext().execute_with(|| {
+    assert_ok!(_create_default_blog()); // BlogId 1
+    assert_ok!(_create_default_post()); // PostId 1
+
+    // Try to catch an error creating a comment with wrong parent
+    assert_noop!(_create_comment(None, None, None, Some(ipfs_hash)), Error::<Test>::IpfsIsIncorrect);
+  });
+}
+
+#[test]
+fn update_comment_should_work() {
+  new_test_ext().execute_with(|| {
+    assert_ok!(_create_default_blog()); // BlogId 1
+    assert_ok!(_create_default_post()); // PostId 1
+    assert_ok!(_create_default_comment()); // CommentId 1
+
+    // Post update with ID 1 should be fine
+    assert_ok!(_update_comment(
+      None,
+      None,
+      Some(self::comment_update(self::subcomment_ipfs_hash()))
+    ));
+
+    // Check whether post updates correctly
+    let comment = Social::comment_by_id(1).unwrap();
+    assert_eq!(comment.ipfs_hash, self::subcomment_ipfs_hash());
+
+    // Check whether history recorded correctly
+    assert_eq!(Social::comment_edit_history(1, 0)[0].old_data.ipfs_hash, self::comment_ipfs_hash());
+  });
+}
+
+#[test]
+fn update_comment_should_fail_comment_not_found() {
+  new_test_ext().execute_with(|| {
+    // Try to catch an error updating a comment with wrong CommentId
+    assert_noop!(_update_comment(
+      None,
+      None,
+      Some(self::comment_update(self::subcomment_ipfs_hash()))
+    ),
+    Error::<Test>::CommentNotFound);
+  });
+}
+
+#[test]
+fn update_comment_should_fail_not_an_owner() {
+  new_test_ext().execute_with(|| {
+    assert_ok!(_create_default_blog()); // BlogId 1
+    assert_ok!(_create_default_post()); // PostId 1
+    assert_ok!(_create_default_comment()); // CommentId 1
+
+    // Try to catch an error updating a comment with wrong Account
+    assert_noop!(_update_comment(
+      Some(Origin::signed(2)),
+      None,
+      Some(self::comment_update(self::subcomment_ipfs_hash()))
+    ),
+    Error::<Test>::NotACommentAuthor);
+  });
+}
+
+#[test]
+fn update_comment_should_fail_invalid_ipfs_hash() {
+  let ipfs_hash : Vec<u8> = b"QmV9tSDx9UiPeWExXEeH6aoDvmihvx6j".to_vec();
+
+  new_test_ext().execute_with(|| {
+    assert_ok!(_create_default_blog()); // BlogId 1
+    assert_ok!(_create_default_post()); // PostId 1
+    assert_ok!(_create_default_comment()); // CommentId 1
+
+    // Try to catch an error updating a comment with invalid ipfs_hash
+    assert_noop!(_update_comment(
+      None,
+      None,
+      Some(self::comment_update(ipfs_hash))
+    ),
+    Error::<Test>::IpfsIsIncorrect);
+  });
+}
+
+#[test]
+fn update_comment_should_fail_ipfs_hash_dont_differ() {
+  new_test_ext().execute_with(|| {
+    assert_ok!(_create_default_blog()); // BlogId 1
+    assert_ok!(_create_default_post()); // PostId 1
+    assert_ok!(_create_default_comment()); // CommentId 1
+
+    // Try to catch an error updating a comment with the same ipfs_hash
+    assert_noop!(_update_comment(
+      None,
+      None,
+      Some(self::comment_update(self::comment_ipfs_hash()))
+    ),
+    Error::<Test>::CommentIPFSHashNotDiffer);
+  });
+}
+
+#[test]
+fn delete_comment_should_work() {
+  new_test_ext().execute_with(|| {
+    assert_ok!(_create_default_blog()); // BlogId 1
+    assert_ok!(_create_default_post()); // PostId 1
+    assert_ok!(_create_comment(Some(Origin::signed(ACCOUNT2)), None, None, None)); // CommentId 1 by ACCOUNT2
+    assert_ok!(_create_comment_reaction(None, None, None)); // ReactionId 1 on CommentId 1
+
+    assert_ok!(_delete_comment(Some(Origin::signed(ACCOUNT2)), None));
+
+    // Check storages
+    assert!(Social::comment_by_id(1).is_none());
+    assert!(Social::comment_ids_by_post_id(1).is_empty());
+    assert!(Social::reaction_by_id(1).is_none());
+    assert!(Social::reaction_ids_by_comment_id(1).is_empty());
+    assert!(Social::reaction_id_by_account((ACCOUNT1, ReactionTarget::Comment(1))).is_none());
+
+    // Check whether counters, scores and reputation reverted
+    let post = Social::post_by_id(1).unwrap();
+    assert_eq!(post.comments_count, 0);
+    assert_eq!(post.score, 0);
+    assert_eq!(Social::blog_by_id(1).unwrap().score, 0);
+    assert_eq!(Social::social_account_by_id(ACCOUNT1).unwrap().reputation, 1);
+    assert_eq!(Social::social_account_by_id(ACCOUNT2).unwrap().reputation, 1);
+    assert_eq!(Social::post_score_by_account((ACCOUNT2, 1, self::scoring_action_create_comment())), None);
+  });
+}
+
+#[test]
+fn delete_comment_should_work_reply() {
+  new_test_ext().execute_with(|| {
+    assert_ok!(_create_default_blog()); // BlogId 1
+    assert_ok!(_create_default_post()); // PostId 1
+    assert_ok!(_create_default_comment()); // CommentId 1
+    assert_ok!(_create_comment(None, None, Some(1), None)); // CommentId 2 with parent CommentId 1
+
+    assert_ok!(_delete_comment(None, Some(2)));
+
+    assert!(Social::comment_by_id(2).is_none());
+    assert_eq!(Social::comment_ids_by_post_id(1), vec![1]);
+    assert!(Social::comment_ids_by_parent_id(1).is_empty());
+    assert_eq!(Social::comment_by_id(1).unwrap().direct_replies_count, 0);
+    assert_eq!(Social::post_by_id(1).unwrap().comments_count, 1);
+  });
+}
+
+#[test]
+fn delete_comment_should_leave_tombstone_if_has_replies() {
+  new_test_ext().execute_with(|| {
+    assert_ok!(_create_default_blog()); // BlogId 1
+    assert_ok!(_create_default_post()); // PostId 1
+    assert_ok!(_create_default_comment()); // CommentId 1
+    assert_ok!(_create_comment(Some(Origin::signed(ACCOUNT2)), None, Some(1), None)); // CommentId 2 with parent CommentId 1
+    assert_ok!(_create_comment_reaction(Some(Origin::signed(ACCOUNT2)), None, None)); // ReactionId 1 on CommentId 1
+
+    assert_ok!(_delete_comment(None, None));
+
+    // Check whether the comment is kept in the tree without content
+    let comment = Social::comment_by_id(1).unwrap();
+    assert!(comment.is_deleted());
+    assert!(comment.updated.is_some());
+    assert_eq!(comment.direct_replies_count, 1);
+    assert_eq!(Social::comment_ids_by_post_id(1), vec![1, 2]);
+    assert_eq!(Social::post_by_id(1).unwrap().comments_count, 2);
+
+    // Deleted comment cannot be updated, deleted, reacted to or shared
+    assert_noop!(_update_comment(None, None, None), Error::<Test>::CommentIsDeleted);
+    assert_noop!(_delete_comment(None, None), Error::<Test>::CommentIsDeleted);
+    assert_noop!(_create_comment_reaction(Some(Origin::signed(ACCOUNT3)), None, None), Error::<Test>::CommentIsDeleted);
+    assert_noop!(
+      _update_comment_reaction(Some(Origin::signed(ACCOUNT2)), None, Some(self::reaction_downvote())),
+      Error::<Test>::CommentIsDeleted
+    );
+    assert_noop!(
+      _create_post(None, None, None, Some(self::extension_shared_comment(1))),
+      Error::<Test>::CommentIsDeleted
+    );
+
+    // Existing reactions can still be deleted
+    assert_ok!(_delete_comment_reaction(Some(Origin::signed(ACCOUNT2)), None));
+    assert!(Social::reaction_by_id(1).is_none());
+  });
+}
+
+#[test]
+fn delete_comment_should_remove_tombstones_left_without_replies() {
+  new_test_ext().execute_with(|| {
+    assert_ok!(_create_default_blog()); // BlogId 1
+    assert_ok!(_create_default_post()); // PostId 1
+    assert_ok!(_create_default_comment()); // CommentId 1
+    assert_ok!(_create_comment(Some(Origin::signed(ACCOUNT2)), None, Some(1), None)); // CommentId 2 with parent CommentId 1
+    assert_ok!(_create_comment(Some(Origin::signed(ACCOUNT2)), None, Some(2), None)); // CommentId 3 with parent CommentId 2
+
+    assert_ok!(_delete_comment(None, Some(1)));
+    assert_ok!(_delete_comment(Some(Origin::signed(ACCOUNT2)), Some(2)));
+    assert!(Social::comment_by_id(2).unwrap().is_deleted());
+
+    // Deleting the last reply removes the chain of tombstones above it
+    assert_ok!(_delete_comment(Some(Origin::signed(ACCOUNT2)), Some(3)));
+
+    assert!(Social::comment_by_id(1).is_none());
+    assert!(Social::comment_by_id(2).is_none());
+    assert!(Social::comment_by_id(3).is_none());
+    assert!(Social::comment_ids_by_post_id(1).is_empty());
+    assert!(Social::comment_ids_by_parent_id(1).is_empty());
+    assert!(Social::comment_ids_by_parent_id(2).is_empty());
+
+    let post = Social::post_by_id(1).unwrap();
+    assert_eq!(post.comments_count, 0);
+    assert_eq!(post.score, 0);
+    assert_eq!(Social::post_score_by_account((ACCOUNT2, 1, self::scoring_action_create_comment())), None);
+  });
+}
+
+#[test]
+fn delete_comment_should_keep_tombstone_with_other_replies() {
+  new_test_ext().execute_with(|| {
+    assert_ok!(_create_default_blog()); // BlogId 1
+    assert_ok!(_create_default_post()); // PostId 1
+    assert_ok!(_create_default_comment()); // CommentId 1
+    assert_ok!(_create_comment(None, None, Some(1), None)); // CommentId 2 with parent CommentId 1
+    assert_ok!(_create_comment(None, None, Some(1), None)); // CommentId 3 with parent CommentId 1
+
+    assert_ok!(_delete_comment(None, Some(1)));
+    assert_ok!(_delete_comment(None, Some(2)));
+
+    let comment = Social::comment_by_id(1).unwrap();
+    assert!(comment.is_deleted());
+    assert_eq!(comment.direct_replies_count, 1);
+    assert_eq!(Social::comment_ids_by_post_id(1), vec![1, 3]);
+    assert_eq!(Social::post_by_id(1).unwrap().comments_count, 2);
+  });
+}
+
+#[test]
+fn delete_comment_should_fail_comment_not_found() {
+  new_test_ext().execute_with(|| {
+    assert_noop!(_delete_comment(None, None), Error::<Test>::CommentNotFound);
+  });
+}
+
+#[test]
+fn delete_comment_should_fail_not_an_author() {
+  new_test_ext().execute_with(|| {
+    assert_ok!(_create_default_blog()); // BlogId 1
+    assert_ok!(_create_default_post()); // PostId 1
+    assert_ok!(_create_default_comment()); // CommentId 1
+
+    // Try to catch an error deleting a comment with different account
+    assert_noop!(_delete_comment(Some(Origin::signed(ACCOUNT2)), None), Error::<Test>::NotACommentAuthor);
+  });
+}
+
+// Reaction tests
+#[test]
+fn create_post_reaction_should_work_upvote() {
+  new_test_ext().execute_with(|| {
+    assert_ok!(_create_default_blog()); // BlogId 1
+    assert_ok!(_create_default_post()); // PostId 1
+
+    assert_ok!(_create_post_reaction(Some(Origin::signed(ACCOUNT2)), None, None)); // ReactionId 1 by ACCOUNT2
+
+    // Check storages
+    assert_eq!(Social::reaction_ids_by_post_id(1), vec![1]);
+    assert_eq!(Social::next_reaction_id(), 2);
+
+    // Check post reaction counters
+    let post = Social::post_by_id(1).unwrap();
+    assert_eq!(post.upvotes_count, 1);
+    assert_eq!(post.downvotes_count, 0);
+
+    // Check whether data stored correctly
+    let reaction = Social::reaction_by_id(1).unwrap();
+    assert_eq!(reaction.created.account, ACCOUNT2);
+    assert_eq!(reaction.kind, self::reaction_upvote());
+  });
+}
+
+#[test]
+fn create_post_reaction_should_work_downvote() {
+  new_test_ext().execute_with(|| {
+    assert_ok!(_create_default_blog()); // BlogId 1
+    assert_ok!(_create_default_post()); // PostId 1
+
+    assert_ok!(_create_post_reaction(Some(Origin::signed(ACCOUNT2)), None, Some(self::reaction_downvote()))); // ReactionId 1 by ACCOUNT2
+
+    // Check storages
+    assert_eq!(Social::reaction_ids_by_post_id(1), vec![1]);
+    assert_eq!(Social::next_reaction_id(), 2);
+
+    // Check post reaction counters
+    let post = Social::post_by_id(1).unwrap();
+    assert_eq!(post.upvotes_count, 0);
+    assert_eq!(post.downvotes_count, 1);
+
+    // Check whether data stored correctly
+    let reaction = Social::reaction_by_id(1).unwrap();
+    assert_eq!(reaction.created.account, ACCOUNT2);
+    assert_eq!(reaction.kind, self::reaction_downvote());
+  });
+}
+
+#[test]
+fn create_post_reaction_should_fail_already_reacted() {
+  new_test_ext().execute_with(|| {
+    assert_ok!(_create_default_blog()); // BlogId 1
+    assert_ok!(_create_default_post()); // PostId 1
+    assert_ok!(_create_default_post_reaction()); // ReactionId1
+
+    // Try to catch an error creating reaction by the same account
+    assert_noop!(_create_default_post_reaction(), Error::<Test>::AccountAlreadyReactedToPost);
+  });
+}
+
+#[test]
+fn create_post_reaction_should_fail_post_not_found() {
+  new_test_ext().execute_with(|| {
+    // Try to catch an error creating reaction by the same account
+    assert_noop!(_create_default_post_reaction(), Error::<Test>::PostNotFound);
+  });
+}
+
+#[test]
+fn create_comment_reaction_should_work_upvote() {
+  new_test_ext().execute_with(|| {
+    assert_ok!(_create_default_blog()); // BlogId 1
+    assert_ok!(_create_default_post()); // PostId 1
+    assert_ok!(_create_default_comment()); // CommentId 1
+    assert_ok!(_create_comment_reaction(Some(Origin::signed(ACCOUNT2)), None, None)); // ReactionId 1 by ACCOUNT2
+
+    // Check storages
+    assert_eq!(Social::reaction_ids_by_comment_id(1), vec![1]);
+    assert_eq!(Social::next_reaction_id(), 2);
+
+    // Check comment reaction counters
+    let comment = Social::comment_by_id(1).unwrap();
+    assert_eq!(comment.upvotes_count, 1);
+    assert_eq!(comment.downvotes_count, 0);
+
+    // Check whether data stored correctly
+    let reaction = Social::reaction_by_id(1).unwrap();
+    assert_eq!(reaction.created.account, ACCOUNT2);
+    assert_eq!(reaction.kind, self::reaction_upvote());
+  });
+}
+
+#[test]
+fn create_comment_reaction_should_work_downvote() {
+  new_test_ext().execute_with(|| {
+    assert_ok!(_create_default_blog()); // BlogId 1
+    assert_ok!(_create_default_post()); // PostId 1
+    assert_ok!(_create_default_comment()); // CommentId 1
+    assert_ok!(_create_comment_reaction(Some(Origin::signed(ACCOUNT2)), None, Some(self::reaction_downvote()))); // ReactionId 1 by ACCOUNT2
+
+    // Check storages
+    assert_eq!(Social::reaction_ids_by_comment_id(1), vec![1]);
+    assert_eq!(Social::next_reaction_id(), 2);
+
+    // Check comment reaction counters
+    let comment = Social::comment_by_id(1).unwrap();
+    assert_eq!(comment.upvotes_count, 0);
+    assert_eq!(comment.downvotes_count, 1);
+
+    // Check whether data stored correctly
+    let reaction = Social::reaction_by_id(1).unwrap();
+    assert_eq!(reaction.created.account, ACCOUNT2);
+    assert_eq!(reaction.kind, self::reaction_downvote());
+  });
+}
+
+#[test]
+fn create_comment_reaction_should_fail_already_reacted() {
+  new_test_ext().execute_with(|| {
+    assert_ok!(_create_default_blog()); // BlogId 1
+    assert_ok!(_create_default_post()); // PostId 1
+    assert_ok!(_create_default_comment()); // CommentId 1
+    assert_ok!(_create_default_comment_reaction()); // ReactionId 1
+
+    // Try to catch an error creating reaction by the same account
+    assert_noop!(_create_default_comment_reaction(), Error::<Test>::AccountAlreadyReactedToComment);
+  });
+}
+
+#[test]
+fn create_comment_reaction_should_fail_comment_not_found() {
+  new_test_ext().execute_with(|| {
+    // Try to catch an error creating reaction by the same account
+    assert_noop!(_create_default_comment_reaction(), Error::<Test>::CommentNotFound);
+  });
+}
+
+#[test]
+fn react_should_work_with_blog_target() {
+  new_test_ext().execute_with(|| {
+    assert_ok!(_create_default_blog()); // BlogId 1
+    assert_ok!(_react(Some(Origin::signed(ACCOUNT2)), Some(ReactionTarget::Blog(1)), None)); // ReactionId 1
+
+    assert_eq!(Social::reaction_ids_by_target(ReactionTarget::Blog(1)), vec![1]);
+    assert_eq!(Social::reaction_id_by_account((ACCOUNT2, ReactionTarget::Blog(1))), Some(1));
+
+    // Blogs have no reaction counters and score
+    assert_eq!(Social::blog_by_id(1).unwrap().score, 0);
+
+    assert_noop!(
+      _react(Some(Origin::signed(ACCOUNT2)), Some(ReactionTarget::Blog(1)), None),
+      Error::<Test>::AccountAlreadyReacted
+    );
+  });
+}
+
+#[test]
+fn react_should_work_with_profile_target() {
+  new_test_ext().execute_with(|| {
+    assert_ok!(_create_default_profile()); // Profile of ACCOUNT1
+    let target = ReactionTarget::Profile(ACCOUNT1);
+
+    assert_ok!(_react(Some(Origin::signed(ACCOUNT2)), Some(target.clone()), None)); // ReactionId 1
+    assert_ok!(_update_reaction(Some(Origin::signed(ACCOUNT2)), Some(target.clone()), Some(self::reaction_downvote())));
+    assert_eq!(Social::reaction_by_id(1).unwrap().kind, self::reaction_downvote());
+
+    assert_ok!(_delete_reaction(Some(Origin::signed(ACCOUNT2)), Some(target.clone())));
+    assert!(Social::reaction_by_id(1).is_none());
+    assert!(Social::reaction_ids_by_target(target.clone()).is_empty());
+    assert!(Social::reaction_id_by_account((ACCOUNT2, target)).is_none());
+  });
+}
+
+#[test]
+fn react_should_fail_target_not_found() {
+  new_test_ext().execute_with(|| {
+    assert_noop!(_react(None, Some(ReactionTarget::Blog(1)), None), Error::<Test>::BlogNotFound);
+    assert_noop!(_react(None, Some(ReactionTarget::Post(1)), None), Error::<Test>::PostNotFound);
+    assert_noop!(_react(None, Some(ReactionTarget::Comment(1)), None), Error::<Test>::CommentNotFound);
+    assert_noop!(_react(None, Some(ReactionTarget::Profile(ACCOUNT2)), None), Error::<Test>::ProfileDoesNotExist);
+  });
+}
+
+#[test]
+fn update_and_delete_reaction_should_work_with_post_target() {
+  new_test_ext().execute_with(|| {
+    assert_ok!(_create_default_blog()); // BlogId 1
+    assert_ok!(_create_default_post()); // PostId 1
+    assert_ok!(_react(Some(Origin::signed(ACCOUNT2)), None, None)); // ReactionId 1
+
+    assert_ok!(_update_reaction(Some(Origin::signed(ACCOUNT2)), None, Some(self::reaction_downvote())));
+    let post = Social::post_by_id(1).unwrap();
+    assert_eq!(post.upvotes_count, 0);
+    assert_eq!(post.downvotes_count, 1);
+
+    assert_ok!(_delete_reaction(Some(Origin::signed(ACCOUNT2)), None));
+    let post = Social::post_by_id(1).unwrap();
+    assert_eq!(post.downvotes_count, 0);
+    assert_eq!(post.score, 0);
+    assert!(Social::reaction_ids_by_post_id(1).is_empty());
+
+    assert_noop!(_delete_reaction(Some(Origin::signed(ACCOUNT2)), None), Error::<Test>::PostReactionByAccountNotFound);
+  });
+}
+
+#[test]
+fn delete_blog_should_remove_blog_reactions() {
+  new_test_ext().execute_with(|| {
+    assert_ok!(_create_default_blog()); // BlogId 1
+    assert_ok!(_react(Some(Origin::signed(ACCOUNT2)), Some(ReactionTarget::Blog(1)), None)); // ReactionId 1
+    assert_ok!(_delete_blog(None, None));
+
+    assert!(Social::reaction_by_id(1).is_none());
+    assert!(Social::reaction_ids_by_target(ReactionTarget::Blog(1)).is_empty());
+    assert!(Social::reaction_id_by_account((ACCOUNT2, ReactionTarget::Blog(1))).is_none());
+  });
+}
+
+#[test]
+fn update_post_reaction_should_only_change_reaction_on_given_post() {
+  new_test_ext().execute_with(|| {
+    assert_ok!(_create_default_blog()); // BlogId 1
+    assert_ok!(_create_default_post()); // PostId 1
+    assert_ok!(_create_default_post()); // PostId 2
+    assert_ok!(_create_post_reaction(Some(Origin::signed(ACCOUNT2)), Some(1), None)); // ReactionId 1
+    assert_ok!(_create_post_reaction(Some(Origin::signed(ACCOUNT2)), Some(2), None)); // ReactionId 2
+
+    assert_ok!(_update_post_reaction(Some(Origin::signed(ACCOUNT2)), Some(2), Some(self::reaction_downvote())));
+
+    assert_eq!(Social::reaction_by_id(1).unwrap().kind, self::reaction_upvote());
+    assert_eq!(Social::reaction_by_id(2).unwrap().kind, self::reaction_downvote());
+
+    let post = Social::post_by_id(1).unwrap();
+    assert_eq!(post.upvotes_count, 1);
+    assert_eq!(post.downvotes_count, 0);
+
+    let post = Social::post_by_id(2).unwrap();
+    assert_eq!(post.upvotes_count, 0);
+    assert_eq!(post.downvotes_count, 1);
+  });
+}
+
+#[test]
+fn delete_post_reaction_should_only_delete_reaction_on_given_post() {
+  new_test_ext().execute_with(|| {
+    assert_ok!(_create_default_blog()); // BlogId 1
+    assert_ok!(_create_default_post()); // PostId 1
+    assert_ok!(_create_default_post()); // PostId 2
+    assert_ok!(_create_post_reaction(Some(Origin::signed(ACCOUNT2)), Some(1), None)); // ReactionId 1
+    assert_ok!(_create_post_reaction(Some(Origin::signed(ACCOUNT2)), Some(2), None)); // ReactionId 2
+
+    assert_ok!(_delete_post_reaction(Some(Origin::signed(ACCOUNT2)), Some(2)));
+
+    assert!(Social::reaction_by_id(1).is_some());
+    assert!(Social::reaction_by_id(2).is_none());
+    assert_eq!(Social::post_by_id(1).unwrap().upvotes_count, 1);
+    assert_eq!(Social::post_by_id(2).unwrap().upvotes_count, 0);
+
+    // ACCOUNT3 has not reacted to the post, so there is nothing to delete
+    assert_noop!(_delete_post_reaction(Some(Origin::signed(ACCOUNT3)), Some(1)), Error::<Test>::PostReactionByAccountNotFound);
+  });
+}
+
+#[test]
+fn update_comment_reaction_should_only_change_reaction_on_given_comment() {
+  new_test_ext().execute_with(|| {
+    assert_ok!(_create_default_blog()); // BlogId 1
+    assert_ok!(_create_default_post()); // PostId 1
+    assert_ok!(_create_default_comment()); // CommentId 1
+    assert_ok!(_create_default_comment()); // CommentId 2
+    assert_ok!(_create_comment_reaction(Some(Origin::signed(ACCOUNT2)), Some(1), None)); // ReactionId 1
+    assert_ok!(_create_comment_reaction(Some(Origin::signed(ACCOUNT2)), Some(2), None)); // ReactionId 2
+
+    assert_ok!(_update_comment_reaction(Some(Origin::signed(ACCOUNT2)), Some(2), Some(self::reaction_downvote())));
+    assert_ok!(_delete_comment_reaction(Some(Origin::signed(ACCOUNT2)), Some(1)));
+
+    assert!(Social::reaction_by_id(1).is_none());
+    assert_eq!(Social::reaction_by_id(2).unwrap().kind, self::reaction_downvote());
+
+    let comment = Social::comment_by_id(1).unwrap();
+    assert_eq!(comment.upvotes_count, 0);
+    assert_eq!(comment.downvotes_count, 0);
+
+    let comment = Social::comment_by_id(2).unwrap();
+    assert_eq!(comment.upvotes_count, 0);
+    assert_eq!(comment.downvotes_count, 1);
+  });
+}
+
+#[test]
+fn create_post_reaction_should_fail_overflow_upvoting() {
+  new_test_ext().execute_with(|| {
+    assert_ok!(_create_default_blog()); // BlogId 1
+    assert_ok!(_create_default_post()); // PostId 1
+
+    let mut post = Social::post_by_id(1).unwrap();
+    post.upvotes_count = u32::max_value();
+    <PostById<Test>>::insert(1, post);
+
+    assert_noop!(
+      Social::create_post_reaction(Origin::signed(ACCOUNT2), 1, self::reaction_upvote()),
+      Error::<Test>::OverflowUpvotingPost
+    );
+  });
+}
+
+#[test]
+fn update_post_reaction_should_fail_overflow_downvoting() {
+  new_test_ext().execute_with(|| {
+    assert_ok!(_create_default_blog()); // BlogId 1
+    assert_ok!(_create_default_post()); // PostId 1
+    assert_ok!(_create_post_reaction(Some(Origin::signed(ACCOUNT2)), None, None)); // ReactionId 1
+
+    let mut post = Social::post_by_id(1).unwrap();
+    post.downvotes_count = u32::max_value();
+    <PostById<Test>>::insert(1, post);
+
+    assert_noop!(
+      Social::update_post_reaction(Origin::signed(ACCOUNT2), 1, self::reaction_downvote()),
+      Error::<Test>::OverflowDownvotingPost
+    );
+  });
+}
+
+#[test]
+fn delete_post_reaction_should_fail_underflow_removing_upvote() {
+  new_test_ext().execute_with(|| {
+    assert_ok!(_create_default_blog()); // BlogId 1
+    assert_ok!(_create_default_post()); // PostId 1
+    assert_ok!(_create_post_reaction(Some(Origin::signed(ACCOUNT2)), None, None)); // ReactionId 1
+
+    let mut post = Social::post_by_id(1).unwrap();
+    post.upvotes_count = 0;
+    <PostById<Test>>::insert(1, post);
+
+    assert_noop!(
+      Social::delete_post_reaction(Origin::signed(ACCOUNT2), 1),
+      Error::<Test>::UnderflowRemovingUpvote
+    );
+    assert_noop!(
+      Social::update_post_reaction(Origin::signed(ACCOUNT2), 1, self::reaction_downvote()),
+      Error::<Test>::UnderflowRemovingUpvote
+    );
+  });
+}
+
+#[test]
+fn update_comment_reaction_should_fail_overflow_upvoting() {
+  new_test_ext().execute_with(|| {
+    assert_ok!(_create_default_blog()); // BlogId 1
+    assert_ok!(_create_default_post()); // PostId 1
+    assert_ok!(_create_default_comment()); // CommentId 1
+    assert_ok!(_create_comment_reaction(Some(Origin::signed(ACCOUNT2)), None, Some(self::reaction_downvote()))); // ReactionId 1
+
+    let mut comment = Social::comment_by_id(1).unwrap();
+    comment.upvotes_count = u32::max_value();
+    <CommentById<Test>>::insert(1, comment);
+
+    assert_noop!(
+      Social::update_comment_reaction(Origin::signed(ACCOUNT2), 1, self::reaction_upvote()),
+      Error::<Test>::OverflowUpvotingComment
+    );
+  });
+}
+
+#[test]
+fn delete_comment_reaction_should_fail_underflow_removing_downvote() {
+  new_test_ext().execute_with(|| {
+    assert_ok!(_create_default_blog()); // BlogId 1
+    assert_ok!(_create_default_post()); // PostId 1
+    assert_ok!(_create_default_comment()); // CommentId 1
+    assert_ok!(_create_comment_reaction(Some(Origin::signed(ACCOUNT2)), None, Some(self::reaction_downvote()))); // ReactionId 1
+
+    let mut comment = Social::comment_by_id(1).unwrap();
+    comment.downvotes_count = 0;
+    <CommentById<Test>>::insert(1, comment);
+
+    assert_noop!(
+      Social::delete_comment_reaction(Origin::signed(ACCOUNT2), 1),
+      Error::<Test>::UnderflowRemovingDownvote
+    );
+  });
+}
+
+#[test]
+fn emoji_reaction_should_be_counted_without_score() {
+  new_test_ext().execute_with(|| {
+    assert_ok!(_create_default_blog()); // BlogId 1
+    assert_ok!(_create_default_post()); // PostId 1
+    assert_ok!(_create_post_reaction(Some(Origin::signed(ACCOUNT2)), None, Some(self::reaction_emoji()))); // ReactionId 1
+    assert_ok!(_create_post_reaction(Some(Origin::signed(ACCOUNT3)), None, Some(self::reaction_emoji()))); // ReactionId 2
+
+    assert_eq!(Social::reaction_count_by_target((ReactionTarget::Post(1), self::reaction_emoji())), 2);
+    assert_eq!(Social::reaction_count_by_target((ReactionTarget::Post(1), self::reaction_upvote())), 0);
+
+    let post = Social::post_by_id(1).unwrap();
+    assert_eq!(post.upvotes_count, 0);
+    assert_eq!(post.downvotes_count, 0);
+    assert_eq!(post.score, 0);
+
+    assert_ok!(_update_post_reaction(Some(Origin::signed(ACCOUNT2)), None, None));
+    assert_eq!(Social::reaction_count_by_target((ReactionTarget::Post(1), self::reaction_emoji())), 1);
+    assert_eq!(Social::reaction_count_by_target((ReactionTarget::Post(1), self::reaction_upvote())), 1);
+    assert_eq!(Social::post_by_id(1).unwrap().score, DEFAULT_UPVOTE_POST_ACTION_WEIGHT as i32);
+  });
+}
+
+#[test]
+fn emoji_reaction_should_be_scored_as_configured() {
+  new_test_ext().execute_with(|| {
+    assert_ok!(_create_default_blog()); // BlogId 1
+    assert_ok!(_create_default_post()); // PostId 1
+    assert_ok!(Social::set_emoji_scoring_kind(system::RawOrigin::Root.into(), 1, Some(self::reaction_upvote())));
+
+    assert_ok!(_create_post_reaction(Some(Origin::signed(ACCOUNT2)), None, Some(self::reaction_emoji()))); // ReactionId 1
+    assert_eq!(Social::post_by_id(1).unwrap().score, DEFAULT_UPVOTE_POST_ACTION_WEIGHT as i32);
+    assert_eq!(Social::post_by_id(1).unwrap().upvotes_count, 0);
+
+    // The score is reverted even if the emoji is not scored anymore
+    assert_ok!(Social::set_emoji_scoring_kind(system::RawOrigin::Root.into(), 1, None));
+    assert_ok!(_delete_post_reaction(Some(Origin::signed(ACCOUNT2)), None));
+    assert_eq!(Social::post_by_id(1).unwrap().score, 0);
+    assert_eq!(Social::social_account_by_id(ACCOUNT1).unwrap().reputation, 1);
+  });
+}
+
+#[test]
+fn set_emoji_scoring_kind_should_fail() {
+  new_test_ext().execute_with(|| {
+    assert_noop!(
+      Social::set_emoji_scoring_kind(Origin::signed(ACCOUNT1), 1, Some(self::reaction_upvote())),
+      DispatchError::BadOrigin
+    );
+    assert_noop!(
+      Social::set_emoji_scoring_kind(system::RawOrigin::Root.into(), 1, Some(ReactionKind::Emoji(2))),
+      Error::<Test>::InvalidEmojiScoringKind
+    );
+  });
+}
+
+// Rating system tests
+
+#[test]
+fn score_diff_by_weights_check_result() {
+  new_test_ext().execute_with(|| {
+    assert_eq!(Social::get_score_diff(1, self::scoring_action_upvote_post()), DEFAULT_UPVOTE_POST_ACTION_WEIGHT as i16);
+    assert_eq!(Social::get_score_diff(1, self::scoring_action_downvote_post()), DEFAULT_DOWNVOTE_POST_ACTION_WEIGHT as i16);
+    assert_eq!(Social::get_score_diff(1, self::scoring_action_share_post()), DEFAULT_SHARE_POST_ACTION_WEIGHT as i16);
+    assert_eq!(Social::get_score_diff(1, self::scoring_action_create_comment()), DEFAULT_CREATE_COMMENT_ACTION_WEIGHT as i16);
+    assert_eq!(Social::get_score_diff(1, self::scoring_action_upvote_comment()), DEFAULT_UPVOTE_COMMENT_ACTION_WEIGHT as i16);
+    assert_eq!(Social::get_score_diff(1, self::scoring_action_downvote_comment()), DEFAULT_DOWNVOTE_COMMENT_ACTION_WEIGHT as i16);
+    assert_eq!(Social::get_score_diff(1, self::scoring_action_share_comment()), DEFAULT_SHARE_COMMENT_ACTION_WEIGHT as i16);
+    assert_eq!(Social::get_score_diff(1, self::scoring_action_follow_blog()), DEFAULT_FOLLOW_BLOG_ACTION_WEIGHT as i16);
+    assert_eq!(Social::get_score_diff(1, self::scoring_action_follow_account()), DEFAULT_FOLLOW_ACCOUNT_ACTION_WEIGHT as i16);
+  });
+}
+
+#[test]
+fn random_score_diff_check_result() {
+  new_test_ext().execute_with(|| {
+    assert_eq!(Social::get_score_diff(32768, self::scoring_action_upvote_post()), 80); // 2^15
+    assert_eq!(Social::get_score_diff(32769, self::scoring_action_upvote_post()), 80); // 2^15 + 1
+    assert_eq!(Social::get_score_diff(65535, self::scoring_action_upvote_post()), 80); // 2^16 - 1
+    assert_eq!(Social::get_score_diff(65536, self::scoring_action_upvote_post()), 85); // 2^16
+  });
+}
+
+//--------------------------------------------------------------------------------------------------
+
+#[test]
+fn change_blog_score_should_work_follow_blog() {
+  new_test_ext().execute_with(|| {
+    assert_ok!(_create_default_blog()); // BlogId 1
+
+    assert_ok!(Social::follow_blog(Origin::signed(ACCOUNT2), 1));
+
+    assert_eq!(Social::blog_by_id(1).unwrap().score, DEFAULT_FOLLOW_BLOG_ACTION_WEIGHT as i32);
+    assert_eq!(Social::social_account_by_id(ACCOUNT1).unwrap().reputation, 1 + DEFAULT_FOLLOW_BLOG_ACTION_WEIGHT as u32);
+    assert_eq!(Social::social_account_by_id(ACCOUNT2).unwrap().reputation, 1);
+  });
+}
+
+#[test]
+fn change_blog_score_should_work_revert_follow_blog() {
+  new_test_ext().execute_with(|| {
+    assert_ok!(_create_default_blog()); // BlogId 1
+
+    assert_ok!(Social::follow_blog(Origin::signed(ACCOUNT2), 1));
+    assert_ok!(Social::unfollow_blog(Origin::signed(ACCOUNT2), 1));
+
+    assert_eq!(Social::blog_by_id(1).unwrap().score, 0);
+    assert_eq!(Social::social_account_by_id(ACCOUNT1).unwrap().reputation, 1);
+    assert_eq!(Social::social_account_by_id(ACCOUNT2).unwrap().reputation, 1);
+  });
+}
+
+#[test]
+fn change_blog_score_should_work_upvote_post() {
+  new_test_ext().execute_with(|| {
+    assert_ok!(_create_default_blog()); // BlogId 1
+    assert_ok!(_create_default_post());
+    assert_ok!(_create_post_reaction(Some(Origin::signed(ACCOUNT2)), None, None)); // ReactionId 1
+
+    assert_eq!(Social::blog_by_id(1).unwrap().score, DEFAULT_UPVOTE_POST_ACTION_WEIGHT as i32);
+    assert_eq!(Social::social_account_by_id(ACCOUNT1).unwrap().reputation, 1 + DEFAULT_UPVOTE_POST_ACTION_WEIGHT as u32);
+  });
+}
+
+#[test]
+fn change_blog_score_should_work_downvote_post() {
+  new_test_ext().execute_with(|| {
+    assert_ok!(_create_default_blog()); // BlogId 1
+    assert_ok!(_create_default_post());
+    assert_ok!(_create_post_reaction(Some(Origin::signed(ACCOUNT2)), None, Some(self::reaction_downvote()))); // ReactionId 1
+
+    assert_eq!(Social::blog_by_id(1).unwrap().score, DEFAULT_DOWNVOTE_POST_ACTION_WEIGHT as i32);
+    assert_eq!(Social::social_account_by_id(ACCOUNT1).unwrap().reputation, 1);
+  });
+}
+
+#[test]
+fn hot_score_should_decay_over_time() {
+  new_test_ext().execute_with(|| {
+    ScoreHalfLife::put(100);
+
+    assert_ok!(_create_default_blog()); // BlogId 1
+    assert_ok!(_create_default_post()); // PostId 1
+    assert_ok!(_create_post_reaction(Some(Origin::signed(ACCOUNT2)), None, None)); // ReactionId 1 at block 0
+
+    let initial = DEFAULT_UPVOTE_POST_ACTION_WEIGHT as i64 * HOT_SCORE_PRECISION;
+    assert_eq!(Social::hot_score(1, 0), initial);
+    assert_eq!(Social::hot_score(1, 50), initial * 3 / 4);
+    assert_eq!(Social::hot_score(1, 100), initial / 2);
+    assert_eq!(Social::hot_score(1, 200), initial / 4);
+
+    // Raw score is not decayed
+    assert_eq!(Social::post_by_id(1).unwrap().score, DEFAULT_UPVOTE_POST_ACTION_WEIGHT as i32);
+  });
+}
+
+#[test]
+fn hot_score_should_add_new_score_to_decayed_value() {
+  new_test_ext().execute_with(|| {
+    ScoreHalfLife::put(100);
+
+    assert_ok!(_create_default_blog()); // BlogId 1
+    assert_ok!(_create_default_post()); // PostId 1
+    assert_ok!(_create_default_comment()); // CommentId 1
+    assert_ok!(_create_comment_reaction(Some(Origin::signed(ACCOUNT2)), None, None)); // ReactionId 1 at block 0
+
+    system::Module::<Test>::set_block_number(100);
+    assert_ok!(_create_post_reaction(Some(Origin::signed(ACCOUNT2)), None, None)); // ReactionId 2 at block 100
+
+    let comment_upvote = DEFAULT_UPVOTE_COMMENT_ACTION_WEIGHT as i64 * HOT_SCORE_PRECISION;
+    assert_eq!(Social::comment_hot_score(1, 100), comment_upvote / 2);
+    assert_eq!(Social::hot_score(1, 100), DEFAULT_UPVOTE_POST_ACTION_WEIGHT as i64 * HOT_SCORE_PRECISION);
+
+    assert_ok!(_delete_comment(None, None));
+    assert!(Social::comment_hot_score_by_id(1).is_none());
+  });
+}
+
+//--------------------------------------------------------------------------------------------------
+
+#[test]
+fn change_post_score_should_work_create_comment() {
+  new_test_ext().execute_with(|| {
+    assert_ok!(_create_default_blog()); // BlogId 1
+    assert_ok!(_create_default_post()); // PostId 1
+    assert_ok!(_create_comment(Some(Origin::signed(ACCOUNT2)), None, None, None)); // CommentId 1
+
+    assert_eq!(Social::post_by_id(1).unwrap().score, DEFAULT_CREATE_COMMENT_ACTION_WEIGHT as i32);
+    assert_eq!(Social::blog_by_id(1).unwrap().score, DEFAULT_CREATE_COMMENT_ACTION_WEIGHT as i32);
+    assert_eq!(Social::social_account_by_id(ACCOUNT1).unwrap().reputation, 1 + DEFAULT_CREATE_COMMENT_ACTION_WEIGHT as u32);
+    assert_eq!(Social::post_score_by_account((ACCOUNT2, 1, self::scoring_action_create_comment())), Some(DEFAULT_CREATE_COMMENT_ACTION_WEIGHT));
+  });
+}
+
+#[test]
+fn change_post_score_should_work_upvote() {
+  new_test_ext().execute_with(|| {
+    assert_ok!(_create_default_blog());
+    assert_ok!(_create_default_post()); // PostId 1
+
+    assert_ok!(_create_post_reaction(Some(Origin::signed(ACCOUNT2)), None, None));
+
+    assert_eq!(Social::post_by_id(1).unwrap().score, DEFAULT_UPVOTE_POST_ACTION_WEIGHT as i32);
+    assert_eq!(Social::social_account_by_id(ACCOUNT1).unwrap().reputation, 1 + DEFAULT_UPVOTE_POST_ACTION_WEIGHT as u32);
+    assert_eq!(Social::post_score_by_account((ACCOUNT2, 1, self::scoring_action_upvote_post())), Some(DEFAULT_UPVOTE_POST_ACTION_WEIGHT));
+  });
+}
+
+#[test]
+fn change_post_score_should_work_downvote() {
+  new_test_ext().execute_with(|| {
+    assert_ok!(_create_default_blog());
+    assert_ok!(_create_default_post()); // PostId 1
+
+    assert_ok!(_create_post_reaction(Some(Origin::signed(ACCOUNT2)), None, Some(self::reaction_downvote())));
+
+    assert_eq!(Social::post_by_id(1).unwrap().score, DEFAULT_DOWNVOTE_POST_ACTION_WEIGHT as i32);
+    assert_eq!(Social::social_account_by_id(ACCOUNT1).unwrap().reputation, 1);
+    assert_eq!(Social::post_score_by_account((ACCOUNT2, 1, self::scoring_action_downvote_post())), Some(DEFAULT_DOWNVOTE_POST_ACTION_WEIGHT));
+  });
+}
+
+#[test]
+fn change_post_score_should_revert_upvote() {
+  new_test_ext().execute_with(|| {
+    assert_ok!(_create_default_blog());
+    assert_ok!(_create_default_post()); // PostId 1
+
+    assert_ok!(_create_post_reaction(Some(Origin::signed(ACCOUNT2)), None, None)); // ReactionId 1
+    assert_ok!(_delete_post_reaction(Some(Origin::signed(ACCOUNT2)), None));
+
+    assert_eq!(Social::post_by_id(1).unwrap().score, 0);
+    assert_eq!(Social::social_account_by_id(ACCOUNT1).unwrap().reputation, 1);
+    assert_eq!(Social::post_score_by_account((ACCOUNT2, 1, self::scoring_action_upvote_post())), None);
+  });
+}
+
+#[test]
+fn change_post_score_should_revert_downvote() {
+  new_test_ext().execute_with(|| {
+    assert_ok!(_create_default_blog());
+    assert_ok!(_create_default_post()); // PostId 1
+
+    assert_ok!(_create_post_reaction(Some(Origin::signed(ACCOUNT2)), None, Some(self::reaction_downvote()))); // ReactionId 1
+    assert_ok!(_delete_post_reaction(Some(Origin::signed(ACCOUNT2)), None));
+
+    assert_eq!(Social::post_by_id(1).unwrap().score, 0);
+    assert_eq!(Social::social_account_by_id(ACCOUNT1).unwrap().reputation, 1);
+    assert_eq!(Social::post_score_by_account((ACCOUNT2, 1, self::scoring_action_downvote_post())), None);
+  });
+}
+
+#[test]
+fn change_post_score_cancel_upvote_with_downvote() {
+  new_test_ext().execute_with(|| {
+    assert_ok!(_create_default_blog());
+    assert_ok!(_create_default_post()); // PostId 1
+
+    assert_ok!(_create_post_reaction(Some(Origin::signed(ACCOUNT2)), None, None)); // ReactionId 1
+    assert_ok!(_update_post_reaction(Some(Origin::signed(ACCOUNT2)), None, Some(self::reaction_downvote())));
+
+    assert_eq!(Social::post_by_id(1).unwrap().score, DEFAULT_DOWNVOTE_POST_ACTION_WEIGHT as i32);
+    assert_eq!(Social::social_account_by_id(ACCOUNT1).unwrap().reputation, 1);
+    assert_eq!(Social::post_score_by_account((ACCOUNT2, 1, self::scoring_action_upvote_post())), None);
+    assert_eq!(Social::post_score_by_account((ACCOUNT2, 1, self::scoring_action_downvote_post())), Some(DEFAULT_DOWNVOTE_POST_ACTION_WEIGHT));
+  });
+}
+
+#[test]
+fn change_post_score_cancel_downvote_with_upvote() {
+  new_test_ext().execute_with(|| {
+    assert_ok!(_create_default_blog());
+    assert_ok!(_create_default_post()); // PostId 1
+
+    assert_ok!(_create_post_reaction(Some(Origin::signed(ACCOUNT2)), None, Some(self::reaction_downvote()))); // ReactionId 1
+    assert_ok!(_update_post_reaction(Some(Origin::signed(ACCOUNT2)), None, None));
+
+    assert_eq!(Social::post_by_id(1).unwrap().score, DEFAULT_UPVOTE_POST_ACTION_WEIGHT as i32);
+    assert_eq!(Social::social_account_by_id(ACCOUNT1).unwrap().reputation, 1 + DEFAULT_UPVOTE_POST_ACTION_WEIGHT as u32);
+    assert_eq!(Social::post_score_by_account((ACCOUNT2, 1, self::scoring_action_downvote_post())), None);
+    assert_eq!(Social::post_score_by_account((ACCOUNT2, 1, self::scoring_action_upvote_post())), Some(DEFAULT_UPVOTE_POST_ACTION_WEIGHT));
+  });
+}
+
+#[test]
+fn change_post_score_should_fail_post_not_found() {
+  new_test_ext().execute_with(|| {
+    assert_ok!(_create_default_blog());
+    assert_noop!(_change_post_score_by_id(ACCOUNT1, 1, self::scoring_action_upvote_post()), Error::<Test>::PostNotFound);
+  });
+}
+
+//--------------------------------------------------------------------------------------------------
+
+#[test]
+fn change_social_account_reputation_should_work_max_score_diff() {
+  new_test_ext().execute_with(|| {
+    assert_ok!(_create_blog(Some(Origin::signed(ACCOUNT2)), None, None));
+    assert_ok!(_create_post(Some(Origin::signed(ACCOUNT2)), None, None, None));
+    assert_ok!(Social::change_social_account_reputation(
+      ACCOUNT2,
+      ACCOUNT1,
+      std::i16::MAX,
+      self::scoring_action_follow_account(),
+      ScoredEntity::Account(ACCOUNT2))
+    );
+  });
+}
+
+#[test]
+fn change_social_account_reputation_should_work_min_score_diff() {
+  new_test_ext().execute_with(|| {
+    assert_ok!(_create_blog(Some(Origin::signed(ACCOUNT2)), None, None));
+    assert_ok!(_create_post(Some(Origin::signed(ACCOUNT2)), None, None, None));
+    assert_ok!(Social::change_social_account_reputation(
+      ACCOUNT2,
+      ACCOUNT1,
+      std::i16::MIN,
+      self::scoring_action_follow_account(),
+      ScoredEntity::Account(ACCOUNT2))
+    );
+  });
+}
+
+#[test]
+fn change_social_account_reputation_should_work() {
+  new_test_ext().execute_with(|| {
+    assert_ok!(_create_blog(Some(Origin::signed(ACCOUNT2)), None, None));
+    assert_ok!(_create_post(Some(Origin::signed(ACCOUNT2)), None, None, None));
+    assert_ok!(Social::change_social_account_reputation(
+      ACCOUNT2,
+      ACCOUNT1,
+      DEFAULT_DOWNVOTE_POST_ACTION_WEIGHT,
+      self::scoring_action_downvote_post(),
+      ScoredEntity::Post(1))
+    );
+    assert_eq!(
+      Social::reputation_diff_by_key((ACCOUNT1, self::scoring_action_downvote_post(), ScoredEntity::Post(1))),
+      Some((ACCOUNT2, 0))
+    );
+    assert_eq!(Social::social_account_by_id(ACCOUNT2).unwrap().reputation, 1);
+
+    assert_ok!(Social::change_social_account_reputation(
+      ACCOUNT2,
+      ACCOUNT1,
+      DEFAULT_UPVOTE_POST_ACTION_WEIGHT * 2,
+      self::scoring_action_upvote_post(),
+      ScoredEntity::Post(1))
+    );
+    assert_eq!(
+      Social::reputation_diff_by_key((ACCOUNT1, self::scoring_action_upvote_post(), ScoredEntity::Post(1))),
+      Some((ACCOUNT2, DEFAULT_UPVOTE_POST_ACTION_WEIGHT * 2))
+    );
+    assert_eq!(Social::social_account_by_id(ACCOUNT2).unwrap().reputation, 1 + (DEFAULT_UPVOTE_POST_ACTION_WEIGHT * 2) as u32);
+  });
+}
+
+//--------------------------------------------------------------------------------------------------
+
+#[test]
+fn change_comment_score_should_work_upvote() {
+  new_test_ext().execute_with(|| {
+    assert_ok!(_create_blog(Some(Origin::signed(ACCOUNT2)), None, None));
+    assert_ok!(_create_post(Some(Origin::signed(ACCOUNT2)), None, None, None));
+    assert_ok!(_create_comment(Some(Origin::signed(ACCOUNT2)), None, None, None));
+    assert_ok!(_change_comment_score_by_id(ACCOUNT1, 1, self::scoring_action_upvote_comment()));
+    assert_eq!(Social::comment_by_id(1).unwrap().score, DEFAULT_UPVOTE_COMMENT_ACTION_WEIGHT as i32);
+    assert_eq!(Social::social_account_by_id(ACCOUNT1).unwrap().reputation, 1);
+    assert_eq!(Social::social_account_by_id(ACCOUNT2).unwrap().reputation, 1 + DEFAULT_UPVOTE_COMMENT_ACTION_WEIGHT as u32);
+    assert_eq!(Social::comment_score_by_account((ACCOUNT1, 1, self::scoring_action_upvote_comment())), Some(DEFAULT_UPVOTE_COMMENT_ACTION_WEIGHT));
+  });
+}
+
+#[test]
+fn change_comment_score_should_work_downvote() {
+  new_test_ext().execute_with(|| {
+    assert_ok!(_create_blog(Some(Origin::signed(ACCOUNT2)), None, None));
+    assert_ok!(_create_post(Some(Origin::signed(ACCOUNT2)), None, None, None));
+    assert_ok!(_create_comment(Some(Origin::signed(ACCOUNT2)), None, None, None));
+    assert_ok!(_change_comment_score_by_id(ACCOUNT1, 1, self::scoring_action_downvote_comment()));
+    assert_eq!(Social::comment_by_id(1).unwrap().score, DEFAULT_DOWNVOTE_COMMENT_ACTION_WEIGHT as i32);
+    assert_eq!(Social::social_account_by_id(ACCOUNT1).unwrap().reputation, 1);
+    assert_eq!(Social::social_account_by_id(ACCOUNT2).unwrap().reputation, 1);
+    assert_eq!(Social::comment_score_by_account((ACCOUNT1, 1, self::scoring_action_downvote_comment())), Some(DEFAULT_DOWNVOTE_COMMENT_ACTION_WEIGHT));
+  });
+}
+
+#[test]
+fn change_comment_score_should_revert_upvote() {
+  new_test_ext().execute_with(|| {
+    assert_ok!(_create_blog(Some(Origin::signed(ACCOUNT2)), None, None));
+    assert_ok!(_create_post(Some(Origin::signed(ACCOUNT2)), None, None, None));
+    assert_ok!(_create_comment(Some(Origin::signed(ACCOUNT2)), None, None, None));
+    assert_ok!(_change_comment_score_by_id(ACCOUNT1, 1, self::scoring_action_upvote_comment()));
+    assert_ok!(_change_comment_score_by_id(ACCOUNT1, 1, self::scoring_action_upvote_comment()));
+    assert_eq!(Social::comment_by_id(1).unwrap().score, 0);
+    assert_eq!(Social::social_account_by_id(ACCOUNT1).unwrap().reputation, 1);
+    assert_eq!(Social::social_account_by_id(ACCOUNT2).unwrap().reputation, 1);
+    assert_eq!(Social::comment_score_by_account((ACCOUNT1, 1, self::scoring_action_upvote_comment())), None);
+  });
+}
+
+#[test]
+fn change_comment_score_should_revert_downvote() {
+  new_test_ext().execute_with(|| {
+    assert_ok!(_create_blog(Some(Origin::signed(ACCOUNT2)), None, None));
+    assert_ok!(_create_post(Some(Origin::signed(ACCOUNT2)), None, None, None));
+    assert_ok!(_create_comment(Some(Origin::signed(ACCOUNT2)), None, None, None));
+    assert_ok!(_change_comment_score_by_id(ACCOUNT1, 1, self::scoring_action_downvote_comment()));
+    assert_ok!(_change_comment_score_by_id(ACCOUNT1, 1, self::scoring_action_downvote_comment()));
+    assert_eq!(Social::comment_by_id(1).unwrap().score, 0);
+    assert_eq!(Social::social_account_by_id(ACCOUNT1).unwrap().reputation, 1);
+    assert_eq!(Social::social_account_by_id(ACCOUNT2).unwrap().reputation, 1);
+    assert_eq!(Social::comment_score_by_account((ACCOUNT1, 1, self::scoring_action_downvote_comment())), None);
+  });
+}
+
+#[test]
+fn change_comment_score_check_cancel_upvote() {
+  new_test_ext().execute_with(|| {
+    assert_ok!(_create_blog(Some(Origin::signed(ACCOUNT2)), None, None));
+    assert_ok!(_create_post(Some(Origin::signed(ACCOUNT2)), None, None, None));
+    assert_ok!(_create_comment(Some(Origin::signed(ACCOUNT2)), None, None, None));
+
+    assert_ok!(_change_comment_score_by_id(ACCOUNT1, 1, self::scoring_action_upvote_comment()));
+
+    assert_ok!(_change_comment_score_by_id(ACCOUNT1, 1, self::scoring_action_downvote_comment()));
+    assert_eq!(Social::comment_by_id(1).unwrap().score, DEFAULT_DOWNVOTE_COMMENT_ACTION_WEIGHT as i32);
+    assert_eq!(Social::social_account_by_id(ACCOUNT1).unwrap().reputation, 1);
+    assert_eq!(Social::social_account_by_id(ACCOUNT2).unwrap().reputation, 1);
+    assert_eq!(Social::comment_score_by_account((ACCOUNT1, 1, self::scoring_action_upvote_comment())), None);
+    assert_eq!(Social::comment_score_by_account((ACCOUNT1, 1, self::scoring_action_downvote_comment())), Some(DEFAULT_DOWNVOTE_COMMENT_ACTION_WEIGHT));
+  });
+}
+
+#[test]
+fn change_comment_score_check_cancel_downvote() {
+  new_test_ext().execute_with(|| {
+    assert_ok!(_create_blog(Some(Origin::signed(ACCOUNT2)), None, None));
+    assert_ok!(_create_post(Some(Origin::signed(ACCOUNT2)), None, None, None));
+    assert_ok!(_create_comment(Some(Origin::signed(ACCOUNT2)), None, None, None));
+
+    assert_ok!(_change_comment_score_by_id(ACCOUNT1, 1, self::scoring_action_downvote_comment()));
+
+    assert_ok!(_change_comment_score_by_id(ACCOUNT1, 1, self::scoring_action_upvote_comment()));
+    assert_eq!(Social::comment_by_id(1).unwrap().score, DEFAULT_UPVOTE_COMMENT_ACTION_WEIGHT as i32);
+    assert_eq!(Social::social_account_by_id(ACCOUNT1).unwrap().reputation, 1);
+    assert_eq!(Social::social_account_by_id(ACCOUNT2).unwrap().reputation, 1 + DEFAULT_UPVOTE_COMMENT_ACTION_WEIGHT as u32);
+    assert_eq!(Social::comment_score_by_account((ACCOUNT1, 1, self::scoring_action_downvote_comment())), None);
+    assert_eq!(Social::comment_score_by_account((ACCOUNT1, 1, self::scoring_action_upvote_comment())), Some(DEFAULT_UPVOTE_COMMENT_ACTION_WEIGHT));
+  });
+}
+
+#[test]
+fn change_comment_score_should_fail_comment_not_found() {
+  new_test_ext().execute_with(|| {
+    assert_ok!(_create_default_blog());
+    assert_ok!(_create_default_post());
+    assert_ok!(_create_comment(Some(Origin::signed(ACCOUNT2)), None, None, None));
+    assert_noop!(_change_comment_score_by_id(ACCOUNT1, 2, self::scoring_action_upvote_comment()), Error::<Test>::CommentNotFound);
+  });
+}
+
+// Shares tests
+
+#[test]
+fn share_post_should_work() {
+  new_test_ext().execute_with(|| {
+    assert_ok!(_create_default_blog()); // BlogId 1
+    assert_ok!(_create_blog(Some(Origin::signed(ACCOUNT2)), Some(b"blog2_slug".to_vec()), None)); // BlogId 2 by ACCOUNT2
+    assert_ok!(_create_default_post()); // PostId 1
+    assert_ok!(_create_post(
+      Some(Origin::signed(ACCOUNT2)),
+      Some(2),
+      Some(vec![]),
+      Some(self::extension_shared_post(1))
+    )); // Share PostId 1 on BlogId 2 by ACCOUNT2
+
+    // Check storages
+    assert_eq!(Social::post_ids_by_blog_id(1), vec![1]);
+    assert_eq!(Social::post_ids_by_blog_id(2), vec![2]);
+    assert_eq!(Social::next_post_id(), 3);
+
+    assert_eq!(Social::post_shares_by_account((ACCOUNT2, 1)), 1);
+    assert_eq!(Social::shared_post_ids_by_original_post_id(1), vec![2]);
+
+    // Check whether data stored correctly
+    assert_eq!(Social::post_by_id(1).unwrap().shares_count, 1);
+
+    let shared_post = Social::post_by_id(2).unwrap();
+
+    assert_eq!(shared_post.blog_id, 2);
+    assert_eq!(shared_post.created.account, ACCOUNT2);
+    assert!(shared_post.ipfs_hash.is_empty());
+    assert_eq!(shared_post.extension, self::extension_shared_post(1));
+  });
+}
+
+#[test]
+fn share_post_should_work_share_own_post() {
+  new_test_ext().execute_with(|| {
+    assert_ok!(_create_default_blog()); // BlogId 1
+    assert_ok!(_create_default_post()); // PostId 1
+    assert_ok!(_create_post(
+      Some(Origin::signed(ACCOUNT1)),
+      Some(1),
+      Some(vec![]),
+      Some(self::extension_shared_post(1))
+    )); // Share PostId 1
+
+    // Check storages
+    assert_eq!(Social::post_ids_by_blog_id(1), vec![1, 2]);
+    assert_eq!(Social::next_post_id(), 3);
+
+    assert_eq!(Social::post_shares_by_account((ACCOUNT1, 1)), 1);
+    assert_eq!(Social::shared_post_ids_by_original_post_id(1), vec![2]);
+
+    // Check whether data stored correctly
+    assert_eq!(Social::post_by_id(1).unwrap().shares_count, 1);
+
+    let shared_post = Social::post_by_id(2).unwrap();
+    assert_eq!(shared_post.blog_id, 1);
+    assert_eq!(shared_post.created.account, ACCOUNT1);
+    assert!(shared_post.ipfs_hash.is_empty());
+    assert_eq!(shared_post.extension, self::extension_shared_post(1));
+  });
+}
+
+#[test]
+fn share_post_should_change_score() {
+  new_test_ext().execute_with(|| {
+    assert_ok!(_create_default_blog()); // BlogId 1
+    assert_ok!(_create_blog(Some(Origin::signed(ACCOUNT2)), Some(b"blog2_slug".to_vec()), None)); // BlogId 2 by ACCOUNT2
+    assert_ok!(_create_default_post()); // PostId 1
+    assert_ok!(_create_post(
+      Some(Origin::signed(ACCOUNT2)),
+      Some(2),
+      Some(vec![]),
+      Some(self::extension_shared_post(1))
+    )); // Share PostId 1 on BlogId 2 by ACCOUNT2
+
+    assert_eq!(Social::post_by_id(1).unwrap().score, DEFAULT_SHARE_POST_ACTION_WEIGHT as i32);
+    assert_eq!(Social::social_account_by_id(ACCOUNT1).unwrap().reputation, 1 + DEFAULT_SHARE_POST_ACTION_WEIGHT as u32);
+    assert_eq!(Social::post_score_by_account((ACCOUNT2, 1, self::scoring_action_share_post())), Some(DEFAULT_SHARE_POST_ACTION_WEIGHT));
+  });
+}
+
+#[test]
+fn share_post_should_not_change_score() {
+  new_test_ext().execute_with(|| {
+    assert_ok!(_create_default_blog()); // BlogId 1
+    assert_ok!(_create_default_post()); // PostId 1
+    assert_ok!(_create_post(
+      Some(Origin::signed(ACCOUNT1)),
+      Some(1),
+      Some(vec![]),
+      Some(self::extension_shared_post(1))
+    )); // Share PostId
+
+    assert_eq!(Social::post_by_id(1).unwrap().score, 0);
+    assert_eq!(Social::social_account_by_id(ACCOUNT1).unwrap().reputation, 1);
+    assert_eq!(Social::post_score_by_account((ACCOUNT1, 1, self::scoring_action_share_post())), None);
+  });
+}
+
+#[test]
+fn share_post_should_fail_original_post_not_found() {
+  new_test_ext().execute_with(|| {
+    assert_ok!(_create_default_blog()); // BlogId 1
+    assert_ok!(_create_blog(Some(Origin::signed(ACCOUNT2)), Some(b"blog2_slug".to_vec()), None)); // BlogId 2 by ACCOUNT2
+    // Skipped creating PostId 1
+    assert_noop!(_create_post(
+      Some(Origin::signed(ACCOUNT2)),
+      Some(2),
+      Some(vec![]),
+      Some(self::extension_shared_post(1))),
+
+    Error::<Test>::OriginalPostNotFound);
+  });
+}
+
+#[test]
+fn share_post_should_fail_share_shared_post() {
+  new_test_ext().execute_with(|| {
+    assert_ok!(_create_default_blog()); // BlogId 1
+    assert_ok!(_create_blog(Some(Origin::signed(ACCOUNT2)), Some(b"blog2_slug".to_vec()), None)); // BlogId 2 by ACCOUNT2
+    assert_ok!(_create_default_post());
+    assert_ok!(_create_post(
+      Some(Origin::signed(ACCOUNT2)),
+      Some(2),
+      Some(vec![]),
+      Some(self::extension_shared_post(1)))
+    );
+
+    // Try to share post with extension SharedPost
+    assert_noop!(_create_post(
+      Some(Origin::signed(ACCOUNT1)),
+      Some(1),
+      Some(vec![]),
+      Some(self::extension_shared_post(2))),
+
+    Error::<Test>::CannotShareSharedPost);
+  });
+}
+
+#[test]
+fn share_comment_should_work() {
+  new_test_ext().execute_with(|| {
+    assert_ok!(_create_default_blog()); // BlogId 1
+    assert_ok!(_create_blog(Some(Origin::signed(ACCOUNT2)), Some(b"blog2_slug".to_vec()), None)); // BlogId 2 by ACCOUNT2
+    assert_ok!(_create_default_post()); // PostId 1
+    assert_ok!(_create_default_comment()); // CommentId 1
+    assert_ok!(_create_post(
+      Some(Origin::signed(ACCOUNT2)),
+      Some(2),
+      Some(vec![]),
+      Some(self::extension_shared_comment(1))
+    )); // Share CommentId 1 on BlogId 2 by ACCOUNT2
+
+    // Check storages
+    assert_eq!(Social::post_ids_by_blog_id(1), vec![1]);
+    assert_eq!(Social::post_ids_by_blog_id(2), vec![2]);
+    assert_eq!(Social::next_post_id(), 3);
+
+    assert_eq!(Social::comment_shares_by_account((ACCOUNT2, 1)), 1);
+    assert_eq!(Social::shared_post_ids_by_original_comment_id(1), vec![2]);
+
+    // Check whether data stored correctly
+    assert_eq!(Social::comment_by_id(1).unwrap().shares_count, 1);
+
+    let shared_post = Social::post_by_id(2).unwrap();
+
+    assert_eq!(shared_post.blog_id, 2);
+    assert_eq!(shared_post.created.account, ACCOUNT2);
+    assert!(shared_post.ipfs_hash.is_empty());
+    assert_eq!(shared_post.extension, self::extension_shared_comment(1));
+  });
+}
+
+#[test]
+fn share_comment_should_change_score() {
+  new_test_ext().execute_with(|| {
+    assert_ok!(_create_default_blog()); // BlogId 1
+    assert_ok!(_create_blog(Some(Origin::signed(ACCOUNT2)), Some(b"blog2_slug".to_vec()), None)); // BlogId 2 by ACCOUNT2
+    assert_ok!(_create_default_post()); // PostId 1
+    assert_ok!(_create_default_comment()); // CommentId 1
+    assert_ok!(_create_post(
+      Some(Origin::signed(ACCOUNT2)),
+      Some(2),
+      Some(vec![]),
+      Some(self::extension_shared_comment(1))
+    )); // Share CommentId 1 on BlogId 2 by ACCOUNT2
+
+    assert_eq!(Social::comment_by_id(1).unwrap().score, DEFAULT_SHARE_COMMENT_ACTION_WEIGHT as i32);
+    assert_eq!(Social::social_account_by_id(ACCOUNT1).unwrap().reputation, 1 + DEFAULT_SHARE_COMMENT_ACTION_WEIGHT as u32);
+    assert_eq!(Social::comment_score_by_account((ACCOUNT2, 1, self::scoring_action_share_comment())), Some(DEFAULT_SHARE_COMMENT_ACTION_WEIGHT));
+  });
+}
+
+#[test]
+fn share_comment_should_count_shares_by_comment_author() {
+  new_test_ext().execute_with(|| {
+    assert_ok!(_create_default_blog()); // BlogId 1
+    assert_ok!(_create_default_post()); // PostId 1
+    assert_ok!(_create_default_comment()); // CommentId 1
+
+    // Sharing an own comment is not scored, but the shares count is still saved
+    assert_ok!(_create_post(None, None, Some(vec![]), Some(self::extension_shared_comment(1)))); // PostId 2
+    assert_ok!(_create_post(None, None, Some(vec![]), Some(self::extension_shared_comment(1)))); // PostId 3
+
+    let comment = Social::comment_by_id(1).unwrap();
+    assert_eq!(comment.shares_count, 2);
+    assert_eq!(comment.score, 0);
+    assert_eq!(Social::comment_shares_by_account((ACCOUNT1, 1)), 2);
+
+    assert_ok!(_delete_post(None, Some(3)));
+    assert_eq!(Social::comment_by_id(1).unwrap().shares_count, 1);
+  });
+}
+
+#[test]
+fn share_comment_should_fail_original_comment_not_found() {
+  new_test_ext().execute_with(|| {
+    assert_ok!(_create_default_blog()); // BlogId 1
+    assert_ok!(_create_blog(Some(Origin::signed(ACCOUNT2)), Some(b"blog2_slug".to_vec()), None)); // BlogId 2 by ACCOUNT2
+    assert_ok!(_create_default_post()); // PostId 1
+    // Skipped creating CommentId 1
+    assert_noop!(_create_post(
+      Some(Origin::signed(ACCOUNT2)),
+      Some(2),
+      Some(vec![]),
+      Some(self::extension_shared_comment(1))),
+
+    Error::<Test>::OriginalCommentNotFound);
+  });
+}
+
+// Profiles tests
+
+#[test]
+fn create_profile_should_work() {
+  new_test_ext().execute_with(|| {
+    assert_ok!(_create_default_profile()); // AccountId 1
+
+    let profile = Social::social_account_by_id(ACCOUNT1).unwrap().profile.unwrap();
+    assert_eq!(profile.created.account, ACCOUNT1);
+    // TODO: Fix unresolved error
+    // assert_eq!(profile.updated, None);
+    assert_eq!(profile.username, self::alice_username());
+    assert_eq!(profile.ipfs_hash, self::profile_ipfs_hash());
+    assert!(Social::profile_edit_history(ACCOUNT1, 0).is_empty());
+    assert_eq!(Social::account_by_profile_username(self::alice_username()), Some(ACCOUNT1));
+  });
+}
+
+#[test]
+fn create_profile_should_fail_profile_exists() {
+  new_test_ext().execute_with(|| {
+    assert_ok!(_create_default_profile()); // AccountId 1
+    assert_noop!(_create_default_profile(), Error::<Test>::ProfileAlreadyExists);
+  });
+}
+
+#[test]
+fn create_profile_should_fail_invalid_ipfs_hash() {
+  let ipfs_hash : Vec<u8> = b"QmV9tSDx9UiPeWExXEeH6aoDvmihvx6j".to_vec();
+
+  new_test_ext().execute_with(|| {
+    assert_noop!(_create_profile(None, None, Some(ipfs_hash)), Error::<Test>::IpfsIsIncorrect);
+  });
+}
+
+#[test]
+fn create_profile_should_fail_username_is_busy() {
+  new_test_ext().execute_with(|| {
+    assert_ok!(_create_default_profile()); // AccountId 1
+    assert_noop!(_create_profile(Some(Origin::signed(ACCOUNT2)), None, None), Error::<Test>::UsernameIsBusy);
+  });
+}
+
+#[test]
+fn create_profile_should_fail_too_short_username() {
+  let username : Vec<u8> = vec![97; (DEFAULT_USERNAME_MIN_LEN - 1) as usize];
+
+  new_test_ext().execute_with(|| {
+    assert_ok!(_create_default_profile()); // AccountId 1
+    assert_noop!(_create_profile(Some(Origin::signed(ACCOUNT2)), Some(username), None), Error::<Test>::UsernameIsTooShort);
+  });
+}
+
+#[test]
+fn create_profile_should_fail_too_long_username() {
+  let username : Vec<u8> = vec![97; (DEFAULT_USERNAME_MAX_LEN + 1) as usize];
+
+  new_test_ext().execute_with(|| {
+    assert_ok!(_create_default_profile()); // AccountId 1
+    assert_noop!(_create_profile(Some(Origin::signed(ACCOUNT2)), Some(username), None), Error::<Test>::UsernameIsTooLong);
+  });
+}
+
+#[test]
+fn create_profile_should_fail_invalid_username() {
+  let username : Vec<u8> = b"{}sername".to_vec();
+
+  new_test_ext().execute_with(|| {
+    assert_ok!(_create_default_profile()); // AccountId 1
+    assert_noop!(_create_profile(Some(Origin::signed(ACCOUNT2)), Some(username), None), Error::<Test>::UsernameIsNotAlphanumeric);
+  });
+}
+
+#[test]
+fn update_profile_should_work() {
+  new_test_ext().execute_with(|| {
+    assert_ok!(_create_default_profile()); // AccountId 1
+    assert_ok!(_update_profile(None, Some(self::bob_username()), Some(self::blog_ipfs_hash())));
+
+    // Check whether profile updated correctly
+    let profile = Social::social_account_by_id(ACCOUNT1).unwrap().profile.unwrap();
+    assert!(profile.updated.is_some());
+    assert_eq!(profile.username, self::bob_username());
+    assert_eq!(profile.ipfs_hash, self::blog_ipfs_hash());
+
+    // Check storages
+    assert_eq!(Social::account_by_profile_username(self::alice_username()), None);
+    assert_eq!(Social::account_by_profile_username(self::bob_username()), Some(ACCOUNT1));
+
+    // Check whether profile history is written correctly
+    assert_eq!(Social::profile_edit_history(ACCOUNT1, 0)[0].old_data.username, Some(self::alice_username()));
+    assert_eq!(Social::profile_edit_history(ACCOUNT1, 0)[0].old_data.ipfs_hash, Some(self::profile_ipfs_hash()));
+  });
+}
+
+#[test]
+fn update_profile_should_fail_no_social_account() {
+  new_test_ext().execute_with(|| {
+    assert_noop!(_update_profile(None, Some(self::bob_username()), None), Error::<Test>::SocialAccountNotFound);
+  });
+}
+
+#[test]
+fn update_profile_should_fail_no_profile() {
+  new_test_ext().execute_with(|| {
+    assert_ok!(Social::follow_account(Origin::signed(ACCOUNT1), ACCOUNT2));
+    assert_noop!(_update_profile(None, Some(self::bob_username()), None), Error::<Test>::ProfileDoesNotExist);
+  });
+}
+
+#[test]
+fn update_profile_should_fail_nothing_to_update() {
+  new_test_ext().execute_with(|| {
+    assert_ok!(_create_default_profile()); // AccountId 1
+    assert_noop!(_update_profile(None, None, None), Error::<Test>::NoUpdatesInProfile);
+  });
+}
+
+#[test]
+fn update_profile_should_fail_username_is_busy() {
+  new_test_ext().execute_with(|| {
+    assert_ok!(_create_default_profile()); // AccountId 1
+    assert_ok!(_create_profile(Some(Origin::signed(ACCOUNT2)), Some(self::bob_username()), None));
+    assert_noop!(_update_profile(None, Some(self::bob_username()), None), Error::<Test>::UsernameIsBusy);
+  });
+}
+
+#[test]
+fn update_profile_should_fail_too_short_username() {
+  let username : Vec<u8> = vec![97; (DEFAULT_USERNAME_MIN_LEN - 1) as usize];
+
+  new_test_ext().execute_with(|| {
+    assert_ok!(_create_default_profile()); // AccountId 1
+    assert_noop!(_update_profile(None, Some(username), None), Error::<Test>::UsernameIsTooShort);
+  });
+}
+
+#[test]
+fn update_profile_should_fail_too_long_username() {
+  let username : Vec<u8> = vec![97; (DEFAULT_USERNAME_MAX_LEN + 1) as usize];
+
+  new_test_ext().execute_with(|| {
+    assert_ok!(_create_default_profile()); // AccountId 1
+    assert_noop!(_update_profile(None, Some(username), None), Error::<Test>::UsernameIsTooLong);
+  });
+}
+
+#[test]
+fn update_profile_should_fail_invalid_username() {
+  let username : Vec<u8> = b"{}sername".to_vec();
+
+  new_test_ext().execute_with(|| {
+    assert_ok!(_create_default_profile()); // AccountId 1
+    assert_noop!(_update_profile(None, Some(username), None), Error::<Test>::UsernameIsNotAlphanumeric);
+  });
+}
+
+#[test]
+fn update_profile_should_fail_invalid_ipfs_hash() {
+  let ipfs_hash : Vec<u8> = b"QmV9tSDx9UiPeWExXEeH6aoDvmihvx6j".to_vec();
+
+  new_test_ext().execute_with(|| {
+    assert_ok!(_create_default_profile());
+    assert_noop!(_update_profile(None, None, Some(ipfs_hash)), Error::<Test>::IpfsIsIncorrect);
+  });
+}
+
+// Blog following tests
+
+#[test]
+fn follow_blog_should_work() {
+  new_test_ext().execute_with(|| {
+    assert_ok!(_create_default_blog()); // BlogId 1
+
+    assert_ok!(_default_follow_blog()); // Follow BlogId 1 by ACCOUNT2
+
+    assert_eq!(Social::blog_by_id(1).unwrap().followers_count, 2);
+    assert_eq!(Social::blogs_followed_by_account(ACCOUNT2), vec![1]);
+    assert_eq!(Social::blog_followers(1), vec![ACCOUNT1, ACCOUNT2]);
+    assert_eq!(Social::blog_followed_by_account((ACCOUNT2, 1)), true);
+  });
+}
+
+#[test]
+fn follow_blog_should_fail_blog_not_found() {
+  new_test_ext().execute_with(|| {
+    assert_noop!(_default_follow_blog(), Error::<Test>::BlogNotFound);
+  });
+}
+
+#[test]
+fn follow_blog_should_fail_already_following() {
+  new_test_ext().execute_with(|| {
+    assert_ok!(_create_default_blog()); // BlogId 1
+    assert_ok!(_default_follow_blog()); // Follow BlogId 1 by ACCOUNT2
+
+    assert_noop!(_default_follow_blog(), Error::<Test>::AccountIsFollowingBlog);
+  });
+}
+
+#[test]
+fn unfollow_blog_should_work() {
+  new_test_ext().execute_with(|| {
+    assert_ok!(_create_default_blog()); // BlogId 1
+
+    assert_ok!(_default_follow_blog()); // Follow BlogId 1 by ACCOUNT2
+    assert_ok!(_default_unfollow_blog());
+
+    assert_eq!(Social::blog_by_id(1).unwrap().followers_count, 1);
+    assert!(Social::blogs_followed_by_account(ACCOUNT2).is_empty());
+    assert_eq!(Social::blog_followers(1), vec![ACCOUNT1]);
+  });
+}
+
+#[test]
+fn unfollow_blog_should_fail_blog_not_found() {
+  new_test_ext().execute_with(|| {
+    assert_noop!(_default_unfollow_blog(), Error::<Test>::BlogNotFound);
+  });
+}
+
+#[test]
+fn unfollow_blog_should_fail_already_following() {
+  new_test_ext().execute_with(|| {
+    assert_ok!(_create_default_blog()); // BlogId 1
+
+    assert_noop!(_default_unfollow_blog(), Error::<Test>::AccountIsNotFollowingBlog);
+  });
+}
+
+// Account following tests
+
+#[test]
+fn follow_account_should_work() {
+  new_test_ext().execute_with(|| {
+    assert_ok!(_default_follow_account()); // Follow ACCOUNT1 by ACCOUNT2
+
+    assert_eq!(Social::accounts_followed_by_account(ACCOUNT2), vec![ACCOUNT1]);
+    assert_eq!(Social::account_followers(ACCOUNT1), vec![ACCOUNT2]);
+    assert_eq!(Social::account_followed_by_account((ACCOUNT2, ACCOUNT1)), true);
   });
 }
 
-//--------------------------------------------------------------------------------------------------
-
 #[test]
-fn change_comment_score_should_work_upvote() {
+fn follow_account_should_fail_follow_itself() {
   new_test_ext().execute_with(|| {
-    assert_ok!(_create_default_blog());
-    assert_ok!(_create_post(Some(Origin::signed(ACCOUNT2)), None, None, None));
-    assert_ok!(_create_comment(Some(Origin::signed(ACCOUNT2)), None, None, None));
-    assert_ok!(_change_comment_score_by_id(ACCOUNT1, 1, self::scoring_action_upvote_comment()));
-    assert_eq!(Social::comment_by_id(1).unwrap().score, DEFAULT_UPVOTE_COMMENT_ACTION_WEIGHT as i32);
-    assert_eq!(Social::social_account_by_id(ACCOUNT1).unwrap().reputation, 1);
-    assert_eq!(Social::social_account_by_id(ACCOUNT2).unwrap().reputation, 1 + DEFAULT_UPVOTE_COMMENT_ACTION_WEIGHT as u32);
-    assert_eq!(Social::comment_score_by_account((ACCOUNT1, 1, self::scoring_action_upvote_comment())), Some(DEFAULT_UPVOTE_COMMENT_ACTION_WEIGHT));
+    assert_noop!(_follow_account(None, Some(ACCOUNT2)), Error::<Test>::AccountCannotFollowItself);
   });
 }
 
 #[test]
-fn change_comment_score_should_work_downvote() {
+fn follow_account_should_fail_already_followed() {
   new_test_ext().execute_with(|| {
-    assert_ok!(_create_default_blog());
-    assert_ok!(_create_post(Some(Origin::signed(ACCOUNT2)), None, None, None));
-    assert_ok!(_create_comment(Some(Origin::signed(ACCOUNT2)), None, None, None));
-    assert_ok!(_change_comment_score_by_id(ACCOUNT1, 1, self::scoring_action_downvote_comment()));
-    assert_eq!(Social::comment_by_id(1).unwrap().score, DEFAULT_DOWNVOTE_COMMENT_ACTION_WEIGHT as i32);
-    assert_eq!(Social::social_account_by_id(ACCOUNT1).unwrap().reputation, 1);
-    assert_eq!(Social::social_account_by_id(ACCOUNT2).unwrap().reputation, 1);
-    assert_eq!(Social::comment_score_by_account((ACCOUNT1, 1, self::scoring_action_downvote_comment())), Some(DEFAULT_DOWNVOTE_COMMENT_ACTION_WEIGHT));
+    assert_ok!(_default_follow_account());
+
+    assert_noop!(_default_follow_account(), Error::<Test>::AccountIsAlreadyFollowed);
   });
 }
 
+
+
 #[test]
-fn change_comment_score_should_revert_upvote() {
+fn unfollow_account_should_work() {
   new_test_ext().execute_with(|| {
-    assert_ok!(_create_default_blog());
-    assert_ok!(_create_post(Some(Origin::signed(ACCOUNT2)), None, None, None));
-    assert_ok!(_create_comment(Some(Origin::signed(ACCOUNT2)), None, None, None));
-    assert_ok!(_change_comment_score_by_id(ACCOUNT1, 1, self::scoring_action_upvote_comment()));
-    assert_ok!(_change_comment_score_by_id(ACCOUNT1, 1, self::scoring_action_upvote_comment()));
-    assert_eq!(Social::comment_by_id(1).unwrap().score, 0);
-    assert_eq!(Social::social_account_by_id(ACCOUNT1).unwrap().reputation, 1);
-    assert_eq!(Social::social_account_by_id(ACCOUNT2).unwrap().reputation, 1);
-    assert_eq!(Social::comment_score_by_account((ACCOUNT1, 1, self::scoring_action_upvote_comment())), None);
+    assert_ok!(_default_follow_account()); // Follow ACCOUNT1 by ACCOUNT2
+
+    assert_eq!(Social::accounts_followed_by_account(ACCOUNT2), vec![ACCOUNT1]);
+    assert_eq!(Social::account_followers(ACCOUNT1), vec![ACCOUNT2]);
+    assert_eq!(Social::account_followed_by_account((ACCOUNT2, ACCOUNT1)), true);
   });
 }
 
 #[test]
-fn change_comment_score_should_revert_downvote() {
+fn unfollow_account_should_fail_unfollow_itself() {
   new_test_ext().execute_with(|| {
-    assert_ok!(_create_default_blog());
-    assert_ok!(_create_post(Some(Origin::signed(ACCOUNT2)), None, None, None));
-    assert_ok!(_create_comment(Some(Origin::signed(ACCOUNT2)), None, None, None));
-    assert_ok!(_change_comment_score_by_id(ACCOUNT1, 1, self::scoring_action_downvote_comment()));
-    assert_ok!(_change_comment_score_by_id(ACCOUNT1, 1, self::scoring_action_downvote_comment()));
-    assert_eq!(Social::comment_by_id(1).unwrap().score, 0);
-    assert_eq!(Social::social_account_by_id(ACCOUNT1).unwrap().reputation, 1);
-    assert_eq!(Social::social_account_by_id(ACCOUNT2).unwrap().reputation, 1);
-    assert_eq!(Social::comment_score_by_account((ACCOUNT1, 1, self::scoring_action_downvote_comment())), None);
+    assert_noop!(_unfollow_account(None, Some(ACCOUNT2)), Error::<Test>::AccountCannotUnfollowItself);
   });
 }
 
 #[test]
-fn change_comment_score_check_cancel_upvote() {
+fn unfollow_account_should_fail_is_not_followed() {
   new_test_ext().execute_with(|| {
-    assert_ok!(_create_default_blog());
-    assert_ok!(_create_post(Some(Origin::signed(ACCOUNT2)), None, None, None));
-    assert_ok!(_create_comment(Some(Origin::signed(ACCOUNT2)), None, None, None));
-
-    assert_ok!(_change_comment_score_by_id(ACCOUNT1, 1, self::scoring_action_upvote_comment()));
+    assert_ok!(_default_follow_account());
+    assert_ok!(_default_unfollow_account());
 
-    assert_ok!(_change_comment_score_by_id(ACCOUNT1, 1, self::scoring_action_downvote_comment()));
-    assert_eq!(Social::comment_by_id(1).unwrap().score, DEFAULT_DOWNVOTE_COMMENT_ACTION_WEIGHT as i32);
-    assert_eq!(Social::social_account_by_id(ACCOUNT1).unwrap().reputation, 1);
-    assert_eq!(Social::social_account_by_id(ACCOUNT2).unwrap().reputation, 1);
-    assert_eq!(Social::comment_score_by_account((ACCOUNT1, 1, self::scoring_action_upvote_comment())), None);
-    assert_eq!(Social::comment_score_by_account((ACCOUNT1, 1, self::scoring_action_downvote_comment())), Some(DEFAULT_DOWNVOTE_COMMENT_ACTION_WEIGHT));
+    assert_noop!(_default_unfollow_account(), Error::<Test>::AccountIsNotFollowed);
   });
 }
 
+// Invariants tests
+
 #[test]
-fn change_comment_score_check_cancel_downvote() {
+fn check_invariants_should_detect_counter_mismatch() {
   new_test_ext().execute_with(|| {
-    assert_ok!(_create_default_blog());
-    assert_ok!(_create_post(Some(Origin::signed(ACCOUNT2)), None, None, None));
-    assert_ok!(_create_comment(Some(Origin::signed(ACCOUNT2)), None, None, None));
-
-    assert_ok!(_change_comment_score_by_id(ACCOUNT1, 1, self::scoring_action_downvote_comment()));
+    assert_ok!(_create_default_blog()); // BlogId 1
+    assert_ok!(_create_default_post()); // PostId 1
 
-    assert_ok!(_change_comment_score_by_id(ACCOUNT1, 1, self::scoring_action_upvote_comment()));
-    assert_eq!(Social::comment_by_id(1).unwrap().score, DEFAULT_UPVOTE_COMMENT_ACTION_WEIGHT as i32);
-    assert_eq!(Social::social_account_by_id(ACCOUNT1).unwrap().reputation, 1);
-    assert_eq!(Social::social_account_by_id(ACCOUNT2).unwrap().reputation, 1 + DEFAULT_UPVOTE_COMMENT_ACTION_WEIGHT as u32);
-    assert_eq!(Social::comment_score_by_account((ACCOUNT1, 1, self::scoring_action_downvote_comment())), None);
-    assert_eq!(Social::comment_score_by_account((ACCOUNT1, 1, self::scoring_action_upvote_comment())), Some(DEFAULT_UPVOTE_COMMENT_ACTION_WEIGHT));
+    let mut blog = Social::blog_by_id(1).unwrap();
+    blog.posts_count = 2;
+    <BlogById<Test>>::insert(1, blog);
+
+    assert_eq!(Social::check_invariants(), vec![
+      invariants::InvariantViolation::CounterMismatch {
+        entity: invariants::InvariantEntity::Blog(1),
+        counter: "posts_count",
+        stored: 2,
+        actual: 1,
+      }
+    ]);
   });
 }
 
 #[test]
-fn change_comment_score_should_fail_comment_not_found() {
+fn check_invariants_should_detect_dangling_index_entry() {
   new_test_ext().execute_with(|| {
-    assert_ok!(_create_default_blog());
-    assert_ok!(_create_default_post());
-    assert_ok!(_create_comment(Some(Origin::signed(ACCOUNT2)), None, None, None));
-    assert_noop!(_change_comment_score_by_id(ACCOUNT1, 2, self::scoring_action_upvote_comment()), Error::<Test>::CommentNotFound);
+    assert_ok!(_create_default_blog()); // BlogId 1
+    assert_ok!(_create_default_post()); // PostId 1
+    assert_ok!(_create_default_comment()); // CommentId 1
+
+    CommentIdsByPostId::mutate(1, |ids| ids.push(2));
+    let mut post = Social::post_by_id(1).unwrap();
+    post.comments_count = 2;
+    <PostById<Test>>::insert(1, post);
+
+    assert_eq!(Social::check_invariants(), vec![
+      invariants::InvariantViolation::DanglingIndexEntry {
+        entity: invariants::InvariantEntity::Post(1),
+        index: "CommentIdsByPostId",
+        item: invariants::InvariantEntity::Comment(2),
+      }
+    ]);
   });
 }
 
-// Shares tests
-
 #[test]
-fn share_post_should_work() {
+fn check_invariants_should_detect_stale_reaction_count() {
   new_test_ext().execute_with(|| {
     assert_ok!(_create_default_blog()); // BlogId 1
-    assert_ok!(_create_blog(Some(Origin::signed(ACCOUNT2)), Some(b"blog2_slug".to_vec()), None)); // BlogId 2 by ACCOUNT2
     assert_ok!(_create_default_post()); // PostId 1
-    assert_ok!(_create_post(
-      Some(Origin::signed(ACCOUNT2)),
-      Some(2),
-      Some(vec![]),
-      Some(self::extension_shared_post(1))
-    )); // Share PostId 1 on BlogId 2 by ACCOUNT2
+    assert_ok!(_create_post_reaction(Some(Origin::signed(ACCOUNT2)), None, None)); // ReactionId 1
 
-    // Check storages
-    assert_eq!(Social::post_ids_by_blog_id(1), vec![1]);
-    assert_eq!(Social::post_ids_by_blog_id(2), vec![2]);
-    assert_eq!(Social::next_post_id(), 3);
+    // A count of a kind that has no reactions on the post:
+    <ReactionCountByTarget<Test>>::insert((ReactionTarget::Post(1), self::reaction_downvote()), 1);
+
+    assert_eq!(Social::check_invariants(), vec![
+      invariants::InvariantViolation::CounterMismatch {
+        entity: invariants::InvariantEntity::Post(1),
+        counter: "reaction_count",
+        stored: 1,
+        actual: 0,
+      }
+    ]);
+  });
+}
 
-    assert_eq!(Social::post_shares_by_account((ACCOUNT2, 1)), 1);
-    assert_eq!(Social::shared_post_ids_by_original_post_id(1), vec![2]);
+// Genesis config tests
 
-    // Check whether data stored correctly
-    assert_eq!(Social::post_by_id(1).unwrap().shares_count, 1);
+#[test]
+fn genesis_config_should_work() {
+  new_test_ext_with_config(GenesisConfig {
+    slug_min_len: 3,
+    slug_max_len: 30,
+    max_comment_depth: 5,
+    follow_blog_action_weight: 10,
+    downvote_post_action_weight: -5,
+    ..Default::default()
+  }).execute_with(|| {
+    assert_eq!(Social::slug_min_len(), 3);
+    assert_eq!(Social::slug_max_len(), 30);
+    assert_eq!(Social::max_comment_depth(), 5);
+    assert_eq!(Social::follow_blog_action_weight(), 10);
+    assert_eq!(Social::downvote_post_action_weight(), -5);
+
+    // Items that were not configured should keep their default values
+    assert_eq!(Social::ipfs_hash_len(), DEFAULT_IPFS_HASH_LEN);
+    assert_eq!(Social::upvote_post_action_weight(), DEFAULT_UPVOTE_POST_ACTION_WEIGHT);
+  });
+}
 
-    let shared_post = Social::post_by_id(2).unwrap();
+#[test]
+#[should_panic(expected = "SlugMinLenGreaterThanMax")]
+fn genesis_config_should_fail_slug_min_len_greater_than_max() {
+  new_test_ext_with_config(GenesisConfig {
+    slug_min_len: 20,
+    slug_max_len: 10,
+    ..Default::default()
+  });
+}
 
-    assert_eq!(shared_post.blog_id, 2);
-    assert_eq!(shared_post.created.account, ACCOUNT2);
-    assert!(shared_post.ipfs_hash.is_empty());
-    assert_eq!(shared_post.extension, self::extension_shared_post(1));
+#[test]
+#[should_panic(expected = "UsernameMinLenGreaterThanMax")]
+fn genesis_config_should_fail_username_min_len_greater_than_max() {
+  new_test_ext_with_config(GenesisConfig {
+    username_min_len: 20,
+    username_max_len: 10,
+    ..Default::default()
   });
 }
 
 #[test]
-fn share_post_should_work_share_own_post() {
+#[should_panic(expected = "PositiveDownvoteWeight")]
+fn genesis_config_should_fail_positive_downvote_weight() {
+  new_test_ext_with_config(GenesisConfig {
+    downvote_comment_action_weight: 2,
+    ..Default::default()
+  });
+}
+
+#[test]
+#[should_panic(expected = "ZeroMaxEditHistoryRecords")]
+fn genesis_config_should_fail_zero_max_edit_history_records() {
+  new_test_ext_with_config(GenesisConfig {
+    max_edit_history_records: 0,
+    ..Default::default()
+  });
+}
+
+// Reputation audit tests
+
+#[test]
+fn audit_reputation_should_be_consistent() {
   new_test_ext().execute_with(|| {
     assert_ok!(_create_default_blog()); // BlogId 1
     assert_ok!(_create_default_post()); // PostId 1
-    assert_ok!(_create_post(
-      Some(Origin::signed(ACCOUNT1)),
-      Some(1),
-      Some(vec![]),
-      Some(self::extension_shared_post(1))
-    )); // Share PostId 1
-
-    // Check storages
-    assert_eq!(Social::post_ids_by_blog_id(1), vec![1, 2]);
-    assert_eq!(Social::next_post_id(), 3);
-
-    assert_eq!(Social::post_shares_by_account((ACCOUNT1, 1)), 1);
-    assert_eq!(Social::shared_post_ids_by_original_post_id(1), vec![2]);
+    assert_ok!(_create_post_reaction(Some(Origin::signed(ACCOUNT2)), None, None)); // ReactionId 1 by ACCOUNT2
 
-    // Check whether data stored correctly
-    assert_eq!(Social::post_by_id(1).unwrap().shares_count, 1);
+    let audit = Social::audit_reputation(ACCOUNT1);
+    assert!(audit.is_consistent());
+    assert_eq!(audit.stored_reputation, 1 + DEFAULT_UPVOTE_POST_ACTION_WEIGHT as u32);
+    assert_eq!(audit.ledger_entries_count, 1);
+    assert_eq!(
+      Social::reputation_diff_keys_by_account(ACCOUNT1),
+      vec![(ACCOUNT2, self::scoring_action_upvote_post(), ScoredEntity::Post(1))]
+    );
 
-    let shared_post = Social::post_by_id(2).unwrap();
-    assert_eq!(shared_post.blog_id, 1);
-    assert_eq!(shared_post.created.account, ACCOUNT1);
-    assert!(shared_post.ipfs_hash.is_empty());
-    assert_eq!(shared_post.extension, self::extension_shared_post(1));
+    assert_ok!(_delete_post_reaction(Some(Origin::signed(ACCOUNT2)), None));
+    assert!(Social::reputation_diff_keys_by_account(ACCOUNT1).is_empty());
+    assert!(Social::audit_reputation(ACCOUNT1).is_consistent());
   });
 }
 
 #[test]
-fn share_post_should_change_score() {
+fn audit_reputation_should_keep_votes_of_one_scorer_on_several_posts() {
   new_test_ext().execute_with(|| {
     assert_ok!(_create_default_blog()); // BlogId 1
-    assert_ok!(_create_blog(Some(Origin::signed(ACCOUNT2)), Some(b"blog2_slug".to_vec()), None)); // BlogId 2 by ACCOUNT2
     assert_ok!(_create_default_post()); // PostId 1
-    assert_ok!(_create_post(
-      Some(Origin::signed(ACCOUNT2)),
-      Some(2),
-      Some(vec![]),
-      Some(self::extension_shared_post(1))
-    )); // Share PostId 1 on BlogId 2 by ACCOUNT2
+    assert_ok!(_create_default_post()); // PostId 2
+    assert_ok!(_create_post_reaction(Some(Origin::signed(ACCOUNT2)), Some(1), None)); // ReactionId 1
+    assert_ok!(_create_post_reaction(Some(Origin::signed(ACCOUNT2)), Some(2), None)); // ReactionId 2
+
+    let reputation = 1 + 2 * DEFAULT_UPVOTE_POST_ACTION_WEIGHT as u32;
+    let audit = Social::audit_reputation(ACCOUNT1);
+    assert!(audit.is_consistent());
+    assert_eq!(audit.stored_reputation, reputation);
+    assert_eq!(audit.ledger_entries_count, 2);
+
+    assert_ok!(Social::recalculate_reputation(system::RawOrigin::Root.into(), ACCOUNT1));
+    assert_eq!(Social::social_account_by_id(ACCOUNT1).unwrap().reputation, reputation);
+
+    assert_ok!(_delete_post_reaction(Some(Origin::signed(ACCOUNT2)), Some(1)));
+    assert_eq!(Social::social_account_by_id(ACCOUNT1).unwrap().reputation, reputation - DEFAULT_UPVOTE_POST_ACTION_WEIGHT as u32);
+    assert!(Social::audit_reputation(ACCOUNT1).is_consistent());
+  });
+}
 
-    assert_eq!(Social::post_by_id(1).unwrap().score, DEFAULT_SHARE_POST_ACTION_WEIGHT as i32);
-    assert_eq!(Social::social_account_by_id(ACCOUNT1).unwrap().reputation, 1 + DEFAULT_SHARE_POST_ACTION_WEIGHT as u32);
-    assert_eq!(Social::post_score_by_account((ACCOUNT2, 1, self::scoring_action_share_post())), Some(DEFAULT_SHARE_POST_ACTION_WEIGHT));
+#[test]
+fn follow_account_should_keep_reputation_consistent() {
+  new_test_ext().execute_with(|| {
+    assert_ok!(_default_follow_account()); // Follow ACCOUNT1 by ACCOUNT2
+
+    let followed_account = Social::social_account_by_id(ACCOUNT1).unwrap();
+    assert_eq!(followed_account.followers_count, 1);
+    assert_eq!(followed_account.reputation, 1 + DEFAULT_FOLLOW_ACCOUNT_ACTION_WEIGHT as u32);
+    assert_eq!(Social::social_account_by_id(ACCOUNT2).unwrap().following_accounts_count, 1);
+
+    let audit = Social::audit_reputation(ACCOUNT1);
+    assert!(audit.is_consistent());
+    assert_eq!(audit.ledger_entries_count, 1);
   });
 }
 
 #[test]
-fn share_post_should_not_change_score() {
+fn unfollow_account_should_revert_reputation() {
   new_test_ext().execute_with(|| {
-    assert_ok!(_create_default_blog()); // BlogId 1
-    assert_ok!(_create_default_post()); // PostId 1
-    assert_ok!(_create_post(
-      Some(Origin::signed(ACCOUNT1)),
-      Some(1),
-      Some(vec![]),
-      Some(self::extension_shared_post(1))
-    )); // Share PostId
+    assert_ok!(_default_follow_account()); // Follow ACCOUNT1 by ACCOUNT2
+    assert_eq!(Social::social_account_by_id(ACCOUNT1).unwrap().reputation, 1 + DEFAULT_FOLLOW_ACCOUNT_ACTION_WEIGHT as u32);
 
-    assert_eq!(Social::post_by_id(1).unwrap().score, 0);
+    assert_ok!(_default_unfollow_account());
     assert_eq!(Social::social_account_by_id(ACCOUNT1).unwrap().reputation, 1);
-    assert_eq!(Social::post_score_by_account((ACCOUNT1, 1, self::scoring_action_share_post())), None);
+    assert!(Social::audit_reputation(ACCOUNT1).is_consistent());
   });
 }
 
 #[test]
-fn share_post_should_fail_original_post_not_found() {
+fn recalculate_reputation_should_repair_drift() {
   new_test_ext().execute_with(|| {
     assert_ok!(_create_default_blog()); // BlogId 1
-    assert_ok!(_create_blog(Some(Origin::signed(ACCOUNT2)), Some(b"blog2_slug".to_vec()), None)); // BlogId 2 by ACCOUNT2
-    // Skipped creating PostId 1
-    assert_noop!(_create_post(
-      Some(Origin::signed(ACCOUNT2)),
-      Some(2),
-      Some(vec![]),
-      Some(self::extension_shared_post(1))),
+    assert_ok!(_create_default_post()); // PostId 1
+    assert_ok!(_create_post_reaction(Some(Origin::signed(ACCOUNT2)), None, None)); // ReactionId 1 by ACCOUNT2
+    assert_ok!(_create_post_reaction(Some(Origin::signed(ACCOUNT3)), None, Some(self::reaction_downvote()))); // ReactionId 2 by ACCOUNT3
 
-    Error::<Test>::OriginalPostNotFound);
+    // Reverting the upvote clamps reputation to 1, so reverting the downvote afterwards
+    // leaves reputation that is not backed by the ledger:
+    assert_ok!(_delete_post_reaction(Some(Origin::signed(ACCOUNT2)), None));
+    assert_ok!(_delete_post_reaction(Some(Origin::signed(ACCOUNT3)), None));
+
+    let audit = Social::audit_reputation(ACCOUNT1);
+    assert!(!audit.is_consistent());
+    assert_eq!(audit.expected_reputation, 1);
+    assert_ne!(audit.stored_reputation, 1);
+
+    assert_ok!(Social::recalculate_reputation(system::RawOrigin::Root.into(), ACCOUNT1));
+
+    assert_eq!(Social::social_account_by_id(ACCOUNT1).unwrap().reputation, 1);
+    assert!(Social::audit_reputation(ACCOUNT1).is_consistent());
   });
 }
 
 #[test]
-fn share_post_should_fail_share_shared_post() {
+fn recalculate_reputation_should_fail_not_root() {
   new_test_ext().execute_with(|| {
-    assert_ok!(_create_default_blog()); // BlogId 1
-    assert_ok!(_create_blog(Some(Origin::signed(ACCOUNT2)), Some(b"blog2_slug".to_vec()), None)); // BlogId 2 by ACCOUNT2
-    assert_ok!(_create_default_post());
-    assert_ok!(_create_post(
-      Some(Origin::signed(ACCOUNT2)),
-      Some(2),
-      Some(vec![]),
-      Some(self::extension_shared_post(1)))
+    assert_noop!(
+      Social::recalculate_reputation(Origin::signed(ACCOUNT1), ACCOUNT1),
+      DispatchError::BadOrigin
     );
+  });
+}
 
-    // Try to share post with extension SharedPost
-    assert_noop!(_create_post(
-      Some(Origin::signed(ACCOUNT1)),
-      Some(1),
-      Some(vec![]),
-      Some(self::extension_shared_post(2))),
+// Reputation tiers tests
 
-    Error::<Test>::CannotShareSharedPost);
+#[test]
+fn create_blog_should_fail_insufficient_reputation() {
+  new_test_ext().execute_with(|| {
+    MinReputationToCreateBlog::put(10);
+
+    assert_noop!(_create_default_blog(), Error::<Test>::InsufficientReputationToCreateBlog);
   });
 }
 
 #[test]
-fn share_comment_should_work() {
+fn create_post_reaction_should_fail_insufficient_reputation_to_downvote() {
   new_test_ext().execute_with(|| {
     assert_ok!(_create_default_blog()); // BlogId 1
-    assert_ok!(_create_blog(Some(Origin::signed(ACCOUNT2)), Some(b"blog2_slug".to_vec()), None)); // BlogId 2 by ACCOUNT2
     assert_ok!(_create_default_post()); // PostId 1
-    assert_ok!(_create_default_comment()); // CommentId 1
-    assert_ok!(_create_post(
-      Some(Origin::signed(ACCOUNT2)),
-      Some(2),
-      Some(vec![]),
-      Some(self::extension_shared_comment(1))
-    )); // Share CommentId 1 on BlogId 2 by ACCOUNT2
-
-    // Check storages
-    assert_eq!(Social::post_ids_by_blog_id(1), vec![1]);
-    assert_eq!(Social::post_ids_by_blog_id(2), vec![2]);
-    assert_eq!(Social::next_post_id(), 3);
-
-    assert_eq!(Social::comment_shares_by_account((ACCOUNT2, 1)), 1);
-    assert_eq!(Social::shared_post_ids_by_original_comment_id(1), vec![2]);
-
-    // Check whether data stored correctly
-    assert_eq!(Social::comment_by_id(1).unwrap().shares_count, 1);
+    MinReputationToDownvote::put(10);
 
-    let shared_post = Social::post_by_id(2).unwrap();
+    assert_noop!(
+      _create_post_reaction(Some(Origin::signed(ACCOUNT2)), None, Some(self::reaction_downvote())),
+      Error::<Test>::InsufficientReputationToDownvote
+    );
 
-    assert_eq!(shared_post.blog_id, 2);
-    assert_eq!(shared_post.created.account, ACCOUNT2);
-    assert!(shared_post.ipfs_hash.is_empty());
-    assert_eq!(shared_post.extension, self::extension_shared_comment(1));
+    // Upvoting is not restricted
+    assert_ok!(_create_post_reaction(Some(Origin::signed(ACCOUNT2)), None, None)); // ReactionId 1 by ACCOUNT2
+
+    // The restriction cannot be bypassed by changing the kind of reaction
+    assert_noop!(
+      _update_post_reaction(Some(Origin::signed(ACCOUNT2)), None, Some(self::reaction_downvote())),
+      Error::<Test>::InsufficientReputationToDownvote
+    );
   });
 }
 
 #[test]
-fn share_comment_should_change_score() {
+fn create_comment_reaction_should_fail_insufficient_reputation_to_downvote() {
   new_test_ext().execute_with(|| {
     assert_ok!(_create_default_blog()); // BlogId 1
-    assert_ok!(_create_blog(Some(Origin::signed(ACCOUNT2)), Some(b"blog2_slug".to_vec()), None)); // BlogId 2 by ACCOUNT2
     assert_ok!(_create_default_post()); // PostId 1
     assert_ok!(_create_default_comment()); // CommentId 1
-    assert_ok!(_create_post(
-      Some(Origin::signed(ACCOUNT2)),
-      Some(2),
-      Some(vec![]),
-      Some(self::extension_shared_comment(1))
-    )); // Share CommentId 1 on BlogId 2 by ACCOUNT2
+    MinReputationToDownvote::put(10);
 
-    assert_eq!(Social::comment_by_id(1).unwrap().score, DEFAULT_SHARE_COMMENT_ACTION_WEIGHT as i32);
-    assert_eq!(Social::social_account_by_id(ACCOUNT1).unwrap().reputation, 1 + DEFAULT_SHARE_COMMENT_ACTION_WEIGHT as u32);
-    assert_eq!(Social::comment_score_by_account((ACCOUNT2, 1, self::scoring_action_share_comment())), Some(DEFAULT_SHARE_COMMENT_ACTION_WEIGHT));
+    assert_noop!(
+      _create_comment_reaction(Some(Origin::signed(ACCOUNT2)), None, Some(self::reaction_downvote())),
+      Error::<Test>::InsufficientReputationToDownvote
+    );
   });
 }
 
 #[test]
-fn share_comment_should_fail_original_comment_not_found() {
+fn grant_blog_role_should_fail_insufficient_reputation_to_moderate() {
   new_test_ext().execute_with(|| {
     assert_ok!(_create_default_blog()); // BlogId 1
-    assert_ok!(_create_blog(Some(Origin::signed(ACCOUNT2)), Some(b"blog2_slug".to_vec()), None)); // BlogId 2 by ACCOUNT2
-    assert_ok!(_create_default_post()); // PostId 1
-    // Skipped creating CommentId 1
-    assert_noop!(_create_post(
-      Some(Origin::signed(ACCOUNT2)),
-      Some(2),
-      Some(vec![]),
-      Some(self::extension_shared_comment(1))),
+    MinReputationToModerate::put(10);
 
-    Error::<Test>::OriginalCommentNotFound);
+    assert_noop!(_grant_blog_role(None, None, BlogRole::Moderator), Error::<Test>::InsufficientReputationToModerate);
+    assert_noop!(_grant_blog_role(None, None, BlogRole::Editor), Error::<Test>::InsufficientReputationToModerate);
+
+    // Other roles are not restricted
+    assert_ok!(_grant_blog_role(None, None, BlogRole::Commenter));
   });
 }
 
-// Profiles tests
-
 #[test]
-fn create_profile_should_work() {
+fn set_reputation_tiers_should_work() {
   new_test_ext().execute_with(|| {
-    assert_ok!(_create_default_profile()); // AccountId 1
+    let tiers = ReputationTiers { downvote: 5, create_blog: 10, moderate: 20 };
+    assert_ok!(Social::set_reputation_tiers(system::RawOrigin::Root.into(), tiers.clone()));
 
-    let profile = Social::social_account_by_id(ACCOUNT1).unwrap().profile.unwrap();
-    assert_eq!(profile.created.account, ACCOUNT1);
-    // TODO: Fix unresolved error
-    // assert_eq!(profile.updated, None);
-    assert_eq!(profile.username, self::alice_username());
-    assert_eq!(profile.ipfs_hash, self::profile_ipfs_hash());
-    assert!(profile.edit_history.is_empty());
-    assert_eq!(Social::account_by_profile_username(self::alice_username()), Some(ACCOUNT1));
+    assert_eq!(Social::reputation_tiers(), tiers);
+    assert_noop!(
+      Social::set_reputation_tiers(Origin::signed(ACCOUNT1), tiers),
+      DispatchError::BadOrigin
+    );
   });
 }
 
 #[test]
-fn create_profile_should_fail_profile_exists() {
+fn set_reputation_tiers_should_fail_invalid_tiers() {
   new_test_ext().execute_with(|| {
-    assert_ok!(_create_default_profile()); // AccountId 1
-    assert_noop!(_create_default_profile(), Error::<Test>::ProfileAlreadyExists);
+    assert_noop!(
+      Social::set_reputation_tiers(system::RawOrigin::Root.into(), ReputationTiers { downvote: 0, create_blog: 10, moderate: 20 }),
+      Error::<Test>::ZeroReputationTier
+    );
+    assert_noop!(
+      Social::set_reputation_tiers(system::RawOrigin::Root.into(), ReputationTiers { downvote: 5, create_blog: 30, moderate: 20 }),
+      Error::<Test>::ModerateTierBelowOthers
+    );
   });
 }
 
 #[test]
-fn create_profile_should_fail_invalid_ipfs_hash() {
-  let ipfs_hash : Vec<u8> = b"QmV9tSDx9UiPeWExXEeH6aoDvmihvx6j".to_vec();
-
+fn set_score_half_life_should_work() {
   new_test_ext().execute_with(|| {
-    assert_noop!(_create_profile(None, None, Some(ipfs_hash)), Error::<Test>::IpfsIsIncorrect);
+    assert_ok!(Social::set_score_half_life(system::RawOrigin::Root.into(), 100));
+    assert_eq!(Social::score_half_life(), 100);
+
+    assert_noop!(
+      Social::set_score_half_life(Origin::signed(ACCOUNT1), 200),
+      DispatchError::BadOrigin
+    );
+    assert_noop!(
+      Social::set_score_half_life(system::RawOrigin::Root.into(), 0),
+      Error::<Test>::ZeroScoreHalfLife
+    );
   });
 }
 
+// Governance tests
+
 #[test]
-fn create_profile_should_fail_username_is_busy() {
+fn set_scoring_weights_should_work() {
   new_test_ext().execute_with(|| {
-    assert_ok!(_create_default_profile()); // AccountId 1
-    assert_noop!(_create_profile(Some(Origin::signed(ACCOUNT2)), None, None), Error::<Test>::UsernameIsBusy);
+    assert_ok!(Social::set_scoring_weights(system::RawOrigin::Root.into(), self::scoring_weights()));
+
+    assert_eq!(Social::scoring_weights(), self::scoring_weights());
+    assert_eq!(Social::weight_of_scoring_action(self::scoring_action_upvote_post()), 10);
+    assert_eq!(Social::weight_of_scoring_action(self::scoring_action_follow_account()), 2);
   });
 }
 
 #[test]
-fn create_profile_should_fail_too_short_username() {
-  let username : Vec<u8> = vec![97; (DEFAULT_USERNAME_MIN_LEN - 1) as usize];
-
+fn set_scoring_weights_should_fail_not_root() {
   new_test_ext().execute_with(|| {
-    assert_ok!(_create_default_profile()); // AccountId 1
-    assert_noop!(_create_profile(Some(Origin::signed(ACCOUNT2)), Some(username), None), Error::<Test>::UsernameIsTooShort);
+    assert_noop!(
+      Social::set_scoring_weights(Origin::signed(ACCOUNT1), self::scoring_weights()),
+      DispatchError::BadOrigin
+    );
   });
 }
 
 #[test]
-fn create_profile_should_fail_too_long_username() {
-  let username : Vec<u8> = vec![97; (DEFAULT_USERNAME_MAX_LEN + 1) as usize];
-
+fn set_scoring_weights_should_fail_invalid_weights() {
   new_test_ext().execute_with(|| {
-    assert_ok!(_create_default_profile()); // AccountId 1
-    assert_noop!(_create_profile(Some(Origin::signed(ACCOUNT2)), Some(username), None), Error::<Test>::UsernameIsTooLong);
+    let mut weights = self::scoring_weights();
+    weights.upvote_comment = -1;
+    assert_noop!(
+      Social::set_scoring_weights(system::RawOrigin::Root.into(), weights),
+      Error::<Test>::NegativePositiveActionWeight
+    );
+
+    let mut weights = self::scoring_weights();
+    weights.downvote_post = 1;
+    assert_noop!(
+      Social::set_scoring_weights(system::RawOrigin::Root.into(), weights),
+      Error::<Test>::PositiveDownvoteWeight
+    );
   });
 }
 
 #[test]
-fn create_profile_should_fail_invalid_username() {
-  let username : Vec<u8> = b"{}sername".to_vec();
-
+fn set_content_limits_should_work() {
   new_test_ext().execute_with(|| {
-    assert_ok!(_create_default_profile()); // AccountId 1
-    assert_noop!(_create_profile(Some(Origin::signed(ACCOUNT2)), Some(username), None), Error::<Test>::UsernameIsNotAlphanumeric);
+    assert_ok!(Social::set_content_limits(system::RawOrigin::Root.into(), self::content_limits()));
+
+    assert_eq!(Social::content_limits(), self::content_limits());
+    assert_eq!(Social::slug_min_len(), 3);
+    assert_eq!(Social::max_comment_depth(), 5);
   });
 }
 
 #[test]
-fn update_profile_should_work() {
+fn set_content_limits_should_fail_not_root() {
   new_test_ext().execute_with(|| {
-    assert_ok!(_create_default_profile()); // AccountId 1
-    assert_ok!(_update_profile(None, Some(self::bob_username()), Some(self::blog_ipfs_hash())));
-
-    // Check whether profile updated correctly
-    let profile = Social::social_account_by_id(ACCOUNT1).unwrap().profile.unwrap();
-    assert!(profile.updated.is_some());
-    assert_eq!(profile.username, self::bob_username());
-    assert_eq!(profile.ipfs_hash, self::blog_ipfs_hash());
-
-    // Check storages
-    assert_eq!(Social::account_by_profile_username(self::alice_username()), None);
-    assert_eq!(Social::account_by_profile_username(self::bob_username()), Some(ACCOUNT1));
-
-    // Check whether profile history is written correctly
-    assert_eq!(profile.edit_history[0].old_data.username, Some(self::alice_username()));
-    assert_eq!(profile.edit_history[0].old_data.ipfs_hash, Some(self::profile_ipfs_hash()));
+    assert_noop!(
+      Social::set_content_limits(Origin::signed(ACCOUNT1), self::content_limits()),
+      DispatchError::BadOrigin
+    );
   });
 }
 
 #[test]
-fn update_profile_should_fail_no_social_account() {
+fn set_content_limits_should_fail_invalid_limits() {
   new_test_ext().execute_with(|| {
-    assert_noop!(_update_profile(None, Some(self::bob_username()), None), Error::<Test>::SocialAccountNotFound);
+    let mut limits = self::content_limits();
+    limits.slug_min_len = limits.slug_max_len + 1;
+    assert_noop!(
+      Social::set_content_limits(system::RawOrigin::Root.into(), limits),
+      Error::<Test>::SlugMinLenGreaterThanMax
+    );
+
+    let mut limits = self::content_limits();
+    limits.ipfs_hash_len = 0;
+    assert_noop!(
+      Social::set_content_limits(system::RawOrigin::Root.into(), limits),
+      Error::<Test>::ZeroIpfsHashLen
+    );
+
+    let mut limits = self::content_limits();
+    limits.max_edit_history_records = 0;
+    assert_noop!(
+      Social::set_content_limits(system::RawOrigin::Root.into(), limits),
+      Error::<Test>::ZeroMaxEditHistoryRecords
+    );
   });
 }
 
+// Pagination tests
+
 #[test]
-fn update_profile_should_fail_no_profile() {
-  new_test_ext().execute_with(|| {
-    assert_ok!(Social::follow_account(Origin::signed(ACCOUNT1), ACCOUNT2));
-    assert_noop!(_update_profile(None, Some(self::bob_username()), None), Error::<Test>::ProfileDoesNotExist);
-  });
+fn paginate_should_work() {
+  let items: Vec<u32> = (0..10).collect();
+
+  assert_eq!(Social::paginate(&items, 0, 3), vec![0, 1, 2]);
+  assert_eq!(Social::paginate(&items, 8, 3), vec![8, 9]);
+  assert!(Social::paginate(&items, 10, 3).is_empty());
+  assert!(Social::paginate(&items, 0, 0).is_empty());
+
+  let items: Vec<u32> = (0..MAX_PAGE_LIMIT + 10).collect();
+  assert_eq!(Social::paginate(&items, 0, u32::max_value()).len(), MAX_PAGE_LIMIT as usize);
 }
 
 #[test]
-fn update_profile_should_fail_nothing_to_update() {
+fn posts_by_blog_should_work() {
   new_test_ext().execute_with(|| {
-    assert_ok!(_create_default_profile()); // AccountId 1
-    assert_noop!(_update_profile(None, None, None), Error::<Test>::NoUpdatesInProfile);
+    assert_ok!(_create_default_blog()); // BlogId 1
+    assert_ok!(_create_default_post()); // PostId 1
+    assert_ok!(_create_default_post()); // PostId 2
+    assert_ok!(_create_default_post()); // PostId 3
+
+    let post_ids = |posts: Vec<Post<Test>>| posts.into_iter().map(|post| post.id).collect::<Vec<_>>();
+    assert_eq!(post_ids(Social::posts_by_blog(1, 0, 2)), vec![1, 2]);
+    assert_eq!(post_ids(Social::posts_by_blog(1, 2, 2)), vec![3]);
+    assert!(Social::posts_by_blog(2, 0, 2).is_empty());
   });
 }
 
 #[test]
-fn update_profile_should_fail_username_is_busy() {
+fn comments_by_post_should_work() {
   new_test_ext().execute_with(|| {
-    assert_ok!(_create_default_profile()); // AccountId 1
-    assert_ok!(_create_profile(Some(Origin::signed(ACCOUNT2)), Some(self::bob_username()), None));
-    assert_noop!(_update_profile(None, Some(self::bob_username()), None), Error::<Test>::UsernameIsBusy);
+    assert_ok!(_create_default_blog()); // BlogId 1
+    assert_ok!(_create_default_post()); // PostId 1
+    assert_ok!(_create_default_comment()); // CommentId 1
+    assert_ok!(_create_comment(None, None, Some(1), None)); // CommentId 2
+
+    let comments = Social::comments_by_post(1, 1, 10);
+    assert_eq!(comments.len(), 1);
+    assert_eq!(comments[0].id, 2);
+    assert_eq!(comments[0].parent_id, Some(1));
   });
 }
 
 #[test]
-fn update_profile_should_fail_too_short_username() {
-  let username : Vec<u8> = vec![97; (DEFAULT_USERNAME_MIN_LEN - 1) as usize];
-
+fn followers_should_be_paged() {
   new_test_ext().execute_with(|| {
-    assert_ok!(_create_default_profile()); // AccountId 1
-    assert_noop!(_update_profile(None, Some(username), None), Error::<Test>::UsernameIsTooShort);
+    assert_ok!(_create_default_blog()); // BlogId 1, followed by ACCOUNT1
+    assert_ok!(_follow_blog(Some(Origin::signed(ACCOUNT2)), None));
+    assert_ok!(_follow_account(Some(Origin::signed(ACCOUNT2)), Some(ACCOUNT1)));
+    assert_ok!(_follow_account(Some(Origin::signed(ACCOUNT3)), Some(ACCOUNT1)));
+
+    assert_eq!(Social::followers_of_blog(1, 1, 10), vec![ACCOUNT2]);
+    assert_eq!(Social::followers_of_account(ACCOUNT1, 0, 1), vec![ACCOUNT2]);
+    assert_eq!(Social::followers_of_account(ACCOUNT1, 1, 1), vec![ACCOUNT3]);
   });
 }
 
 #[test]
-fn update_profile_should_fail_too_long_username() {
-  let username : Vec<u8> = vec![97; (DEFAULT_USERNAME_MAX_LEN + 1) as usize];
-
+fn feed_should_list_posts_of_followed_blogs_newest_first() {
   new_test_ext().execute_with(|| {
-    assert_ok!(_create_default_profile()); // AccountId 1
-    assert_noop!(_update_profile(None, Some(username), None), Error::<Test>::UsernameIsTooLong);
+    assert_ok!(_create_default_blog()); // BlogId 1
+    assert_ok!(_create_blog(None, Some(b"blog2".to_vec()), None)); // BlogId 2
+    assert_ok!(_create_blog(None, Some(b"blog3".to_vec()), None)); // BlogId 3
+    assert_ok!(_create_default_post()); // PostId 1
+    assert_ok!(_create_post(None, Some(2), None, None)); // PostId 2
+    assert_ok!(_create_post(None, Some(3), None, None)); // PostId 3
+    assert_ok!(_create_default_post()); // PostId 4
+    assert_ok!(_follow_blog(None, Some(1)));
+    assert_ok!(_follow_blog(None, Some(2)));
+
+    let post_ids = |posts: Vec<Post<Test>>| posts.into_iter().map(|post| post.id).collect::<Vec<_>>();
+    assert_eq!(post_ids(Social::feed(ACCOUNT2, 0, 10)), vec![4, 2, 1]);
+    assert_eq!(post_ids(Social::feed(ACCOUNT2, 1, 1)), vec![2]);
+    assert!(Social::feed(ACCOUNT3, 0, 10).is_empty());
   });
 }
 
 #[test]
-fn update_profile_should_fail_invalid_username() {
-  let username : Vec<u8> = b"{}sername".to_vec();
-
+fn blog_by_slug_and_profile_by_username_should_work() {
   new_test_ext().execute_with(|| {
-    assert_ok!(_create_default_profile()); // AccountId 1
-    assert_noop!(_update_profile(None, Some(username), None), Error::<Test>::UsernameIsNotAlphanumeric);
+    assert_ok!(_create_default_blog()); // BlogId 1
+    assert_ok!(_create_default_profile()); // ACCOUNT1 as Alice
+
+    assert_eq!(Social::blog_by_slug(self::blog_slug()).map(|blog| blog.id), Some(1));
+    assert!(Social::blog_by_slug(b"unknown".to_vec()).is_none());
+
+    let (account, profile) = Social::profile_by_username(self::alice_username()).unwrap();
+    assert_eq!(account, ACCOUNT1);
+    assert_eq!(profile.username, self::alice_username());
+    assert!(Social::profile_by_username(self::bob_username()).is_none());
   });
 }
 
-#[test]
-fn update_profile_should_fail_invalid_ipfs_hash() {
-  let ipfs_hash : Vec<u8> = b"QmV9tSDx9UiPeWExXEeH6aoDvmihvx6j".to_vec();
+// Edit history tests
 
+#[test]
+fn blog_edit_history_should_be_paged() {
   new_test_ext().execute_with(|| {
-    assert_ok!(_create_default_profile());
-    assert_noop!(_update_profile(None, None, Some(ipfs_hash)), Error::<Test>::IpfsIsIncorrect);
+    assert_ok!(_create_default_blog()); // BlogId 1
+
+    for i in 0..EDIT_HISTORY_PAGE_SIZE + 5 {
+      assert_ok!(_update_blog(None, None, Some(self::blog_update_comments_restricted(i % 2 == 0))));
+    }
+
+    assert_eq!(Social::blog_history_records_count(1), EDIT_HISTORY_PAGE_SIZE + 5);
+    assert_eq!(Social::blog_edit_history(1, 0).len(), EDIT_HISTORY_PAGE_SIZE as usize);
+    assert_eq!(Social::blog_edit_history(1, 0)[0].old_data.comments_restricted, Some(false));
+
+    let second_page = Social::blog_edit_history(1, 1);
+    assert_eq!(second_page.len(), 5);
+    assert_eq!(second_page[1].old_data.comments_restricted, Some(true));
+
+    assert!(Social::blog_edit_history(1, 2).is_empty());
+    assert!(Social::blog_edit_history(1, u32::max_value()).is_empty());
   });
 }
 
-// Blog following tests
-
 #[test]
-fn follow_blog_should_work() {
+fn edit_history_should_be_pruned_on_edit() {
   new_test_ext().execute_with(|| {
+    MaxEditHistoryRecords::put(2);
     assert_ok!(_create_default_blog()); // BlogId 1
 
-    assert_ok!(_default_follow_blog()); // Follow BlogId 1 by ACCOUNT2
+    for i in 0..3 {
+      assert_ok!(_update_blog(None, None, Some(self::blog_update_comments_restricted(i % 2 == 0))));
+    }
 
-    assert_eq!(Social::blog_by_id(1).unwrap().followers_count, 2);
-    assert_eq!(Social::blogs_followed_by_account(ACCOUNT2), vec![1]);
-    assert_eq!(Social::blog_followers(1), vec![ACCOUNT1, ACCOUNT2]);
-    assert_eq!(Social::blog_followed_by_account((ACCOUNT2, 1)), true);
+    assert_eq!(Social::blog_history_records_count(1), 3);
+    assert_eq!(Social::blog_history_first_index(1), 1);
+    assert!(Social::blog_history_record_by_index((1, 0)).is_none());
+
+    let history = Social::blog_edit_history(1, 0);
+    assert_eq!(history.len(), 2);
+    assert_eq!(history[0].old_data.comments_restricted, Some(true));
   });
 }
 
 #[test]
-fn follow_blog_should_fail_blog_not_found() {
+fn insert_history_record_should_not_prune() {
   new_test_ext().execute_with(|| {
-    assert_noop!(_default_follow_blog(), Error::<Test>::BlogNotFound);
+    assert_ok!(_create_default_blog()); // BlogId 1
+    assert_ok!(_update_blog(None, None, Some(self::blog_update_comments_restricted(true))));
+    let record = Social::blog_history_record_by_index((1, 0)).unwrap();
+
+    MaxEditHistoryRecords::put(1);
+    assert_ok!(Social::insert_blog_history_record(1, record.clone()));
+    assert_ok!(Social::insert_blog_history_record(1, record));
+
+    assert_eq!(Social::blog_history_records_count(1), 3);
+    assert_eq!(Social::blog_history_first_index(1), 0);
+    assert_eq!(Social::blog_edit_history(1, 0).len(), 3);
   });
 }
 
 #[test]
-fn follow_blog_should_fail_already_following() {
+fn prune_history_should_work() {
   new_test_ext().execute_with(|| {
     assert_ok!(_create_default_blog()); // BlogId 1
-    assert_ok!(_default_follow_blog()); // Follow BlogId 1 by ACCOUNT2
+    for i in 0..3 {
+      assert_ok!(_update_blog(None, None, Some(self::blog_update_comments_restricted(i % 2 == 0))));
+    }
 
-    assert_noop!(_default_follow_blog(), Error::<Test>::AccountIsFollowingBlog);
+    MaxEditHistoryRecords::put(1);
+    assert_noop!(
+      Social::prune_history(Origin::signed(ACCOUNT1), HistoryEntity::Blog(1)),
+      DispatchError::BadOrigin
+    );
+    assert_ok!(Social::prune_history(system::RawOrigin::Root.into(), HistoryEntity::Blog(1)));
+
+    assert_eq!(Social::blog_history_first_index(1), 2);
+    assert_eq!(Social::blog_edit_history(1, 0).len(), 1);
+    assert!(Social::blog_history_record_by_index((1, 1)).is_none());
+
+    // Nothing to prune anymore
+    assert_ok!(Social::prune_history(system::RawOrigin::Root.into(), HistoryEntity::Blog(1)));
+    assert_eq!(Social::blog_history_first_index(1), 2);
   });
 }
 
 #[test]
-fn unfollow_blog_should_work() {
+fn delete_post_should_remove_edit_history() {
   new_test_ext().execute_with(|| {
     assert_ok!(_create_default_blog()); // BlogId 1
+    assert_ok!(_create_default_post()); // PostId 1
+    assert_ok!(_update_post(None, None, Some(self::post_update(None, Some(self::subcomment_ipfs_hash())))));
+    assert_eq!(Social::post_history_records_count(1), 1);
 
-    assert_ok!(_default_follow_blog()); // Follow BlogId 1 by ACCOUNT2
-    assert_ok!(_default_unfollow_blog());
+    assert_ok!(_delete_post(None, None));
 
-    assert_eq!(Social::blog_by_id(1).unwrap().followers_count, 1);
-    assert!(Social::blogs_followed_by_account(ACCOUNT2).is_empty());
-    assert_eq!(Social::blog_followers(1), vec![ACCOUNT1]);
+    assert_eq!(Social::post_history_records_count(1), 0);
+    assert!(Social::post_history_record_by_index((1, 0)).is_none());
   });
 }
 
+// Migration tests
+
+// Layouts of storage version 0, encoded the way the runtime before the first migration stored them:
+
+#[derive(Encode)]
+struct BlogUpdateV0 {
+  writers: Option<Vec<AccountId>>,
+  slug: Option<Vec<u8>>,
+  ipfs_hash: Option<Vec<u8>>,
+}
+
+#[derive(Encode)]
+struct BlogHistoryRecordV0 {
+  edited: Change<Test>,
+  old_data: BlogUpdateV0,
+}
+
+#[derive(Encode)]
+struct BlogV0 {
+  id: BlogId,
+  created: Change<Test>,
+  updated: Option<Change<Test>>,
+  writers: Vec<AccountId>,
+  slug: Vec<u8>,
+  ipfs_hash: Vec<u8>,
+  posts_count: u16,
+  followers_count: u32,
+  edit_history: Vec<BlogHistoryRecordV0>,
+  score: i32,
+}
+
+#[derive(Encode)]
+struct PostV0 {
+  id: PostId,
+  blog_id: BlogId,
+  created: Change<Test>,
+  updated: Option<Change<Test>>,
+  extension: PostExtension,
+  ipfs_hash: Vec<u8>,
+  comments_count: u16,
+  upvotes_count: u16,
+  downvotes_count: u16,
+  shares_count: u16,
+  edit_history: Vec<PostHistoryRecord<Test>>,
+  score: i32,
+}
+
+#[derive(Encode)]
+struct CommentV0 {
+  id: CommentId,
+  parent_id: Option<CommentId>,
+  post_id: PostId,
+  created: Change<Test>,
+  updated: Option<Change<Test>>,
+  ipfs_hash: Vec<u8>,
+  upvotes_count: u16,
+  downvotes_count: u16,
+  shares_count: u16,
+  direct_replies_count: u16,
+  edit_history: Vec<CommentHistoryRecord<Test>>,
+  score: i32,
+}
+
+#[derive(Encode)]
+struct ProfileV0 {
+  created: Change<Test>,
+  updated: Option<Change<Test>>,
+  username: Vec<u8>,
+  ipfs_hash: Vec<u8>,
+  edit_history: Vec<ProfileHistoryRecord<Test>>,
+}
+
+#[derive(Encode)]
+struct SocialAccountV0 {
+  followers_count: u32,
+  following_accounts_count: u16,
+  following_blogs_count: u16,
+  reputation: u32,
+  profile: Option<ProfileV0>,
+}
+
+fn comment_v0(id: CommentId, parent_id: Option<CommentId>, direct_replies_count: u16) -> CommentV0 {
+  CommentV0 {
+    id,
+    parent_id,
+    post_id: 1,
+    created: Social::new_change(ACCOUNT2),
+    updated: None,
+    ipfs_hash: self::comment_ipfs_hash(),
+    upvotes_count: 0,
+    downvotes_count: 0,
+    shares_count: 0,
+    direct_replies_count,
+    edit_history: vec![],
+    score: 0,
+  }
+}
+
 #[test]
-fn unfollow_blog_should_fail_blog_not_found() {
-  new_test_ext().execute_with(|| {
-    assert_noop!(_default_unfollow_blog(), Error::<Test>::BlogNotFound);
+fn storage_version_should_be_current_at_genesis() {
+  new_test_ext_with_config(GenesisConfig::default()).execute_with(|| {
+    assert_eq!(Social::storage_version(), migration::CURRENT_STORAGE_VERSION);
   });
 }
 
 #[test]
-fn unfollow_blog_should_fail_already_following() {
+fn migrate_storage_should_convert_version_0_layout() {
   new_test_ext().execute_with(|| {
-    assert_ok!(_create_default_blog()); // BlogId 1
+    let old_blog = BlogV0 {
+      id: 1,
+      created: Social::new_change(ACCOUNT1),
+      updated: Some(Social::new_change(ACCOUNT1)),
+      writers: vec![ACCOUNT2],
+      slug: self::blog_slug(),
+      ipfs_hash: self::blog_ipfs_hash(),
+      posts_count: 1,
+      followers_count: 0,
+      edit_history: vec![BlogHistoryRecordV0 {
+        edited: Social::new_change(ACCOUNT1),
+        old_data: BlogUpdateV0 { writers: Some(vec![]), slug: None, ipfs_hash: None },
+      }],
+      score: 0,
+    };
+    frame_support::storage::unhashed::put(&<BlogById<Test>>::hashed_key_for(1), &old_blog);
+
+    let old_post = PostV0 {
+      id: 1,
+      blog_id: 1,
+      created: Social::new_change(ACCOUNT1),
+      updated: None,
+      extension: self::extension_regular_post(),
+      ipfs_hash: self::post_ipfs_hash(),
+      comments_count: 3,
+      upvotes_count: u16::max_value(),
+      downvotes_count: 2,
+      shares_count: 1,
+      edit_history: vec![],
+      score: 7,
+    };
+    frame_support::storage::unhashed::put(&<PostById<Test>>::hashed_key_for(1), &old_post);
+
+    // Comment 1 is a root comment, comment 2 replies to it and comment 3 replies to comment 2:
+    for old_comment in vec![comment_v0(1, None, 1), comment_v0(2, Some(1), 1), comment_v0(3, Some(2), 0)] {
+      frame_support::storage::unhashed::put(&<CommentById<Test>>::hashed_key_for(old_comment.id), &old_comment);
+    }
+    NextCommentId::put(4);
+
+    let old_account = SocialAccountV0 {
+      followers_count: 5,
+      following_accounts_count: 4,
+      following_blogs_count: 3,
+      reputation: 10,
+      profile: Some(ProfileV0 {
+        created: Social::new_change(ACCOUNT1),
+        updated: Some(Social::new_change(ACCOUNT1)),
+        username: self::alice_username(),
+        ipfs_hash: self::profile_ipfs_hash(),
+        edit_history: vec![ProfileHistoryRecord {
+          edited: Social::new_change(ACCOUNT1),
+          old_data: ProfileUpdate { username: Some(self::bob_username()), ipfs_hash: None },
+        }],
+      }),
+    };
+    frame_support::storage::unhashed::put(&<SocialAccountById<Test>>::hashed_key_for(ACCOUNT1), &old_account);
+
+    assert_eq!(Social::storage_version(), 0);
+    Social::migrate_storage();
+    assert_eq!(Social::storage_version(), migration::CURRENT_STORAGE_VERSION);
 
-    assert_noop!(_default_unfollow_blog(), Error::<Test>::AccountIsNotFollowingBlog);
+    let blog = Social::blog_by_id(1).unwrap();
+    assert_eq!(blog.owner, ACCOUNT1);
+    assert_eq!(blog.writers, vec![ACCOUNT2]);
+    assert_eq!(blog.comments_restricted, false);
+    assert_eq!(blog.posts_count, 1);
+    assert_eq!(Social::blog_ids_by_writer(ACCOUNT2), vec![1]);
+    assert_eq!(Social::blog_history_records_count(1), 1);
+    assert_eq!(Social::blog_edit_history(1, 0)[0].old_data, BlogUpdate {
+      writers: Some(vec![]),
+      slug: None,
+      ipfs_hash: None,
+      comments_restricted: None,
+    });
+
+    let post = Social::post_by_id(1).unwrap();
+    assert_eq!(post.comments_count, 3);
+    assert_eq!(post.upvotes_count, u16::max_value() as u32);
+    assert_eq!(post.downvotes_count, 2);
+    assert_eq!(post.shares_count, 1);
+    assert_eq!(post.score, 7);
+
+    assert_eq!(Social::comment_by_id(1).unwrap().depth, 0);
+    assert_eq!(Social::comment_by_id(2).unwrap().depth, 1);
+    assert_eq!(Social::comment_by_id(3).unwrap().depth, 2);
+    assert_eq!(Social::comment_by_id(1).unwrap().direct_replies_count, 1);
+    assert_eq!(Social::comment_ids_by_parent_id(1), vec![2]);
+    assert_eq!(Social::comment_ids_by_parent_id(2), vec![3]);
+    assert!(Social::comment_ids_by_parent_id(3).is_empty());
+
+    let social_account = Social::social_account_by_id(ACCOUNT1).unwrap();
+    assert_eq!(social_account.followers_count, 5);
+    assert_eq!(social_account.following_accounts_count, 4);
+    assert_eq!(social_account.following_blogs_count, 3);
+    assert_eq!(social_account.reputation, 10);
+    assert_eq!(social_account.profile.unwrap().username, self::alice_username());
+    assert_eq!(Social::profile_history_records_count(ACCOUNT1), 1);
   });
 }
 
-// Account following tests
-
 #[test]
-fn follow_account_should_work() {
+fn migrate_storage_should_move_reactions_of_version_0() {
   new_test_ext().execute_with(|| {
-    assert_ok!(_default_follow_account()); // Follow ACCOUNT1 by ACCOUNT2
-
-    assert_eq!(Social::accounts_followed_by_account(ACCOUNT2), vec![ACCOUNT1]);
-    assert_eq!(Social::account_followers(ACCOUNT1), vec![ACCOUNT2]);
-    assert_eq!(Social::account_followed_by_account((ACCOUNT2, ACCOUNT1)), true);
+    let reaction = |id: ReactionId, account: AccountId, kind: ReactionKind| Reaction::<Test> {
+      id,
+      created: Social::new_change(account),
+      updated: None,
+      kind,
+    };
+    <ReactionById<Test>>::insert(1, reaction(1, ACCOUNT1, ReactionKind::Upvote));
+    <ReactionById<Test>>::insert(2, reaction(2, ACCOUNT2, ReactionKind::Upvote));
+    <ReactionById<Test>>::insert(3, reaction(3, ACCOUNT1, ReactionKind::Downvote));
+    NextReactionId::put(4);
+    NextPostId::put(2);
+    NextCommentId::put(2);
+
+    ReactionIdsByPostId::insert(1, vec![1, 2]);
+    <PostReactionIdByAccount<Test>>::insert((ACCOUNT1, 1), 1);
+    <PostReactionIdByAccount<Test>>::insert((ACCOUNT2, 1), 2);
+    ReactionIdsByCommentId::insert(1, vec![3]);
+    <CommentReactionIdByAccount<Test>>::insert((ACCOUNT1, 1), 3);
+
+    Social::migrate_storage();
+
+    assert_eq!(Social::reaction_ids_by_target(ReactionTarget::Post(1)), vec![1, 2]);
+    assert_eq!(Social::reaction_id_by_account((ACCOUNT1, ReactionTarget::Post(1))), Some(1));
+    assert_eq!(Social::reaction_id_by_account((ACCOUNT2, ReactionTarget::Post(1))), Some(2));
+    assert_eq!(Social::reaction_count_by_target((ReactionTarget::Post(1), ReactionKind::Upvote)), 2);
+
+    assert_eq!(Social::reaction_ids_by_target(ReactionTarget::Comment(1)), vec![3]);
+    assert_eq!(Social::reaction_id_by_account((ACCOUNT1, ReactionTarget::Comment(1))), Some(3));
+    assert_eq!(Social::reaction_count_by_target((ReactionTarget::Comment(1), ReactionKind::Downvote)), 1);
+
+    assert!(!ReactionIdsByPostId::exists(1));
+    assert!(!<PostReactionIdByAccount<Test>>::exists((ACCOUNT1, 1)));
+    assert!(!ReactionIdsByCommentId::exists(1));
+    assert!(!<CommentReactionIdByAccount<Test>>::exists((ACCOUNT1, 1)));
   });
 }
 
 #[test]
-fn follow_account_should_fail_follow_itself() {
+fn migrate_storage_should_rebuild_reputation_ledger() {
+  use frame_support::storage::StoragePrefixedMap;
+
   new_test_ext().execute_with(|| {
-    assert_noop!(_follow_account(None, Some(ACCOUNT2)), Error::<Test>::AccountCannotFollowItself);
+    assert_ok!(_create_default_blog()); // BlogId 1
+    assert_ok!(_create_default_post()); // PostId 1
+    assert_ok!(_create_default_post()); // PostId 2
+    assert_ok!(_create_post_reaction(Some(Origin::signed(ACCOUNT2)), Some(1), None)); // ReactionId 1
+    assert_ok!(_create_post_reaction(Some(Origin::signed(ACCOUNT2)), Some(2), None)); // ReactionId 2
+    assert_ok!(_follow_blog(None, None)); // Follow BlogId 1 by ACCOUNT2
+    let ledger_keys = Social::reputation_diff_keys_by_account(ACCOUNT1);
+    let follow_diff = Social::reputation_diff(ACCOUNT2, self::scoring_action_follow_blog(), ScoredEntity::Blog(1)).unwrap();
+
+    // Storage version 2 kept the ledger without scored entities,
+    // diffs of votes are restored from `PostScoreByAccount`:
+    <ReputationDiffByKey<Test>>::remove_all();
+    <ReputationDiffKeysByAccount<Test>>::remove_all();
+    <AccountReputationDiffByAccount<Test>>::insert((ACCOUNT2, ACCOUNT1, self::scoring_action_follow_blog()), follow_diff);
+    StorageVersion::put(2);
+
+    Social::migrate_storage();
+
+    let mut migrated_keys = Social::reputation_diff_keys_by_account(ACCOUNT1);
+    migrated_keys.sort_by_key(|key| key.encode());
+    let mut expected_keys = ledger_keys;
+    expected_keys.sort_by_key(|key| key.encode());
+    assert_eq!(migrated_keys, expected_keys);
+    assert_eq!(Social::audit_reputation(ACCOUNT1).ledger_entries_count, 3);
+    assert!(Social::audit_reputation(ACCOUNT1).is_consistent());
+    assert!(!<AccountReputationDiffByAccount<Test>>::exists((ACCOUNT2, ACCOUNT1, self::scoring_action_follow_blog())));
   });
 }
 
 #[test]
-fn follow_account_should_fail_already_followed() {
+fn migrate_storage_should_move_edit_history() {
   new_test_ext().execute_with(|| {
-    assert_ok!(_default_follow_account());
-
-    assert_noop!(_default_follow_account(), Error::<Test>::AccountIsAlreadyFollowed);
+    let history = vec![
+      PostHistoryRecord {
+        edited: Social::new_change(ACCOUNT1),
+        old_data: self::post_update(None, Some(self::post_ipfs_hash())),
+      },
+      PostHistoryRecord {
+        edited: Social::new_change(ACCOUNT1),
+        old_data: self::post_update(Some(2), None),
+      },
+    ];
+    let old_post = migration::PostV1::<Test> {
+      id: 1,
+      blog_id: 1,
+      created: Social::new_change(ACCOUNT1),
+      updated: Some(Social::new_change(ACCOUNT1)),
+      extension: self::extension_regular_post(),
+      ipfs_hash: self::subcomment_ipfs_hash(),
+      comments_count: 0,
+      upvotes_count: 0,
+      downvotes_count: 0,
+      shares_count: 0,
+      edit_history: history.clone(),
+      score: 0,
+    };
+    frame_support::storage::unhashed::put(&<PostById<Test>>::hashed_key_for(1), &old_post);
+
+    // Records beyond the limit are kept by the migration:
+    MaxEditHistoryRecords::put(1);
+    StorageVersion::put(1);
+    Social::migrate_storage();
+
+    assert_eq!(Social::post_by_id(1).unwrap().ipfs_hash, self::subcomment_ipfs_hash());
+    assert_eq!(Social::post_history_records_count(1), 2);
+    assert_eq!(Social::post_history_first_index(1), 0);
+    assert_eq!(Social::post_edit_history(1, 0), history);
   });
 }
 
+// Serialization tests
 
+fn assert_json_round_trip<V>(value: V)
+  where V: Serialize + serde::de::DeserializeOwned + PartialEq + core::fmt::Debug
+{
+  let json = serde_json::to_string(&value).unwrap();
+  assert_eq!(serde_json::from_str::<V>(&json).unwrap(), value);
+}
 
 #[test]
-fn unfollow_account_should_work() {
+fn content_should_round_trip_through_json() {
   new_test_ext().execute_with(|| {
-    assert_ok!(_default_follow_account()); // Follow ACCOUNT1 by ACCOUNT2
-
-    assert_eq!(Social::accounts_followed_by_account(ACCOUNT2), vec![ACCOUNT1]);
-    assert_eq!(Social::account_followers(ACCOUNT1), vec![ACCOUNT2]);
-    assert_eq!(Social::account_followed_by_account((ACCOUNT2, ACCOUNT1)), true);
+    assert_ok!(_create_default_blog()); // BlogId 1
+    assert_ok!(_create_default_post()); // PostId 1
+    assert_ok!(_create_default_comment()); // CommentId 1
+    assert_ok!(_create_post(None, None, None, Some(PostExtension::SharedComment(1)))); // PostId 2
+    assert_ok!(_create_default_profile()); // ACCOUNT1 as Alice
+    assert_ok!(_react(Some(Origin::signed(ACCOUNT2)), None, Some(ReactionKind::Emoji(1)))); // ReactionId 1
+
+    assert_json_round_trip(Social::blog_by_id(1).unwrap());
+    assert_json_round_trip(Social::post_by_id(1).unwrap());
+    assert_json_round_trip(Social::post_by_id(2).unwrap());
+    assert_json_round_trip(Social::comment_by_id(1).unwrap());
+    assert_json_round_trip(Social::reaction_by_id(1).unwrap());
+    assert_json_round_trip(Social::social_account_by_id(ACCOUNT1).unwrap());
+    assert_json_round_trip(Social::social_account_by_id(ACCOUNT1).unwrap().profile.unwrap());
   });
 }
 
 #[test]
-fn unfollow_account_should_fail_unfollow_itself() {
-  new_test_ext().execute_with(|| {
-    assert_noop!(_unfollow_account(None, Some(ACCOUNT2)), Error::<Test>::AccountCannotUnfollowItself);
-  });
+fn enums_should_round_trip_through_json() {
+  for kind in vec![ReactionKind::Upvote, ReactionKind::Downvote, ReactionKind::Emoji(7)] {
+    assert_json_round_trip(kind);
+  }
+  for extension in vec![PostExtension::RegularPost, PostExtension::SharedPost(1), PostExtension::SharedComment(2)] {
+    assert_json_round_trip(extension);
+  }
+  for action in vec![ScoringAction::UpvotePost, ScoringAction::ShareComment, ScoringAction::FollowAccount] {
+    assert_json_round_trip(action);
+  }
+  assert_json_round_trip(ReactionTarget::Profile(ACCOUNT1));
+  assert_json_round_trip(HistoryEntity::<AccountId>::Blog(1));
 }
 
-#[test]
-fn unfollow_account_should_fail_is_not_followed() {
-  new_test_ext().execute_with(|| {
-    assert_ok!(_default_follow_account());
-    assert_ok!(_default_unfollow_account());
+// A runtime with a custom scoring strategy
+mod constant_scoring {
+  use super::*;
 
-    assert_noop!(_default_unfollow_account(), Error::<Test>::AccountIsNotFollowed);
-  });
+  impl_outer_origin! {
+    pub enum Origin for TestWithConstantScoring {}
+  }
+
+  #[derive(Clone, Eq, PartialEq)]
+  pub struct TestWithConstantScoring;
+  impl system::Trait for TestWithConstantScoring {
+    type Origin = Origin;
+    type Call = ();
+    type Index = u64;
+    type BlockNumber = u64;
+    type Hash = H256;
+    type Hashing = BlakeTwo256;
+    type AccountId = u64;
+    type Lookup = IdentityLookup<Self::AccountId>;
+    type Header = Header;
+    type Event = ();
+    type BlockHashCount = BlockHashCount;
+    type MaximumBlockWeight = MaximumBlockWeight;
+    type MaximumBlockLength = MaximumBlockLength;
+    type AvailableBlockRatio = AvailableBlockRatio;
+    type Version = ();
+    type ModuleToIndex = ();
+  }
+
+  impl pallet_timestamp::Trait for TestWithConstantScoring {
+    type Moment = u64;
+    type OnTimestampSet = ();
+    type MinimumPeriod = MinimumPeriod;
+  }
+
+  const CONSTANT_SCORE_DIFF: i16 = 3;
+
+  /// Gives the same score diff for any actor and action.
+  pub struct ConstantScoringStrategy;
+
+  impl ScoringStrategy<TestWithConstantScoring> for ConstantScoringStrategy {
+    fn score_diff(_actor: &SocialAccount<TestWithConstantScoring>, _action: ScoringAction) -> i16 {
+      CONSTANT_SCORE_DIFF
+    }
+  }
+
+  impl Trait for TestWithConstantScoring {
+    type Event = ();
+    type ScoringStrategy = ConstantScoringStrategy;
+  }
+
+  type Social = Module<TestWithConstantScoring>;
+
+  fn new_test_ext() -> sp_io::TestExternalities {
+    system::GenesisConfig::default().build_storage::<TestWithConstantScoring>().unwrap().into()
+  }
+
+  #[test]
+  fn change_post_score_should_use_scoring_strategy() {
+    new_test_ext().execute_with(|| {
+      assert_ok!(Social::create_blog(Origin::signed(ACCOUNT1), blog_slug(), blog_ipfs_hash())); // BlogId 1
+      assert_ok!(Social::create_post(Origin::signed(ACCOUNT1), 1, post_ipfs_hash(), extension_regular_post())); // PostId 1
+      assert_ok!(Social::create_post_reaction(Origin::signed(ACCOUNT2), 1, reaction_upvote())); // ReactionId 1
+
+      assert_eq!(Social::post_by_id(1).unwrap().score, CONSTANT_SCORE_DIFF as i32);
+      assert_eq!(Social::blog_by_id(1).unwrap().score, CONSTANT_SCORE_DIFF as i32);
+      assert_eq!(Social::post_score_by_account((ACCOUNT2, 1, scoring_action_upvote_post())), Some(CONSTANT_SCORE_DIFF));
+      assert_eq!(Social::social_account_by_id(ACCOUNT1).unwrap().reputation, 1 + CONSTANT_SCORE_DIFF as u32);
+
+      assert_ok!(Social::delete_post_reaction(Origin::signed(ACCOUNT2), 1));
+      assert_eq!(Social::post_by_id(1).unwrap().score, 0);
+      assert_eq!(Social::social_account_by_id(ACCOUNT1).unwrap().reputation, 1);
+    });
+  }
+
+  #[test]
+  fn follow_account_should_use_scoring_strategy() {
+    new_test_ext().execute_with(|| {
+      assert_ok!(Social::follow_account(Origin::signed(ACCOUNT2), ACCOUNT1));
+      assert_eq!(Social::social_account_by_id(ACCOUNT1).unwrap().reputation, 1 + CONSTANT_SCORE_DIFF as u32);
+      assert_eq!(
+        Social::reputation_diff_by_key((ACCOUNT2, scoring_action_follow_account(), ScoredEntity::Account(ACCOUNT1))),
+        Some((ACCOUNT1, CONSTANT_SCORE_DIFF))
+      );
+      assert!(Social::audit_reputation(ACCOUNT1).is_consistent());
+
+      assert_ok!(Social::unfollow_account(Origin::signed(ACCOUNT2), ACCOUNT1));
+      assert_eq!(Social::social_account_by_id(ACCOUNT1).unwrap().reputation, 1);
+      assert!(Social::audit_reputation(ACCOUNT1).is_consistent());
+    });
+  }
 }